@@ -6,39 +6,659 @@ use std::{
     path::Path,
 };
 
-use minify_html::{Cfg, minify};
+use heck::ToSnakeCase;
+use lightningcss::stylesheet::{MinifyOptions, ParserOptions, PrinterOptions, StyleSheet};
+
+#[path = "build/overrides.rs"]
+mod overrides;
 
 const SOURCE_DIR: &str = "./style/components/";
+/// Cascade layer wrapping the component CSS, left unlayered if empty.
+const LAYER_ENV: &str = "DIOXUS_TW_COMPONENTS_LAYER";
+const DEFAULT_LAYER: &str = "components";
+/// Layer order statement emitted before the component CSS, omitted if empty.
+const LAYER_ORDER_ENV: &str = "DIOXUS_TW_COMPONENTS_LAYER_ORDER";
+/// The layers of Tailwind v4, so that utilities override components
+const DEFAULT_LAYER_ORDER: &str = "theme, base, components, utilities";
+/// Absolute path of a directory of CSS files named after components, merged after their default CSS.
+/// Not read from `[package.metadata]`, as Cargo does not tell a dependency where the application is.
+const OVERRIDES_ENV: &str = "DIOXUS_TW_COMPONENTS_OVERRIDES";
+// The fonts live in a companion crate, so that the package only ships them to the features reading them
+#[cfg(feature = "svg-icons")]
+use dioxus_tw_components_icon_fonts::OUTLINED as ICON_FONT_OUTLINED;
+#[cfg(any(feature = "embedded-icon-font", feature = "svg-icons"))]
+use dioxus_tw_components_icon_fonts::{CODEPOINTS as ICON_CODEPOINTS, FILLED as ICON_FONT};
+const ICON_NAMES: &str = "./src/components/icons.txt";
+/// Comma or whitespace separated `Icons` variants to compile, every icon if unset.
+const ICONS_ENV: &str = "DIOXUS_TW_COMPONENTS_ICONS";
+/// Icons used by the components of the crate, always compiled.
+const BUILTIN_ICONS: &[&str] = &[
+    "Abc",
+    "Add",
+    "ArrowLeft",
+    "ArrowRight",
+    "BrightnessAuto",
+    "CalendarMonth",
+    "Check",
+    "ChevronLeft",
+    "ChevronRight",
+    "Close",
+    "Colorize",
+    "DarkMode",
+    "Description",
+    "DragIndicator",
+    "ExpandMore",
+    "FlipToBack",
+    "FlipToFront",
+    "Info",
+    "KeyboardDoubleArrowLeft",
+    "KeyboardDoubleArrowRight",
+    "LightMode",
+    "Lightbulb",
+    "Palette",
+    "Redo",
+    "Remove",
+    "Report",
+    "RestartAlt",
+    "Restore",
+    "Undo",
+    "UploadFile",
+    "Visibility",
+    "VisibilityOff",
+    "Warning",
+];
 
 fn main() -> Result<(), Box<dyn Error>> {
     println!("cargo::rerun-if-changed=style/components");
+    println!("cargo::rerun-if-changed={ICON_NAMES}");
+    println!("cargo::rerun-if-env-changed={ICONS_ENV}");
+    println!("cargo::rerun-if-env-changed={LAYER_ENV}");
+    println!("cargo::rerun-if-env-changed={LAYER_ORDER_ENV}");
+    println!("cargo::rerun-if-env-changed={OVERRIDES_ENV}");
+
+    let icons = selected_icons()?;
+    write_icons(&icons)?;
+
+    #[cfg(feature = "embedded-icon-font")]
+    icon_font::write_icon_font(&icons)?;
+
+    #[cfg(feature = "svg-icons")]
+    svg_icons::write_svg_icons(&icons)?;
+
+    write_style()?;
+
+    Ok(())
+}
+
+/// Bundles the CSS of the enabled components into a single minified stylesheet.
+fn write_style() -> Result<(), Box<dyn Error>> {
+    let mut sources = Vec::new();
+    for f in fs::read_dir(SOURCE_DIR)? {
+        let f = f?;
+
+        if f.file_type()?.is_file() && is_enabled(&f.path()) {
+            sources.push(f.path());
+        }
+    }
+    // read_dir order depends on the platform, keep the output reproducible
+    sources.sort();
+
+    let overrides = match overrides::overrides_dir(env::var_os(OVERRIDES_ENV).as_deref()) {
+        Ok(overrides) => overrides,
+        Err(reason) => {
+            println!("cargo::warning={OVERRIDES_ENV}: {reason}, the CSS overrides are ignored");
+            None
+        }
+    };
+    if let Some(overrides) = &overrides {
+        println!("cargo::rerun-if-changed={}", overrides.display());
+        for path in overrides::unused_overrides(overrides, &sources)? {
+            println!(
+                "cargo::warning=CSS override {} does not match an enabled component",
+                path.display()
+            );
+        }
+    }
+
+    let mut css = String::new();
+    for path in sources {
+        css.push_str(&fs::read_to_string(&path)?);
+        css.push('\n');
+
+        // Appended right after the defaults, so that the same selectors win
+        if let Some(overrides) = &overrides
+            && let Some(file_name) = path.file_name()
+            && overrides.join(file_name).is_file()
+        {
+            css.push_str(&fs::read_to_string(overrides.join(file_name))?);
+            css.push('\n');
+        }
+    }
+
+    let layer_order = env::var(LAYER_ORDER_ENV).unwrap_or_else(|_| DEFAULT_LAYER_ORDER.to_string());
+    let layer = env::var(LAYER_ENV).unwrap_or_else(|_| DEFAULT_LAYER.to_string());
+
+    let source = if layer.trim().is_empty() {
+        css
+    } else {
+        format!("@layer {} {{\n{}}}\n", layer.trim(), css)
+    };
+
+    let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+            filename: SOURCE_DIR.to_string(),
+            ..ParserOptions::default()
+        },
+    )
+    .map_err(|e| e.to_string())?;
+    stylesheet
+        .minify(MinifyOptions::default())
+        .map_err(|e| e.to_string())?;
+    let minified = stylesheet
+        .to_css(PrinterOptions {
+            minify: true,
+            ..PrinterOptions::default()
+        })
+        .map_err(|e| e.to_string())?;
 
     let out_dir = env::var("OUT_DIR")?;
     let dest_path = Path::new(&out_dir).join("dioxus-tw-components-style.css");
     let mut style = File::create(&dest_path)?;
 
-    write!(
+    writeln!(
         &mut style,
-        "/* This file was generated by Dioxus Tailwind Components build script */ @layer theme {{",
+        "/* This file was generated by Dioxus Tailwind Components build script */"
     )?;
+    // Written as is, the minifier drops the layers that the stylesheet does not use
+    let layer_order: Vec<&str> = layer_order
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+    if !layer_order.is_empty() {
+        write!(&mut style, "@layer {};", layer_order.join(","))?;
+    }
+    write!(&mut style, "{}", minified.code)?;
 
-    for f in fs::read_dir(SOURCE_DIR)? {
-        let f = f?;
+    Ok(())
+}
+
+/// Returns true if the cargo feature named after the stylesheet (e.g. `toast` for `toast.css`) is enabled.
+fn is_enabled(path: &Path) -> bool {
+    let Some(component) = path.file_stem().and_then(|stem| stem.to_str()) else {
+        return false;
+    };
 
-        if !f.file_type()?.is_file() {
-            continue;
+    env::var_os(format!(
+        "CARGO_FEATURE_{}",
+        component.to_uppercase().replace('-', "_")
+    ))
+    .is_some()
+}
+
+/// An `Icons` variant and the ligature it is rendered with.
+struct IconName {
+    variant: String,
+    ligature: String,
+}
+
+/// Reads the icon list, keeping only the icons allowed by `DIOXUS_TW_COMPONENTS_ICONS`.
+fn selected_icons() -> Result<Vec<IconName>, Box<dyn Error>> {
+    let allowlist = env::var(ICONS_ENV).ok().map(|list| {
+        list.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    });
+
+    let icons: Vec<IconName> = fs::read_to_string(ICON_NAMES)?
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let variant = parts.next()?.to_string();
+            // Most ligatures follow the snake case of the variant, the others are listed next to it
+            let ligature = parts
+                .next()
+                .map(str::to_string)
+                .unwrap_or_else(|| variant.to_snake_case());
+            Some(IconName { variant, ligature })
+        })
+        .collect();
+
+    let Some(allowlist) = allowlist else {
+        return Ok(icons);
+    };
+
+    for name in &allowlist {
+        if !icons.iter().any(|icon| &icon.variant == name) {
+            println!("cargo::warning={ICONS_ENV}: unknown icon `{name}`");
         }
+    }
 
-        let content = fs::read_to_string(f.path())?;
+    Ok(icons
+        .into_iter()
+        .filter(|icon| {
+            BUILTIN_ICONS.contains(&icon.variant.as_str()) || allowlist.contains(&icon.variant)
+        })
+        .collect())
+}
+
+/// Generates the `Icons` enum from the selected icons.
+fn write_icons(icons: &[IconName]) -> Result<(), Box<dyn Error>> {
+    let out_dir = env::var("OUT_DIR")?;
+    let dest_path = Path::new(&out_dir).join("dioxus-tw-components-icons.rs");
+    let mut out = File::create(&dest_path)?;
 
-        let mut cfg = Cfg::new();
-        cfg.keep_comments = true;
-        cfg.allow_removing_spaces_between_attributes = true;
-        let minified = minify(content.as_bytes(), &cfg);
+    writeln!(
+        &mut out,
+        "/// Material Symbols icons.\n\
+         ///\n\
+         /// Only the icons listed in the `{ICONS_ENV}` environment variable are compiled when it is set.\n\
+         #[derive(Default, Debug, PartialEq, Copy, Clone, strum::Display)]\n\
+         #[strum(serialize_all = \"snake_case\")]\n\
+         pub enum Icons {{"
+    )?;
 
-        write!(&mut style, "{}", String::from_utf8(minified)?)?;
+    for icon in icons {
+        if icon.variant == "Abc" {
+            writeln!(&mut out, "#[default]")?;
+        }
+        if icon.ligature != icon.variant.to_snake_case() {
+            writeln!(&mut out, "#[strum(serialize = {:?})]", icon.ligature)?;
+        }
+        writeln!(&mut out, "{},", icon.variant)?;
     }
-    write!(&mut style, "}}",)?;
+
+    writeln!(
+        &mut out,
+        "/// An icon defined by the application.\n\
+         #[strum(to_string = \"{{0}}\")]\n\
+         Custom(CustomIcon),\n\
+         }}"
+    )?;
 
     Ok(())
 }
+
+/// Subsets the filled icon font to the compiled `Icons` for the `embedded-icon-font` feature.
+#[cfg(feature = "embedded-icon-font")]
+mod icon_font {
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        env,
+        error::Error,
+        fs::File,
+        io::Write,
+        path::Path,
+    };
+
+    use base64::{Engine, engine::general_purpose::STANDARD};
+
+    use write_fonts::{
+        FontBuilder,
+        read::{FontRef, TableProvider},
+        tables::{
+            cmap::Cmap,
+            gsub::{
+                ExtensionSubstFormat1, ExtensionSubtable, Gsub, Ligature, LigatureSet,
+                LigatureSubstFormat1, SubstitutionLookup, SubstitutionLookupList,
+            },
+            layout::{
+                Feature, FeatureList, FeatureRecord, LangSys, Lookup, LookupFlag, Script,
+                ScriptList, ScriptRecord,
+            },
+        },
+        types::{GlyphId, GlyphId16, Tag},
+    };
+
+    use super::{ICON_CODEPOINTS, ICON_FONT, IconName};
+
+    /// Tables that do not depend on the glyph ids, copied as is.
+    const COPIED_TABLES: [&[u8; 4]; 4] = [b"OS/2", b"gasp", b"name", b"prep"];
+    /// Ligature sets are split in subtables of about this size to keep their 16-bit offsets valid.
+    const SUBTABLE_SIZE: usize = 32 * 1024;
+
+    /// Embeds the icon font as a data URI so that `Bootstrap` does not need the network.
+    pub fn write_icon_font(icons: &[IconName]) -> Result<(), Box<dyn Error>> {
+        let out_dir = env::var("OUT_DIR")?;
+        let dest_path = Path::new(&out_dir).join("dioxus-tw-components-icon-font.css");
+        let mut style = File::create(&dest_path)?;
+
+        write!(
+            &mut style,
+            "@font-face {{ font-family: 'Material Symbols Rounded'; font-style: normal; font-weight: 400; font-display: block; src: url(data:font/ttf;base64,{}) format('truetype'); }}",
+            STANDARD.encode(subset(icons)?)
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns a font with only the glyphs of `icons` and of the letters of their ligatures.
+    fn subset(icons: &[IconName]) -> Result<Vec<u8>, Box<dyn Error>> {
+        let font = FontRef::new(ICON_FONT)?;
+        let cmap = font.cmap()?;
+        let glyph = |c: char| {
+            cmap.map_codepoint(c)
+                .ok_or_else(|| format!("icon font: no glyph for {c:?}"))
+        };
+
+        let mut ligatures = Vec::new();
+        for line in ICON_CODEPOINTS.lines() {
+            let Some((name, codepoint)) = line.split_once(' ') else {
+                continue;
+            };
+            if !icons.iter().any(|icon| icon.ligature == name) {
+                continue;
+            }
+            let Some(codepoint) = u32::from_str_radix(codepoint, 16)
+                .ok()
+                .and_then(char::from_u32)
+            else {
+                continue;
+            };
+            ligatures.push((name.to_string(), codepoint, glyph(codepoint)?));
+        }
+
+        // The characters are mapped to their glyphs, the ligatures of the names to the icons
+        let mut mappings = BTreeMap::new();
+        for (name, codepoint, icon) in &ligatures {
+            mappings.insert(*codepoint, *icon);
+            for c in name.chars() {
+                mappings.insert(c, glyph(c)?);
+            }
+        }
+
+        // Glyph 0 is .notdef, the kept glyphs keep their order
+        let kept: Vec<GlyphId> = [GlyphId::NOTDEF]
+            .into_iter()
+            .chain(mappings.values().copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let new_id = |old: GlyphId| -> GlyphId16 {
+            let index = kept.binary_search(&old).unwrap_or_default();
+            GlyphId16::new(index as u16)
+        };
+
+        let mut builder = FontBuilder::new();
+
+        // Every glyph of the font is simple, so its outline can be copied without remapping
+        let loca = font.loca(None)?;
+        let glyf = font.table_data(Tag::new(b"glyf")).ok_or("missing glyf")?;
+        let hmtx = font.hmtx()?;
+        let mut new_glyf = Vec::new();
+        let mut new_loca = Vec::new();
+        let mut new_hmtx = Vec::new();
+        for &old in &kept {
+            let index = old.to_u32() as usize;
+            let (Some(start), Some(end)) = (loca.get_raw(index), loca.get_raw(index + 1)) else {
+                return Err(format!("icon font: glyph {index} is out of loca").into());
+            };
+            let outline = &glyf.as_bytes()[start as usize..end as usize];
+            if outline.len() >= 2 && i16::from_be_bytes([outline[0], outline[1]]) < 0 {
+                return Err(format!("icon font: glyph {index} is a composite").into());
+            }
+
+            new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+            new_glyf.extend_from_slice(outline);
+            new_glyf.resize(new_glyf.len().next_multiple_of(4), 0);
+
+            new_hmtx.extend(hmtx.advance(old).unwrap_or_default().to_be_bytes());
+            new_hmtx.extend(hmtx.side_bearing(old).unwrap_or_default().to_be_bytes());
+        }
+        new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+        let glyph_count = (kept.len() as u16).to_be_bytes();
+        // Long offsets in loca
+        let head = patched(&font, b"head", 50, &1u16.to_be_bytes())?;
+        // numberOfHMetrics, every glyph has its own metrics
+        let hhea = patched(&font, b"hhea", 34, &glyph_count)?;
+        let maxp = patched(&font, b"maxp", 4, &glyph_count)?;
+        // Version 3 without glyph names
+        let mut post = font
+            .table_data(Tag::new(b"post"))
+            .ok_or("missing post")?
+            .as_bytes()[..32]
+            .to_vec();
+        post[..4].copy_from_slice(&0x0003_0000u32.to_be_bytes());
+
+        builder
+            .add_raw(Tag::new(b"glyf"), new_glyf)
+            .add_raw(Tag::new(b"loca"), new_loca)
+            .add_raw(Tag::new(b"hmtx"), new_hmtx)
+            .add_raw(Tag::new(b"head"), head)
+            .add_raw(Tag::new(b"hhea"), hhea)
+            .add_raw(Tag::new(b"maxp"), maxp)
+            .add_raw(Tag::new(b"post"), post);
+        for tag in COPIED_TABLES {
+            let data = font.table_data(Tag::new(tag)).ok_or("missing table")?;
+            builder.add_raw(Tag::new(tag), data.as_bytes().to_vec());
+        }
+
+        let cmap = Cmap::from_mappings(mappings.iter().map(|(&c, &old)| (c, new_id(old).into())))?;
+        builder.add_table(&cmap)?;
+
+        let mut sets: BTreeMap<GlyphId16, Vec<(Vec<GlyphId16>, GlyphId16)>> = BTreeMap::new();
+        for (name, _, icon) in &ligatures {
+            let glyphs: Vec<GlyphId16> = name.chars().map(|c| new_id(mappings[&c])).collect();
+            sets.entry(glyphs[0])
+                .or_default()
+                .push((glyphs[1..].to_vec(), new_id(*icon)));
+        }
+        builder.add_table(&ligature_gsub(sets))?;
+
+        Ok(builder.build())
+    }
+
+    /// Copies the table `tag` with `value` written at `offset`.
+    fn patched(
+        font: &FontRef,
+        tag: &[u8; 4],
+        offset: usize,
+        value: &[u8],
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut data = font
+            .table_data(Tag::new(tag))
+            .ok_or("missing table")?
+            .as_bytes()
+            .to_vec();
+        data[offset..offset + value.len()].copy_from_slice(value);
+        Ok(data)
+    }
+
+    /// Builds a `GSUB` replacing the glyphs of each name by its icon, under the always applied `rlig` feature.
+    fn ligature_gsub(sets: BTreeMap<GlyphId16, Vec<(Vec<GlyphId16>, GlyphId16)>>) -> Gsub {
+        let mut subtables = Vec::new();
+        let mut coverage = Vec::new();
+        let mut ligature_sets = Vec::new();
+        let mut size = 0;
+
+        for (first, mut ligatures) in sets {
+            // The first matching ligature is applied, longer names must come first
+            ligatures.sort_by_key(|(components, _)| std::cmp::Reverse(components.len()));
+            size += ligatures
+                .iter()
+                .map(|(components, _)| 6 + 2 * components.len())
+                .sum::<usize>();
+
+            coverage.push(first);
+            ligature_sets.push(LigatureSet::new(
+                ligatures
+                    .into_iter()
+                    .map(|(components, icon)| Ligature::new(icon, components))
+                    .collect(),
+            ));
+
+            if size > SUBTABLE_SIZE {
+                subtables.push(LigatureSubstFormat1::new(
+                    std::mem::take(&mut coverage).into(),
+                    std::mem::take(&mut ligature_sets),
+                ));
+                size = 0;
+            }
+        }
+        if !coverage.is_empty() {
+            subtables.push(LigatureSubstFormat1::new(coverage.into(), ligature_sets));
+        }
+
+        // Extension subtables have 32-bit offsets, the lookup would overflow otherwise
+        let lookup = Lookup::new(
+            LookupFlag::empty(),
+            subtables
+                .into_iter()
+                .map(|subtable| {
+                    ExtensionSubtable::Ligature(ExtensionSubstFormat1::new(4, subtable))
+                })
+                .collect(),
+        );
+
+        let lang_sys = LangSys::new(vec![0]);
+        Gsub::new(
+            ScriptList::new(vec![
+                ScriptRecord::new(
+                    Tag::new(b"DFLT"),
+                    Script::new(Some(lang_sys.clone()), vec![]),
+                ),
+                ScriptRecord::new(Tag::new(b"latn"), Script::new(Some(lang_sys), vec![])),
+            ]),
+            FeatureList::new(vec![FeatureRecord::new(
+                Tag::new(b"rlig"),
+                Feature::new(None, vec![0]),
+            )]),
+            SubstitutionLookupList::new(vec![SubstitutionLookup::Extension(lookup)]),
+        )
+    }
+}
+
+/// Converts the icon font glyphs to SVG paths for the `Svg` backend of `Icon`.
+#[cfg(feature = "svg-icons")]
+mod svg_icons {
+    use std::{
+        env,
+        error::Error,
+        fs::File,
+        io::{BufWriter, Write},
+        path::Path,
+    };
+
+    use ttf_parser::{Face, OutlineBuilder};
+
+    use super::{ICON_CODEPOINTS, ICON_FONT, ICON_FONT_OUTLINED, IconName};
+
+    /// Builds the `d` attribute of a path with relative commands.
+    /// The font y axis points up, it is flipped to match the SVG `0 -960 960 960` viewBox.
+    #[derive(Default)]
+    struct PathBuilder {
+        d: String,
+        current: (f32, f32),
+        start: (f32, f32),
+    }
+
+    impl PathBuilder {
+        fn push(&mut self, command: char, points: &[(f32, f32)]) {
+            self.d.push(command);
+
+            let mut first = true;
+            for &(x, y) in points {
+                for value in [x - self.current.0, -y - self.current.1] {
+                    let value = format!("{}", (value * 10.0).round() / 10.0);
+                    if !first && !value.starts_with('-') {
+                        self.d.push(' ');
+                    }
+                    self.d.push_str(&value);
+                    first = false;
+                }
+            }
+        }
+
+        fn is_current(&self, x: f32, y: f32) -> bool {
+            (x, -y) == self.current
+        }
+    }
+
+    impl OutlineBuilder for PathBuilder {
+        fn move_to(&mut self, x: f32, y: f32) {
+            self.push('m', &[(x, y)]);
+            self.current = (x, -y);
+            self.start = self.current;
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            if self.is_current(x, y) {
+                return;
+            }
+            self.push('l', &[(x, y)]);
+            self.current = (x, -y);
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            if self.is_current(x1, y1) && self.is_current(x, y) {
+                return;
+            }
+            self.push('q', &[(x1, y1), (x, y)]);
+            self.current = (x, -y);
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            self.push('c', &[(x1, y1), (x2, y2), (x, y)]);
+            self.current = (x, -y);
+        }
+
+        fn close(&mut self) {
+            self.d.push('z');
+            self.current = self.start;
+        }
+    }
+
+    fn glyph_path(face: &Face, codepoint: char) -> Option<String> {
+        let glyph = face.glyph_index(codepoint)?;
+        let mut builder = PathBuilder::default();
+        face.outline_glyph(glyph, &mut builder)?;
+        Some(builder.d)
+    }
+
+    pub fn write_svg_icons(icons: &[IconName]) -> Result<(), Box<dyn Error>> {
+        let filled = Face::parse(ICON_FONT, 0)?;
+        let outlined = Face::parse(ICON_FONT_OUTLINED, 0)?;
+
+        let out_dir = env::var("OUT_DIR")?;
+        let dest_path = Path::new(&out_dir).join("dioxus-tw-components-svg-icons.rs");
+        let mut out = BufWriter::new(File::create(&dest_path)?);
+
+        writeln!(
+            &mut out,
+            "/// Returns the filled and outlined SVG paths of the icon `name`.\n\
+             pub(crate) fn svg_paths(name: &str) -> Option<(&'static str, &'static str)> {{\n\
+             match name {{"
+        )?;
+
+        for line in ICON_CODEPOINTS.lines() {
+            let Some((name, codepoint)) = line.split_once(' ') else {
+                continue;
+            };
+            if !icons.iter().any(|icon| icon.ligature == name) {
+                continue;
+            }
+            let Some(codepoint) = u32::from_str_radix(codepoint, 16)
+                .ok()
+                .and_then(char::from_u32)
+            else {
+                continue;
+            };
+
+            let (Some(filled), Some(outlined)) = (
+                glyph_path(&filled, codepoint),
+                glyph_path(&outlined, codepoint),
+            ) else {
+                continue;
+            };
+
+            writeln!(&mut out, "{name:?} => Some(({filled:?}, {outlined:?})),")?;
+        }
+
+        writeln!(&mut out, "_ => None,\n}}\n}}")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//! CSS overrides of the applications, shared by the build script and its tests.
+
+use std::{
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Directory named by `DIOXUS_TW_COMPONENTS_OVERRIDES`, `None` if it is unset or empty.
+/// Returns the reason to ignore it if it is relative or not a directory.
+pub fn overrides_dir(var: Option<&OsStr>) -> Result<Option<PathBuf>, String> {
+    let Some(var) = var.filter(|var| !var.is_empty()) else {
+        return Ok(None);
+    };
+    let dir = PathBuf::from(var);
+
+    // The build script runs in the directory of this crate, not of the application
+    if dir.is_relative() {
+        return Err(format!(
+            "{} is relative, set it with `relative = true` in `.cargo/config.toml`",
+            dir.display()
+        ));
+    }
+    if !dir.is_dir() {
+        return Err(format!("{} is not a directory", dir.display()));
+    }
+
+    Ok(Some(dir))
+}
+
+/// CSS files of `overrides` that do not match one of the stylesheets in `sources`.
+pub fn unused_overrides(overrides: &Path, sources: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut unused = Vec::new();
+    for f in fs::read_dir(overrides)? {
+        let path = f?.path();
+
+        if path.extension().is_some_and(|extension| extension == "css")
+            && !sources
+                .iter()
+                .any(|source| source.file_name() == path.file_name())
+        {
+            unused.push(path);
+        }
+    }
+    unused.sort();
+
+    Ok(unused)
+}
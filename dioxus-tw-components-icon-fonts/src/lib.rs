@@ -0,0 +1,12 @@
+//! The Material Symbols Rounded fonts read by the build script of `dioxus-tw-components`.
+//!
+//! They are only downloaded with its `embedded-icon-font` and `svg-icons` features.
+
+/// The filled, regular weight static instance.
+pub const FILLED: &[u8] = include_bytes!("../fonts/MaterialSymbolsRounded-Filled.ttf");
+
+/// The outlined, regular weight static instance.
+pub const OUTLINED: &[u8] = include_bytes!("../fonts/MaterialSymbolsRounded-Outlined.ttf");
+
+/// One `name codepoint` line per icon, the codepoint in hexadecimal.
+pub const CODEPOINTS: &str = include_str!("../fonts/MaterialSymbolsRounded.codepoints");
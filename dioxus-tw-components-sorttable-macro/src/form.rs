@@ -0,0 +1,352 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, format_ident, quote};
+use syn::{DeriveInput, Expr, ext::IdentExt};
+
+pub(crate) fn impl_form_derive(input: DeriveInput) -> TokenStream {
+    let struct_name = input.ident;
+    let vis = input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let syn::Data::Struct(data) = input.data else {
+        return quote::quote! {
+            compile_error!("Only structs are supported");
+        }
+        .into();
+    };
+
+    let syn::Fields::Named(syn::FieldsNamed { named, .. }) = data.fields else {
+        return quote::quote! {
+            compile_error!("Only named fields supported");
+        }
+        .into();
+    };
+
+    // Example:
+    // #[field(label = "Bio", widget = "textarea", validate = [Validator::max_length(200)])]
+    // pub bio: String
+    let mut fields = Vec::new();
+    for field in named.iter() {
+        let mut field_attr = FieldAttr::default();
+        for attr in &field.attrs {
+            if attr.path().is_ident("field") {
+                match attr.parse_args::<FieldAttr>() {
+                    Ok(parsed) => field_attr = parsed,
+                    Err(err) => return err.to_compile_error().into(),
+                }
+            }
+        }
+
+        let ident = field.ident.clone().expect("named field");
+        match FormField::new(ident, &field.ty, field_attr) {
+            Ok(field) => fields.push(field),
+            Err(err) => return err.to_compile_error().into(),
+        }
+    }
+
+    let field_enum = format_ident!("{}Field", struct_name);
+    let variants: Vec<_> = fields.iter().map(|field| &field.variant).collect();
+    let names: Vec<_> = fields.iter().map(|field| &field.name).collect();
+    let idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let labels: Vec<_> = fields.iter().map(|field| &field.label).collect();
+    let widgets: Vec<_> = fields.iter().map(|field| &field.widget).collect();
+    let validators: Vec<_> = fields.iter().map(|field| &field.validators).collect();
+
+    quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #field_enum {
+            #( #variants ),*
+        }
+
+        impl #field_enum {
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #( #field_enum::#variants => #names ),*
+                }
+            }
+        }
+
+        impl #impl_generics ::dioxus_tw_components::FormModel for #struct_name #ty_generics #where_clause {
+            type Field = #field_enum;
+
+            fn fields() -> Vec<::dioxus_tw_components::FieldSpec<#field_enum>> {
+                vec![
+                    #(
+                        ::dioxus_tw_components::FieldSpec {
+                            field: #field_enum::#variants,
+                            name: #names,
+                            label: #labels,
+                            widget: #widgets,
+                            validators: #validators,
+                        }
+                    ),*
+                ]
+            }
+
+            fn to_values(&self) -> ::dioxus_tw_components::FormValues {
+                [
+                    #(
+                        (
+                            #names.to_string(),
+                            ::dioxus_tw_components::FormValue::to_form_value(&self.#idents),
+                        )
+                    ),*
+                ]
+                .into_iter()
+                .collect()
+            }
+
+            fn from_values(
+                values: &::dioxus_tw_components::FormValues,
+            ) -> Result<Self, Vec<::dioxus_tw_components::FieldError<#field_enum>>> {
+                let mut errors = Vec::new();
+                #(
+                    let #idents = match <#types as ::dioxus_tw_components::FormValue>::from_form_value(
+                        values.get(#names).unwrap_or_default(),
+                    ) {
+                        Ok(value) => Some(value),
+                        Err(message) => {
+                            errors.push(::dioxus_tw_components::FieldError {
+                                field: #field_enum::#variants,
+                                message,
+                            });
+                            None
+                        }
+                    };
+                )*
+
+                let ( #( Some(#idents), )* ) = ( #( #idents, )* ) else {
+                    return Err(errors);
+                };
+                Ok(Self { #( #idents ),* })
+            }
+        }
+    }
+    .into()
+}
+
+struct FormField {
+    ident: syn::Ident,
+    ty: syn::Type,
+    variant: syn::Ident,
+    name: String,
+    label: String,
+    widget: TokenStream2,
+    validators: TokenStream2,
+}
+
+impl FormField {
+    fn new(ident: syn::Ident, ty: &syn::Type, attr: FieldAttr) -> syn::Result<Self> {
+        let name = ident.unraw().to_string();
+        let widget = attr.widget(ty, &ident)?;
+        let validators = match attr.validate {
+            Some(validate) => quote! {
+                ::std::vec::Vec::<::dioxus_tw_components::Validator>::from(#validate)
+            },
+            None => quote! { ::std::vec::Vec::new() },
+        };
+
+        Ok(FormField {
+            variant: format_ident!("{}", upper_camel_case(&name)),
+            label: attr.label.unwrap_or_else(|| sentence_case(&name)),
+            ty: ty.clone(),
+            ident,
+            name,
+            widget,
+            validators,
+        })
+    }
+}
+
+/// Widgets other than an input, the value of `widget` is the type of an `Input` otherwise.
+const WIDGETS: &[&str] = &["textarea", "checkbox", "select", "radio", "slider"];
+/// `type`s of `Input` that can hold the value of a field.
+const INPUT_TYPES: &[&str] = &[
+    "text",
+    "number",
+    "email",
+    "password",
+    "tel",
+    "url",
+    "search",
+    "date",
+    "datetime-local",
+    "time",
+    "month",
+    "week",
+    "color",
+];
+
+// Define struct for `#[field(...)]` attributes
+#[derive(Default)]
+struct FieldAttr {
+    label: Option<String>,
+    widget: Option<syn::LitStr>,
+    validate: Option<Expr>,
+    options: Option<Expr>,
+    min: Option<Expr>,
+    max: Option<Expr>,
+    step: Option<Expr>,
+}
+
+impl FieldAttr {
+    fn widget(&self, ty: &syn::Type, ident: &syn::Ident) -> syn::Result<TokenStream2> {
+        let widget = match &self.widget {
+            Some(widget) => widget.value(),
+            None => default_widget(ty).to_string(),
+        };
+
+        let options = || match &self.options {
+            Some(options) => Ok(quote! { &#options }),
+            None => Err(syn::Error::new_spanned(
+                ident,
+                format!("`{widget}` fields need `options = [...]`"),
+            )),
+        };
+
+        Ok(match widget.as_str() {
+            "textarea" => quote! { ::dioxus_tw_components::Widget::TextArea },
+            "checkbox" => quote! { ::dioxus_tw_components::Widget::Checkbox },
+            "select" => {
+                let options = options()?;
+                quote! { ::dioxus_tw_components::Widget::Select(#options) }
+            }
+            "radio" => {
+                let options = options()?;
+                quote! { ::dioxus_tw_components::Widget::Radio(#options) }
+            }
+            "slider" => {
+                let min = self
+                    .min
+                    .as_ref()
+                    .map_or(quote! { 0 }, ToTokens::to_token_stream);
+                let max = self
+                    .max
+                    .as_ref()
+                    .map_or(quote! { 100 }, ToTokens::to_token_stream);
+                let step = self
+                    .step
+                    .as_ref()
+                    .map_or(quote! { 1 }, ToTokens::to_token_stream);
+                quote! {
+                    ::dioxus_tw_components::Widget::Slider { min: #min, max: #max, step: #step }
+                }
+            }
+            input_type if INPUT_TYPES.contains(&input_type) => {
+                quote! { ::dioxus_tw_components::Widget::Input(#input_type) }
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &self.widget,
+                    format!(
+                        "unknown widget `{widget}`, expected one of {}, {}",
+                        WIDGETS.join(", "),
+                        INPUT_TYPES.join(", ")
+                    ),
+                ));
+            }
+        })
+    }
+}
+
+impl syn::parse::Parse for FieldAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut field_attr = FieldAttr::default();
+
+        let args =
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated(input)?;
+
+        for meta in args.iter() {
+            match meta {
+                syn::Meta::NameValue(nv) if nv.path.is_ident("label") => {
+                    field_attr.label = Some(string_value(&nv.value)?);
+                }
+                syn::Meta::NameValue(nv) if nv.path.is_ident("widget") => {
+                    field_attr.widget = Some(string_literal(&nv.value)?);
+                }
+                syn::Meta::NameValue(nv) if nv.path.is_ident("validate") => {
+                    field_attr.validate = Some(nv.value.clone());
+                }
+                syn::Meta::NameValue(nv) if nv.path.is_ident("options") => {
+                    field_attr.options = Some(nv.value.clone());
+                }
+                syn::Meta::NameValue(nv) if nv.path.is_ident("min") => {
+                    field_attr.min = Some(nv.value.clone());
+                }
+                syn::Meta::NameValue(nv) if nv.path.is_ident("max") => {
+                    field_attr.max = Some(nv.value.clone());
+                }
+                syn::Meta::NameValue(nv) if nv.path.is_ident("step") => {
+                    field_attr.step = Some(nv.value.clone());
+                }
+                _ => return Err(syn::Error::new_spanned(meta, "Unexpected attribute")),
+            }
+        }
+
+        Ok(field_attr)
+    }
+}
+
+fn string_value(expr: &Expr) -> syn::Result<String> {
+    string_literal(expr).map(|value| value.value())
+}
+
+fn string_literal(expr: &Expr) -> syn::Result<syn::LitStr> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(value),
+            ..
+        }) => Ok(value.clone()),
+        _ => Err(syn::Error::new_spanned(expr, "Expected a string literal")),
+    }
+}
+
+/// Checkboxes for `bool`, number inputs for numbers and text inputs for anything else.
+/// An `Option` gets the widget of the type it wraps.
+fn default_widget(ty: &syn::Type) -> &'static str {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return "text";
+    };
+    let Some(segment) = path.segments.last() else {
+        return "text";
+    };
+
+    if segment.ident == "Option"
+        && let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = arguments.args.first()
+    {
+        return default_widget(inner);
+    }
+    if !segment.arguments.is_none() {
+        return "text";
+    }
+
+    match segment.ident.to_string().as_str() {
+        "bool" => "checkbox",
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" | "f32" | "f64" => "number",
+        _ => "text",
+    }
+}
+
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn sentence_case(name: &str) -> String {
+    let words = name.replace('_', " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
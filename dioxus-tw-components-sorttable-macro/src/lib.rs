@@ -2,6 +2,8 @@ use proc_macro::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{DeriveInput, parse_macro_input};
 
+mod form;
+
 #[proc_macro_derive(Row, attributes(row))]
 pub fn derive_row(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as syn::DeriveInput);
@@ -9,6 +11,14 @@ pub fn derive_row(input: TokenStream) -> TokenStream {
     impl_row_derive(ast)
 }
 
+/// Implements `FormModel`, rendering the struct with `ModelForm`
+#[proc_macro_derive(Form, attributes(field))]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+
+    form::impl_form_derive(ast)
+}
+
 fn impl_row_derive(input: DeriveInput) -> TokenStream {
     let struct_name = input.ident;
     let struct_generics = input.generics;
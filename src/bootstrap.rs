@@ -5,7 +5,39 @@ pub fn Bootstrap() -> Element {
     let style = include_str!(concat!(env!("OUT_DIR"), "/dioxus-tw-components-style.css"));
 
     rsx! {
-        document::Stylesheet { href: "https://fonts.googleapis.com/css2?family=Material+Symbols+Rounded:FILL@1" }
+        if cfg!(feature = "icon") {
+            IconFont {}
+        }
+        style { {style} }
+    }
+}
+
+/// Loads the Material Symbols font used by `Icon` from Google Fonts.
+/// The filled instance by default, every axis with `variable-icon-font`.
+#[cfg(not(feature = "embedded-icon-font"))]
+#[component]
+fn IconFont() -> Element {
+    let href = if cfg!(feature = "variable-icon-font") {
+        "https://fonts.googleapis.com/css2?family=Material+Symbols+Rounded:opsz,wght,FILL,GRAD@20..48,100..700,0..1,-50..200"
+    } else {
+        "https://fonts.googleapis.com/css2?family=Material+Symbols+Rounded:FILL@1"
+    };
+
+    rsx! {
+        document::Stylesheet { href }
+    }
+}
+
+/// Loads the Material Symbols font used by `Icon` from the crate, so it works offline.
+#[cfg(feature = "embedded-icon-font")]
+#[component]
+fn IconFont() -> Element {
+    let style = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/dioxus-tw-components-icon-font.css"
+    ));
+
+    rsx! {
         style { {style} }
     }
 }
@@ -0,0 +1,667 @@
+//! Conflict resolution between Tailwind classes, in the spirit of `tailwind-merge`.
+
+use std::collections::HashSet;
+
+/// Merges space separated class lists, later classes winning over earlier ones.
+///
+/// When two classes set the same CSS property under the same variants (e.g. `p-4` and `p-2`,
+/// or `hover:bg-primary` and `hover:bg-red-500`), only the last one is kept. A class also
+/// replaces the earlier classes it fully covers: `p-2` removes `px-4`, but `px-2` keeps `p-4`.
+/// Duplicates are removed, and classes that are not Tailwind utilities (e.g. component classes)
+/// are kept as is.
+///
+/// ```
+/// # use dioxus_tw_components::class_merge::tw_merge;
+/// assert_eq!(tw_merge(["button p-4 text-sm", "p-2 text-primary"]), "button text-sm p-2 text-primary");
+/// ```
+pub fn tw_merge<'a>(classes: impl IntoIterator<Item = &'a str>) -> String {
+    let classes: Vec<&str> = classes
+        .into_iter()
+        .flat_map(str::split_whitespace)
+        .collect();
+
+    let mut taken = HashSet::new();
+    let mut kept = Vec::new();
+
+    // Walk backwards so that the last class of each group is the one kept
+    for &class in classes.iter().rev() {
+        if kept.contains(&class) {
+            continue;
+        }
+
+        let parsed = ParsedClass::parse(class);
+        if let Some(group) = parsed.group() {
+            if taken.contains(&(parsed.modifiers.clone(), group.clone())) {
+                continue;
+            }
+
+            for conflict in conflicting_groups(&group) {
+                taken.insert((parsed.modifiers.clone(), conflict.to_string()));
+            }
+            taken.insert((parsed.modifiers, group));
+        }
+
+        kept.push(class);
+    }
+
+    kept.reverse();
+    kept.join(" ")
+}
+
+struct ParsedClass<'a> {
+    /// Sorted variants and important flag, classes only conflict if these are equal.
+    modifiers: String,
+    /// The utility without its variants, important flag and negative sign.
+    utility: &'a str,
+}
+
+impl<'a> ParsedClass<'a> {
+    fn parse(class: &'a str) -> Self {
+        let mut variants = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+
+        for (i, c) in class.char_indices() {
+            match c {
+                '[' | '(' => depth += 1,
+                ']' | ')' => depth -= 1,
+                ':' if depth == 0 => {
+                    variants.push(&class[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+
+        let mut utility = &class[start..];
+        let important = match utility
+            .strip_prefix('!')
+            .or_else(|| utility.strip_suffix('!'))
+        {
+            Some(stripped) => {
+                utility = stripped;
+                true
+            }
+            None => false,
+        };
+        let utility = utility.strip_prefix('-').unwrap_or(utility);
+
+        variants.sort_unstable();
+        let mut modifiers = variants.join(":");
+        if important {
+            modifiers.push('!');
+        }
+
+        Self { modifiers, utility }
+    }
+
+    /// The group of utilities setting the same CSS property, None if not a known utility.
+    fn group(&self) -> Option<String> {
+        // Arbitrary properties, e.g. `[mask-type:luminance]`
+        if let Some(property) = self
+            .utility
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(':'))
+            .map(|(property, _)| property)
+        {
+            return Some(format!("[{property}]"));
+        }
+
+        class_group(self.utility).map(str::to_string)
+    }
+}
+
+/// Utilities that are a single keyword.
+const KEYWORDS: &[(&str, &[&str])] = &[
+    (
+        "display",
+        &[
+            "block",
+            "inline-block",
+            "inline",
+            "flex",
+            "inline-flex",
+            "grid",
+            "inline-grid",
+            "hidden",
+            "contents",
+            "table",
+            "table-row",
+            "table-cell",
+            "flow-root",
+            "list-item",
+        ],
+    ),
+    (
+        "position",
+        &["static", "fixed", "absolute", "relative", "sticky"],
+    ),
+    ("visibility", &["visible", "invisible", "collapse"]),
+    (
+        "flex-direction",
+        &[
+            "flex-row",
+            "flex-row-reverse",
+            "flex-col",
+            "flex-col-reverse",
+        ],
+    ),
+    (
+        "flex-wrap",
+        &["flex-wrap", "flex-wrap-reverse", "flex-nowrap"],
+    ),
+    (
+        "text-align",
+        &[
+            "text-left",
+            "text-center",
+            "text-right",
+            "text-justify",
+            "text-start",
+            "text-end",
+        ],
+    ),
+    (
+        "text-wrap",
+        &["text-wrap", "text-nowrap", "text-balance", "text-pretty"],
+    ),
+    ("text-overflow", &["text-ellipsis", "text-clip"]),
+    ("font-style", &["italic", "not-italic"]),
+    (
+        "text-decoration",
+        &["underline", "overline", "line-through", "no-underline"],
+    ),
+    (
+        "text-transform",
+        &["uppercase", "lowercase", "capitalize", "normal-case"],
+    ),
+    (
+        "font-weight",
+        &[
+            "font-thin",
+            "font-extralight",
+            "font-light",
+            "font-normal",
+            "font-medium",
+            "font-semibold",
+            "font-bold",
+            "font-extrabold",
+            "font-black",
+        ],
+    ),
+    ("font-family", &["font-sans", "font-serif", "font-mono"]),
+    (
+        "border-style",
+        &[
+            "border-solid",
+            "border-dashed",
+            "border-dotted",
+            "border-double",
+            "border-hidden",
+            "border-none",
+        ],
+    ),
+    ("border-collapse", &["border-collapse", "border-separate"]),
+    ("border-w", &["border"]),
+    ("rounded", &["rounded"]),
+    ("shadow", &["shadow"]),
+    ("ring-w", &["ring"]),
+    ("bg-size", &["bg-auto", "bg-cover", "bg-contain"]),
+    ("bg-attachment", &["bg-fixed", "bg-local", "bg-scroll"]),
+    (
+        "bg-repeat",
+        &[
+            "bg-repeat",
+            "bg-no-repeat",
+            "bg-repeat-x",
+            "bg-repeat-y",
+            "bg-repeat-round",
+            "bg-repeat-space",
+        ],
+    ),
+    (
+        "bg-position",
+        &[
+            "bg-bottom",
+            "bg-center",
+            "bg-left",
+            "bg-left-bottom",
+            "bg-left-top",
+            "bg-right",
+            "bg-right-bottom",
+            "bg-right-top",
+            "bg-top",
+        ],
+    ),
+    ("bg-image", &["bg-none"]),
+    ("grow", &["grow"]),
+    ("shrink", &["shrink"]),
+    ("transition", &["transition"]),
+];
+
+/// Utilities taking a value, `{prefix}-{value}`, grouped by prefix. More specific prefixes come first.
+const PREFIXES: &[&str] = &[
+    "px",
+    "py",
+    "pt",
+    "pr",
+    "pb",
+    "pl",
+    "ps",
+    "pe",
+    "p",
+    "mx",
+    "my",
+    "mt",
+    "mr",
+    "mb",
+    "ml",
+    "ms",
+    "me",
+    "m",
+    "space-x",
+    "space-y",
+    "size",
+    "min-w",
+    "max-w",
+    "w",
+    "min-h",
+    "max-h",
+    "h",
+    "gap-x",
+    "gap-y",
+    "gap",
+    "inset-x",
+    "inset-y",
+    "inset",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "start",
+    "end",
+    "z",
+    "opacity",
+    "leading",
+    "tracking",
+    "place-content",
+    "place-items",
+    "place-self",
+    "items",
+    "justify-items",
+    "justify-self",
+    "justify",
+    "content",
+    "self",
+    "overflow-x",
+    "overflow-y",
+    "overflow",
+    "cursor",
+    "select",
+    "pointer-events",
+    "grow",
+    "shrink",
+    "basis",
+    "flex",
+    "order",
+    "grid-cols",
+    "grid-rows",
+    "col-span",
+    "row-span",
+    "duration",
+    "ease",
+    "delay",
+    "transition",
+    "border-spacing-x",
+    "border-spacing-y",
+    "border-spacing",
+    "rounded-tl",
+    "rounded-tr",
+    "rounded-br",
+    "rounded-bl",
+    "rounded-t",
+    "rounded-r",
+    "rounded-b",
+    "rounded-l",
+    "rounded-s",
+    "rounded-e",
+    "rounded",
+];
+
+/// Sides of the `border-{side}` utilities.
+const BORDER_SIDES: &[&str] = &["x", "y", "t", "r", "b", "l", "s", "e"];
+
+fn class_group(utility: &str) -> Option<&'static str> {
+    if let Some((group, _)) = KEYWORDS
+        .iter()
+        .find(|(_, keywords)| keywords.contains(&utility))
+    {
+        return Some(group);
+    }
+
+    if let Some(value) = utility.strip_prefix("text-") {
+        return Some(if is_font_size(value) {
+            "font-size"
+        } else {
+            "text-color"
+        });
+    }
+
+    if let Some(value) = utility.strip_prefix("bg-") {
+        return Some(
+            if ["linear-", "gradient-", "radial", "conic", "[url("]
+                .iter()
+                .any(|prefix| value.starts_with(prefix))
+            {
+                "bg-image"
+            } else {
+                "bg-color"
+            },
+        );
+    }
+
+    if let Some(value) = utility.strip_prefix("border-")
+        && !value.starts_with("spacing-")
+    {
+        return Some(border_group(value));
+    }
+
+    if let Some(value) = utility.strip_prefix("shadow-") {
+        return Some(
+            if ["2xs", "xs", "sm", "md", "lg", "xl", "2xl", "none", "inner"].contains(&value) {
+                "shadow"
+            } else {
+                "shadow-color"
+            },
+        );
+    }
+
+    if let Some(value) = utility.strip_prefix("ring-offset-") {
+        return Some(if is_width(value) {
+            "ring-offset-w"
+        } else {
+            "ring-offset-color"
+        });
+    }
+
+    if let Some(value) = utility.strip_prefix("ring-") {
+        return Some(if is_width(value) {
+            "ring-w"
+        } else {
+            "ring-color"
+        });
+    }
+
+    // Other classes sharing a prefix, like `select-item`, are not utilities
+    PREFIXES.iter().copied().find(|prefix| {
+        utility
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .is_some_and(|value| is_prefix_value(prefix, value))
+    })
+}
+
+/// Whether `value` is a value of the `{prefix}-{value}` utility.
+fn is_prefix_value(prefix: &str, value: &str) -> bool {
+    const ALIGNMENTS: &[&str] = &[
+        "start",
+        "end",
+        "center",
+        "between",
+        "around",
+        "evenly",
+        "baseline",
+        "baseline-last",
+        "stretch",
+        "normal",
+        "auto",
+        "left",
+        "right",
+        "start-safe",
+        "end-safe",
+        "center-safe",
+    ];
+    const SIZES: &[&str] = &[
+        "auto", "full", "screen", "svw", "lvw", "dvw", "svh", "lvh", "dvh", "min", "max", "fit",
+        "px", "none", "prose", "lh",
+    ];
+    const CURSORS: &[&str] = &[
+        "auto",
+        "default",
+        "pointer",
+        "wait",
+        "text",
+        "move",
+        "help",
+        "not-allowed",
+        "none",
+        "context-menu",
+        "progress",
+        "cell",
+        "crosshair",
+        "vertical-text",
+        "alias",
+        "copy",
+        "no-drop",
+        "grab",
+        "grabbing",
+        "all-scroll",
+        "col-resize",
+        "row-resize",
+        "n-resize",
+        "e-resize",
+        "s-resize",
+        "w-resize",
+        "ne-resize",
+        "nw-resize",
+        "se-resize",
+        "sw-resize",
+        "ew-resize",
+        "ns-resize",
+        "nesw-resize",
+        "nwse-resize",
+        "zoom-in",
+        "zoom-out",
+    ];
+
+    if is_arbitrary(value) {
+        return true;
+    }
+    let spacing = is_number(value) || value == "px";
+    let keyword = |keywords: &[&str]| keywords.contains(&value);
+
+    match prefix {
+        "px" | "py" | "pt" | "pr" | "pb" | "pl" | "ps" | "pe" | "p" => spacing,
+        "mx" | "my" | "mt" | "mr" | "mb" | "ml" | "ms" | "me" | "m" => spacing || value == "auto",
+        "space-x" | "space-y" | "gap-x" | "gap-y" | "gap" => spacing,
+        "border-spacing-x" | "border-spacing-y" | "border-spacing" => spacing,
+        "size" | "min-w" | "max-w" | "w" | "min-h" | "max-h" | "h" | "basis" => {
+            spacing || is_fraction(value) || keyword(SIZES) || is_named_size(value)
+        }
+        "inset-x" | "inset-y" | "inset" | "top" | "right" | "bottom" | "left" | "start" | "end" => {
+            spacing || is_fraction(value) || keyword(&["auto", "full"])
+        }
+        "z" => is_number(value) || value == "auto",
+        "opacity" | "grow" | "shrink" => is_number(value),
+        "leading" => {
+            is_number(value) || keyword(&["none", "tight", "snug", "normal", "relaxed", "loose"])
+        }
+        "tracking" => keyword(&["tighter", "tight", "normal", "wide", "wider", "widest"]),
+        "place-content" | "place-items" | "place-self" | "items" | "justify-items"
+        | "justify-self" | "justify" | "content" | "self" => keyword(ALIGNMENTS),
+        "overflow-x" | "overflow-y" | "overflow" => {
+            keyword(&["auto", "hidden", "clip", "visible", "scroll"])
+        }
+        "cursor" => keyword(CURSORS),
+        "select" => keyword(&["none", "text", "all", "auto"]),
+        "pointer-events" => keyword(&["none", "auto"]),
+        "flex" => is_number(value) || is_fraction(value) || keyword(&["auto", "initial", "none"]),
+        "order" => is_number(value) || keyword(&["first", "last", "none"]),
+        "grid-cols" | "grid-rows" => is_number(value) || keyword(&["none", "subgrid"]),
+        "col-span" | "row-span" => is_number(value) || value == "full",
+        "duration" | "delay" => is_number(value) || value == "initial",
+        "ease" => keyword(&["linear", "in", "out", "in-out", "initial"]),
+        "transition" => keyword(&["all", "colors", "opacity", "shadow", "transform", "none"]),
+        _ if prefix.starts_with("rounded") => {
+            keyword(&["none", "xs", "sm", "md", "lg", "xl", "full"]) || is_named_size(value)
+        }
+        _ => false,
+    }
+}
+
+/// An integer or decimal number, like `4` or `0.5`.
+fn is_number(value: &str) -> bool {
+    !value.is_empty()
+        && value.chars().any(|c| c.is_ascii_digit())
+        && value.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && value.matches('.').count() <= 1
+}
+
+/// `1/2`
+fn is_fraction(value: &str) -> bool {
+    value
+        .split_once('/')
+        .is_some_and(|(a, b)| is_number(a) && is_number(b))
+}
+
+/// `xs` to `7xl`, and `2xs` or `3xs`.
+fn is_named_size(value: &str) -> bool {
+    ["xs", "sm", "md", "lg", "xl"].contains(&value)
+        || value
+            .strip_suffix("xl")
+            .or_else(|| value.strip_suffix("xs"))
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// `[…]` values or `(…)` CSS variables.
+fn is_arbitrary(value: &str) -> bool {
+    (value.starts_with('[') && value.ends_with(']'))
+        || (value.starts_with('(') && value.ends_with(')'))
+}
+
+fn border_group(value: &str) -> &'static str {
+    let (side, value) = match value.split_once('-') {
+        Some((side, value)) if BORDER_SIDES.contains(&side) => (Some(side), Some(value)),
+        _ if BORDER_SIDES.contains(&value) => (Some(value), None),
+        _ => (None, Some(value)),
+    };
+
+    let is_width = value.is_none_or(is_width);
+
+    match (side, is_width) {
+        (None, true) => "border-w",
+        (None, false) => "border-color",
+        (Some(side), true) => match side {
+            "x" => "border-w-x",
+            "y" => "border-w-y",
+            "t" => "border-w-t",
+            "r" => "border-w-r",
+            "b" => "border-w-b",
+            "l" => "border-w-l",
+            "s" => "border-w-s",
+            _ => "border-w-e",
+        },
+        (Some(side), false) => match side {
+            "x" => "border-color-x",
+            "y" => "border-color-y",
+            "t" => "border-color-t",
+            "r" => "border-color-r",
+            "b" => "border-color-b",
+            "l" => "border-color-l",
+            "s" => "border-color-s",
+            _ => "border-color-e",
+        },
+    }
+}
+
+/// `xs` to `9xl`, or an arbitrary length. A `/` line height modifier is allowed.
+fn is_font_size(value: &str) -> bool {
+    let size = value.split('/').next().unwrap_or(value);
+
+    ["xs", "sm", "base", "lg", "xl"].contains(&size)
+        || size
+            .strip_suffix("xl")
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        || is_arbitrary_length(size)
+}
+
+/// A number of pixels, or an arbitrary length.
+fn is_width(value: &str) -> bool {
+    value.chars().all(|c| c.is_ascii_digit()) || is_arbitrary_length(value)
+}
+
+fn is_arbitrary_length(value: &str) -> bool {
+    let Some(inner) = value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+    else {
+        return false;
+    };
+
+    inner.starts_with("length:")
+        || inner.starts_with("calc(")
+        || inner.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+}
+
+/// Groups that a class of `group` overrides, in addition to its own.
+fn conflicting_groups(group: &str) -> &'static [&'static str] {
+    match group {
+        "p" => &["px", "py", "pt", "pr", "pb", "pl", "ps", "pe"],
+        "px" => &["pr", "pl", "ps", "pe"],
+        "py" => &["pt", "pb"],
+        "m" => &["mx", "my", "mt", "mr", "mb", "ml", "ms", "me"],
+        "mx" => &["mr", "ml", "ms", "me"],
+        "my" => &["mt", "mb"],
+        "size" => &["w", "h"],
+        "gap" => &["gap-x", "gap-y"],
+        "border-spacing" => &["border-spacing-x", "border-spacing-y"],
+        "inset" => &[
+            "inset-x", "inset-y", "top", "right", "bottom", "left", "start", "end",
+        ],
+        "inset-x" => &["right", "left", "start", "end"],
+        "inset-y" => &["top", "bottom"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "font-size" => &["leading"],
+        "rounded" => &[
+            "rounded-t",
+            "rounded-r",
+            "rounded-b",
+            "rounded-l",
+            "rounded-s",
+            "rounded-e",
+            "rounded-tl",
+            "rounded-tr",
+            "rounded-br",
+            "rounded-bl",
+        ],
+        "rounded-t" => &["rounded-tl", "rounded-tr"],
+        "rounded-r" => &["rounded-tr", "rounded-br"],
+        "rounded-b" => &["rounded-br", "rounded-bl"],
+        "rounded-l" => &["rounded-tl", "rounded-bl"],
+        "border-w" => &[
+            "border-w-x",
+            "border-w-y",
+            "border-w-t",
+            "border-w-r",
+            "border-w-b",
+            "border-w-l",
+            "border-w-s",
+            "border-w-e",
+        ],
+        "border-w-x" => &["border-w-r", "border-w-l"],
+        "border-w-y" => &["border-w-t", "border-w-b"],
+        "border-color" => &[
+            "border-color-x",
+            "border-color-y",
+            "border-color-t",
+            "border-color-r",
+            "border-color-b",
+            "border-color-l",
+            "border-color-s",
+            "border-color-e",
+        ],
+        "border-color-x" => &["border-color-r", "border-color-l"],
+        "border-color-y" => &["border-color-t", "border-color-b"],
+        _ => &[],
+    }
+}
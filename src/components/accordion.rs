@@ -1,4 +1,5 @@
 use crate::components::icon::*;
+use crate::components::variants::*;
 use dioxus::prelude::*;
 use dioxus_core::AttributeValue;
 
@@ -164,6 +165,9 @@ pub struct AccordionContentProps {
     #[props(optional)]
     height: ReadSignal<String>,
 
+    #[props(optional)]
+    animation: Option<Animation>,
+
     children: Element,
 }
 
@@ -186,6 +190,11 @@ pub fn AccordionContent(mut props: AccordionContentProps) -> Element {
         false => "0".to_string(),
     };
 
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
     let attributes = props.attributes.clone();
 
     let default_classes = "accordion-content";
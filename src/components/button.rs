@@ -1,5 +1,22 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
+/// Variant of a button, rendered as `data-variant`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonVariant {
+    Outline,
+    Ghost,
+}
+
+impl ButtonVariant {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ButtonVariant::Outline => "outline",
+            ButtonVariant::Ghost => "ghost",
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Props)]
 pub struct ButtonProps {
     /// Additional attributes to apply to the element
@@ -19,6 +36,15 @@ pub struct ButtonProps {
     #[props(optional)]
     onmouseup: EventHandler<MouseEvent>,
 
+    #[props(optional)]
+    color: Option<Color>,
+    #[props(optional)]
+    variant: Option<ButtonVariant>,
+    #[props(optional)]
+    size: Option<Size>,
+    #[props(optional)]
+    animation: Option<Animation>,
+
     /// Remove default CSS classes
     #[props(default = false)]
     noclasses: bool,
@@ -31,6 +57,26 @@ pub struct ButtonProps {
 pub fn Button(mut props: ButtonProps) -> Element {
     let default_classes = "button";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-variant",
+        props.variant.map(|variant| variant.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-size",
+        props.size.map(|size| size.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     let onclick = move |event| props.onclick.call(event);
     let ondoubleclick = move |event| props.ondoubleclick.call(event);
@@ -0,0 +1,510 @@
+use crate::components::icon::*;
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
+use dioxus::prelude::*;
+
+/// Dates selected in a [`Calendar`], its variant sets how clicking a day selects it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateSelection {
+    Single(Option<NaiveDate>),
+    /// Start and end, both included
+    Range(Option<NaiveDate>, Option<NaiveDate>),
+    /// Sorted dates
+    Multiple(Vec<NaiveDate>),
+}
+
+impl Default for DateSelection {
+    fn default() -> Self {
+        DateSelection::Single(None)
+    }
+}
+
+impl DateSelection {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        match self {
+            DateSelection::Single(selected) => *selected == Some(date),
+            DateSelection::Range(Some(start), Some(end)) => (*start..=*end).contains(&date),
+            DateSelection::Range(start, _) => *start == Some(date),
+            DateSelection::Multiple(dates) => dates.contains(&date),
+        }
+    }
+
+    /// Replaces a single date, starts or ends a range, or toggles one of multiple dates
+    pub fn select(&mut self, date: NaiveDate) {
+        match self {
+            DateSelection::Single(selected) => *selected = Some(date),
+            DateSelection::Range(Some(start), end @ None) if date < *start => {
+                *end = Some(*start);
+                *start = date;
+            }
+            DateSelection::Range(Some(_), end @ None) => *end = Some(date),
+            DateSelection::Range(start, end) => {
+                *start = Some(date);
+                *end = None;
+            }
+            DateSelection::Multiple(dates) => match dates.binary_search(&date) {
+                Ok(index) => {
+                    dates.remove(index);
+                }
+                Err(index) => dates.insert(index, date),
+            },
+        }
+    }
+
+    /// Like [`DateSelection::select`], except that a range is never completed over a date
+    /// `is_disabled` rejects: it starts over from `date` instead
+    pub fn select_enabled(&mut self, date: NaiveDate, is_disabled: impl Fn(NaiveDate) -> bool) {
+        if let DateSelection::Range(Some(start), None) = *self {
+            let (first, last) = (start.min(date), start.max(date));
+            if first
+                .iter_days()
+                .take_while(|day| *day <= last)
+                .any(is_disabled)
+            {
+                *self = DateSelection::Range(Some(date), None);
+                return;
+            }
+        }
+        self.select(date);
+    }
+
+    /// Whether a date, both ends of a range or at least one of multiple dates are selected
+    pub fn is_complete(&self) -> bool {
+        match self {
+            DateSelection::Single(selected) => selected.is_some(),
+            DateSelection::Range(start, end) => start.is_some() && end.is_some(),
+            DateSelection::Multiple(dates) => !dates.is_empty(),
+        }
+    }
+
+    /// Earliest selected date
+    pub fn first(&self) -> Option<NaiveDate> {
+        match self {
+            DateSelection::Single(selected) => *selected,
+            DateSelection::Range(start, _) => *start,
+            DateSelection::Multiple(dates) => dates.first().copied(),
+        }
+    }
+
+    /// Formats the dates with a `chrono` format string, e.g. `"%d/%m/%Y"`
+    pub fn format(&self, format: &str) -> String {
+        let format_date = |date: &NaiveDate| date.format(format).to_string();
+        match self {
+            DateSelection::Single(selected) => {
+                selected.as_ref().map(format_date).unwrap_or_default()
+            }
+            DateSelection::Range(start, end) => match (start, end) {
+                (Some(start), Some(end)) => {
+                    format!("{} – {}", format_date(start), format_date(end))
+                }
+                (Some(start), None) => format!("{} – …", format_date(start)),
+                _ => String::new(),
+            },
+            DateSelection::Multiple(dates) => {
+                dates.iter().map(format_date).collect::<Vec<_>>().join(", ")
+            }
+        }
+    }
+
+    /// ISO 8601 dates, ranges as `start/end` and multiple dates separated by commas
+    fn to_form_value(&self) -> String {
+        match self {
+            DateSelection::Range(Some(start), Some(end)) => format!("{start}/{end}"),
+            DateSelection::Range(..) => String::new(),
+            selection => selection.format("%Y-%m-%d").replace(' ', ""),
+        }
+    }
+
+    /// Reads a value written by `to_form_value` into a selection of the same kind as `self`
+    fn parse_form_value(&self, text: &str) -> Option<DateSelection> {
+        let parse = |date: &str| date.parse::<NaiveDate>().ok();
+        Some(match self {
+            DateSelection::Single(_) if text.is_empty() => DateSelection::Single(None),
+            DateSelection::Single(_) => DateSelection::Single(Some(parse(text)?)),
+            DateSelection::Range(..) if text.is_empty() => DateSelection::Range(None, None),
+            DateSelection::Range(..) => {
+                let (start, end) = text.split_once('/')?;
+                DateSelection::Range(Some(parse(start)?), Some(parse(end)?))
+            }
+            DateSelection::Multiple(_) => DateSelection::Multiple(
+                text.split(',')
+                    .filter(|date| !date.is_empty())
+                    .map(parse)
+                    .collect::<Option<_>>()?,
+            ),
+        })
+    }
+}
+
+fn days_from_week_start(date: NaiveDate, week_start: Weekday) -> u64 {
+    let days = 7 + date.weekday().num_days_from_monday() - week_start.num_days_from_monday();
+    u64::from(days % 7)
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+fn last_of_month(date: NaiveDate) -> NaiveDate {
+    first_of_month(date)
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(date)
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct CalendarProps {
+    #[props(extends = div, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Its variant sets the selection mode, a single date by default
+    #[props(default)]
+    default_value: DateSelection,
+
+    /// Controls the selection, the calendar keeps its own starting from `default_value` if not given
+    #[props(optional)]
+    value: Option<Signal<DateSelection>>,
+
+    /// Earliest selectable date, the bounds are swapped if it comes after `max`
+    #[props(optional)]
+    min: Option<NaiveDate>,
+    #[props(optional)]
+    max: Option<NaiveDate>,
+
+    /// Returns true for the dates that cannot be selected. A range cannot span them:
+    /// ending it past one starts a new range instead.
+    #[props(optional)]
+    is_disabled: Callback<NaiveDate, bool>,
+
+    #[props(default = Weekday::Mon)]
+    week_start: Weekday,
+
+    /// Month shown first, defaults to the month of the first selected date, or today
+    #[props(optional)]
+    default_month: Option<NaiveDate>,
+
+    #[props(optional)]
+    onchange: EventHandler<DateSelection>,
+}
+
+/// A month grid selecting a date, a range or multiple dates.
+///
+/// The grid is focused as a whole: the arrow keys move between days, PageUp and PageDown
+/// between months (years with Shift), Home and End to the ends of the week, and Enter or
+/// Space select the focused day.
+#[component]
+pub fn Calendar(mut props: CalendarProps) -> Element {
+    let default_classes = "calendar";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let id = use_hook(crate::use_unique_id);
+    let mut selection = crate::use_controlled(props.value, || props.default_value.clone());
+    let mut focused = use_signal(|| {
+        props
+            .default_month
+            .or_else(|| selection.peek().first())
+            .unwrap_or_else(|| Local::now().date_naive())
+    });
+
+    let (min, max) = match (props.min, props.max) {
+        (Some(min), Some(max)) if min > max => (Some(max), Some(min)),
+        bounds => bounds,
+    };
+    let week_start = props.week_start;
+    let is_disabled = props.is_disabled;
+    let disabled = move |date: NaiveDate| {
+        min.is_some_and(|min| date < min)
+            || max.is_some_and(|max| date > max)
+            || is_disabled.call(date)
+    };
+
+    let mut move_focus = move |date: Option<NaiveDate>| {
+        if let Some(date) = date {
+            focused.set(date.clamp(min.unwrap_or(NaiveDate::MIN), max.unwrap_or(NaiveDate::MAX)));
+        }
+    };
+
+    let mut select = move |date: NaiveDate| {
+        if disabled(date) {
+            return;
+        }
+        focused.set(date);
+        let mut next = selection.peek().clone();
+        next.select_enabled(date, disabled);
+        selection.set(next.clone());
+        props.onchange.call(next);
+    };
+
+    let onkeydown = move |event: KeyboardEvent| {
+        let date = focused();
+        let shift = event.modifiers().shift();
+        let target = match event.key() {
+            Key::ArrowLeft => date.pred_opt(),
+            Key::ArrowRight => date.succ_opt(),
+            Key::ArrowUp => date.checked_sub_days(Days::new(7)),
+            Key::ArrowDown => date.checked_add_days(Days::new(7)),
+            Key::PageUp => date.checked_sub_months(Months::new(if shift { 12 } else { 1 })),
+            Key::PageDown => date.checked_add_months(Months::new(if shift { 12 } else { 1 })),
+            Key::Home => date.checked_sub_days(Days::new(days_from_week_start(date, week_start))),
+            Key::End => {
+                date.checked_add_days(Days::new(6 - days_from_week_start(date, week_start)))
+            }
+            Key::Enter => {
+                select(date);
+                None
+            }
+            Key::Character(character) if character == " " => {
+                select(date);
+                None
+            }
+            _ => return,
+        };
+        event.prevent_default();
+        move_focus(target);
+    };
+
+    let focused_date = focused();
+    let month_start = first_of_month(focused_date);
+    let month_end = last_of_month(focused_date);
+    let grid_start = month_start - Days::new(days_from_week_start(month_start, week_start));
+    let weeks =
+        (days_from_week_start(month_start, week_start) + u64::from(month_end.day())).div_ceil(7);
+    let weekdays: Vec<Weekday> = std::iter::successors(Some(week_start), |day| Some(day.succ()))
+        .take(7)
+        .collect();
+
+    let today = Local::now().date_naive();
+    let selected = selection.read().clone();
+    let (range_start, range_end) = match selected {
+        DateSelection::Range(start, end) => (start, end),
+        _ => (None, None),
+    };
+
+    let has_previous = min.is_none_or(|min| month_start > min);
+    let has_next = max.is_none_or(|max| month_end < max);
+    let mut navigate = move |months: Months, forward: bool| {
+        move_focus(if forward {
+            focused_date.checked_add_months(months)
+        } else {
+            focused_date.checked_sub_months(months)
+        })
+    };
+
+    rsx! {
+        div { ..props.attributes,
+            div { class: "calendar-header",
+                button {
+                    class: "calendar-nav",
+                    r#type: "button",
+                    aria_label: "Previous year",
+                    disabled: !has_previous,
+                    onclick: move |_| navigate(Months::new(12), false),
+                    Icon { icon: Icons::KeyboardDoubleArrowLeft }
+                }
+                button {
+                    class: "calendar-nav",
+                    r#type: "button",
+                    aria_label: "Previous month",
+                    disabled: !has_previous,
+                    onclick: move |_| navigate(Months::new(1), false),
+                    Icon { icon: Icons::ChevronLeft }
+                }
+                span { class: "calendar-title", aria_live: "polite",
+                    "{focused_date.format(\"%B %Y\")}"
+                }
+                button {
+                    class: "calendar-nav",
+                    r#type: "button",
+                    aria_label: "Next month",
+                    disabled: !has_next,
+                    onclick: move |_| navigate(Months::new(1), true),
+                    Icon { icon: Icons::ChevronRight }
+                }
+                button {
+                    class: "calendar-nav",
+                    r#type: "button",
+                    aria_label: "Next year",
+                    disabled: !has_next,
+                    onclick: move |_| navigate(Months::new(12), true),
+                    Icon { icon: Icons::KeyboardDoubleArrowRight }
+                }
+            }
+            table {
+                class: "calendar-grid",
+                role: "grid",
+                tabindex: "0",
+                "aria-multiselectable": !matches!(selected, DateSelection::Single(_)),
+                "aria-activedescendant": "{id}-{focused_date}",
+                onkeydown,
+                thead {
+                    tr {
+                        for weekday in weekdays {
+                            th { class: "calendar-weekday", scope: "col", "{weekday}" }
+                        }
+                    }
+                }
+                tbody {
+                    for week in 0..weeks {
+                        tr { key: "{week}",
+                            for date in (0..7).filter_map(|day| grid_start.checked_add_days(Days::new(week * 7 + day))) {
+                                td {
+                                    key: "{date}",
+                                    id: "{id}-{date}",
+                                    class: "calendar-day",
+                                    aria_selected: selected.contains(date),
+                                    aria_disabled: disabled(date),
+                                    "data-outside": date.month() != month_start.month(),
+                                    "data-today": date == today,
+                                    "data-focused": date == focused_date,
+                                    "data-range-start": range_start == Some(date),
+                                    "data-range-end": range_end == Some(date),
+                                    onclick: move |_| select(date),
+                                    "{date.day()}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct DatePickerProps {
+    #[props(extends = div, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Its variant sets the selection mode, a single date by default
+    #[props(default)]
+    default_value: DateSelection,
+
+    /// Controls the selection, the picker keeps its own starting from `default_value` if not given
+    #[props(optional)]
+    value: Option<Signal<DateSelection>>,
+
+    #[props(optional)]
+    min: Option<NaiveDate>,
+    #[props(optional)]
+    max: Option<NaiveDate>,
+
+    /// Returns true for the dates that cannot be selected
+    #[props(optional)]
+    is_disabled: Callback<NaiveDate, bool>,
+
+    #[props(default = Weekday::Mon)]
+    week_start: Weekday,
+
+    /// `chrono` format of the dates shown in the trigger
+    #[props(default = "%Y-%m-%d".to_string())]
+    format: String,
+
+    #[props(default = "Pick a date".to_string())]
+    placeholder: String,
+
+    #[props(optional)]
+    onchange: EventHandler<DateSelection>,
+}
+
+/// A button opening a [`Calendar`] in a popover, closed once a date or a whole range is
+/// selected, by Escape or by clicking outside.
+#[component]
+pub fn DatePicker(mut props: DatePickerProps) -> Element {
+    let default_classes = "datepicker";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let mut open = use_signal(|| false);
+    let mut selection = crate::use_controlled(props.value, || props.default_value.clone());
+
+    let field = crate::use_field_binding(|| selection.peek().to_form_value());
+    field.use_controlled_value(props.value.is_some(), move || {
+        selection.read().to_form_value()
+    });
+
+    // The field holds the selection when uncontrolled, so that resetting the form clears it.
+    // A range waiting for its end has no form value yet and is left alone.
+    let controlled = props.value.is_some();
+    use_effect({
+        let field = field.clone();
+        move || {
+            let own = selection.peek().to_form_value();
+            let text = field.value_or(controlled, own.clone());
+            if text == own {
+                return;
+            }
+            let next = selection.peek().parse_form_value(&text);
+            if let Some(next) = next {
+                selection.set(next);
+            }
+        }
+    });
+    let mut trigger_attributes = Vec::new();
+    field.setup_attributes(&mut trigger_attributes);
+
+    let mut close = {
+        let field = field.clone();
+        move || {
+            open.set(false);
+            field.touch();
+        }
+    };
+
+    let onchange = {
+        let mut close = close.clone();
+        move |next: DateSelection| {
+            field.set_value(next.to_form_value());
+            if next.is_complete() && !matches!(next, DateSelection::Multiple(_)) {
+                close();
+            }
+            props.onchange.call(next);
+        }
+    };
+
+    let onkeydown = {
+        let mut close = close.clone();
+        move |event: KeyboardEvent| {
+            if event.key() == Key::Escape && open() {
+                event.prevent_default();
+                close();
+            }
+        }
+    };
+
+    let label = selection.read().format(&props.format);
+    let is_empty = label.is_empty();
+
+    rsx! {
+        div {
+            "data-state": if open() { "open" } else { "closed" },
+            onkeydown,
+            ..props.attributes,
+            button {
+                class: "datepicker-trigger",
+                r#type: "button",
+                aria_haspopup: "dialog",
+                aria_expanded: open(),
+                "data-placeholder": is_empty,
+                onclick: move |_| open.toggle(),
+                ..trigger_attributes,
+                Icon { icon: Icons::CalendarMonth }
+                if is_empty {
+                    "{props.placeholder}"
+                } else {
+                    "{label}"
+                }
+            }
+            if open() {
+                div { class: "datepicker-backdrop", onclick: move |_| close() }
+                div { class: "datepicker-content", role: "dialog",
+                    Calendar {
+                        value: selection,
+                        min: props.min,
+                        max: props.max,
+                        is_disabled: props.is_disabled,
+                        week_start: props.week_start,
+                        onchange,
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,36 @@
 use crate::components::icon::*;
 use dioxus::prelude::*;
-use dioxus_core::AttributeValue;
+
+/// Variant of a callout, rendered as `data-variant`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CalloutVariant {
+    #[default]
+    Note,
+    Tip,
+    Warning,
+    Caution,
+}
+
+impl CalloutVariant {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            CalloutVariant::Note => "note",
+            CalloutVariant::Tip => "tip",
+            CalloutVariant::Warning => "warning",
+            CalloutVariant::Caution => "caution",
+        }
+    }
+
+    /// Icon shown in front of the title when the callout has none.
+    pub const fn icon(&self) -> Icons {
+        match self {
+            CalloutVariant::Note => Icons::Info,
+            CalloutVariant::Tip => Icons::Lightbulb,
+            CalloutVariant::Warning => Icons::Warning,
+            CalloutVariant::Caution => Icons::Report,
+        }
+    }
+}
 
 #[derive(Clone, PartialEq, Props)]
 pub struct CalloutProps {
@@ -9,6 +39,10 @@ pub struct CalloutProps {
 
     title: std::string::String,
 
+    /// Also picks the default icon
+    #[props(optional, default)]
+    pub variant: Option<CalloutVariant>,
+
     #[props(optional, default)]
     pub icon: Option<Icons>,
 
@@ -17,34 +51,24 @@ pub struct CalloutProps {
 
 #[component]
 pub fn Callout(mut props: CalloutProps) -> Element {
-    let icon = if let Some(data_attribute) = props
-        .attributes
-        .iter()
-        .find(|attr| attr.name == "data-variant")
-    {
-        if let AttributeValue::Text(ref value) = data_attribute.value {
-            match value.as_str() {
-                "tip" => Icons::Lightbulb,
-                "warning" => Icons::Warning,
-                "caution" => Icons::Report,
-                "info" | &_ => Icons::Info,
-            }
-        } else {
-            Icons::Info
-        }
-    } else {
-        Icons::Info
-    };
+    let icon = props
+        .icon
+        .unwrap_or(props.variant.unwrap_or_default().icon());
 
     let default_classes = "callout";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-variant",
+        props.variant.map(|variant| variant.as_str()),
+    );
 
     rsx! {
         div {..props.attributes,
             div { style: "display: flex; flex-direction: row; vertical-align: middle;",
                 Icon {
                     style: "margin-right: 0.5rem; font-size: 0.875rem;",
-                    icon: if props.icon.is_some() { props.icon.unwrap() } else { icon },
+                    icon,
                 }
                 "{props.title}"
             }
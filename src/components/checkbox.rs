@@ -9,8 +9,9 @@ pub struct CheckboxProps {
     #[props(optional)]
     default_checked: bool,
 
+    /// Controls the state, the checkbox keeps its own starting from `default_checked` if not given
     #[props(optional)]
-    checked: Signal<bool>,
+    checked: Option<Signal<bool>>,
 
     /// Return value determines if the event should strop propagation (false by default)
     #[props(optional)]
@@ -22,7 +23,15 @@ pub fn Checkbox(mut props: CheckboxProps) -> Element {
     let default_classes = "checkbox";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
-    let mut checked = use_signal(|| props.default_checked);
+    let mut checked = crate::use_controlled(props.checked, || props.default_checked);
+
+    // Checked checkboxes are "on" in the form, like HTML forms
+    let to_form_value = |checked: bool| if checked { "on" } else { "" }.to_string();
+    let controlled = props.checked.is_some();
+    let field = crate::use_field_binding(|| to_form_value(*checked.peek()));
+    field.setup_attributes(&mut props.attributes);
+    field.use_controlled_value(controlled, move || to_form_value(checked()));
+    let is_checked = field.value_or(controlled, to_form_value(checked())) == "on";
 
     let id = crate::use_unique_id();
 
@@ -36,11 +45,12 @@ pub fn Checkbox(mut props: CheckboxProps) -> Element {
         button {
             type: "button",
             role: "checkbox",
-            "data-checked": if *checked.read() { "checked" } else { "unchecked" },
+            "data-checked": if is_checked { "checked" } else { "unchecked" },
             onclick: move |event| {
-                let new_checked = !checked();
+                let new_checked = !is_checked;
                 checked.set(new_checked);
-                props.checked.set(new_checked);
+                field.set_value(to_form_value(new_checked));
+                field.touch();
                 if props.onchange.call(new_checked) {
                     event.stop_propagation();
                 }
@@ -54,7 +64,7 @@ pub fn Checkbox(mut props: CheckboxProps) -> Element {
             },
             ..props.attributes,
             span { class: "checkbox-indicator",
-                if *checked.read() {
+                if is_checked {
                     Icon {
                         icon: Icons::Check
                     }
@@ -64,7 +74,7 @@ pub fn Checkbox(mut props: CheckboxProps) -> Element {
         input {
             id,
             r#type: "checkbox",
-            checked: *checked.read(),
+            checked: is_checked,
             aria_hidden: "true",
             tabindex: "-1",
             position: "absolute",
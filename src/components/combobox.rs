@@ -0,0 +1,455 @@
+use crate::components::icon::*;
+use dioxus::prelude::*;
+use std::{future::Future, pin::Pin, rc::Rc};
+
+/// An option of a [`Combobox`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComboboxOption {
+    pub value: String,
+    pub label: String,
+    pub disabled: bool,
+}
+
+impl ComboboxOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Shows the option without letting it be selected
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+/// An option labelled by its value
+impl From<&str> for ComboboxOption {
+    fn from(value: &str) -> Self {
+        ComboboxOption::new(value, value)
+    }
+}
+
+type Load = Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Vec<ComboboxOption>>>>>;
+
+/// Loads the options of a [`Combobox`] matching the typed query.
+#[derive(Clone)]
+pub struct OptionLoader(Load);
+
+impl OptionLoader {
+    pub fn new<F>(load: impl Fn(String) -> F + 'static) -> Self
+    where
+        F: Future<Output = Vec<ComboboxOption>> + 'static,
+    {
+        Self(Rc::new(move |query| Box::pin(load(query))))
+    }
+}
+
+impl PartialEq for OptionLoader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for OptionLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("OptionLoader")
+    }
+}
+
+/// An entry of the listbox of a [`Combobox`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComboboxItem {
+    Option(ComboboxOption),
+    /// Creates an option from the query
+    Create(String),
+}
+
+impl ComboboxItem {
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, ComboboxItem::Option(option) if option.disabled)
+    }
+
+    /// Entries listed for `query`: the options whose label contains it, or all of them
+    /// without `filter`, then one creating it if `creatable` and no label is the query
+    pub fn list(
+        options: &[ComboboxOption],
+        query: &str,
+        filter: bool,
+        creatable: bool,
+    ) -> Vec<ComboboxItem> {
+        let needle = query.trim().to_lowercase();
+        let mut items: Vec<ComboboxItem> = options
+            .iter()
+            .filter(|option| !filter || option.label.to_lowercase().contains(&needle))
+            .cloned()
+            .map(ComboboxItem::Option)
+            .collect();
+        let exists = items.iter().any(|item| {
+            matches!(item, ComboboxItem::Option(option) if option.label.to_lowercase() == needle)
+        });
+        if creatable && !needle.is_empty() && !exists {
+            items.push(ComboboxItem::Create(query.trim().to_string()));
+        }
+        items
+    }
+
+    /// Index of the next enabled item after `from`, wrapping around
+    pub fn step(items: &[ComboboxItem], from: usize, forward: bool) -> usize {
+        let len = items.len();
+        (1..=len)
+            .map(|offset| match forward {
+                true => (from + offset) % len,
+                false => (from + len - offset % len) % len,
+            })
+            .find(|index| !items[*index].is_disabled())
+            .unwrap_or(from)
+    }
+
+    /// Index of the first enabled item
+    pub fn first(items: &[ComboboxItem]) -> usize {
+        ComboboxItem::step(items, items.len().saturating_sub(1), true)
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct ComboboxProps {
+    #[props(extends = div, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    #[props(default)]
+    options: Vec<ComboboxOption>,
+
+    /// Loads the options matching the query, instead of filtering `options`
+    #[props(optional)]
+    loader: Option<OptionLoader>,
+
+    #[props(default)]
+    default_value: Vec<String>,
+
+    /// Controls the selected values, the combobox keeps its own starting from `default_value` if not given
+    #[props(optional)]
+    value: Option<Signal<Vec<String>>>,
+
+    /// Selects any number of values, shown as removable tags
+    #[props(default)]
+    multiple: bool,
+
+    /// Offers to create an option from a query matching none
+    #[props(default)]
+    creatable: bool,
+
+    #[props(default = "Search...".to_string())]
+    placeholder: String,
+
+    #[props(default = "No results".to_string())]
+    no_results: String,
+
+    #[props(optional)]
+    onchange: EventHandler<Vec<String>>,
+
+    /// Called with the query an option was created from
+    #[props(optional)]
+    oncreate: EventHandler<String>,
+}
+
+/// A text input filtering a listbox of options as it is typed into.
+///
+/// The arrow keys move through the options, Enter selects one, Escape closes the list, and
+/// Backspace with an empty query removes the last tag, or clears the value of a single select.
+#[component]
+pub fn Combobox(mut props: ComboboxProps) -> Element {
+    let default_classes = "combobox";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let id = use_hook(crate::use_unique_id);
+    let mut value = crate::use_controlled(props.value, || props.default_value.clone());
+    let mut query = use_signal(String::new);
+    let mut open = use_signal(|| false);
+    let mut active = use_signal(|| 0usize);
+    // Created and selected options, kept to label the values the options may not list anymore
+    let mut known = use_signal(Vec::<ComboboxOption>::new);
+    let mut loaded = use_signal(|| None::<Vec<ComboboxOption>>);
+    let mut loading = use_signal(|| false);
+    let mut generation = use_signal(|| 0usize);
+
+    let field = crate::use_field_binding(|| value.peek().join(","));
+    field.use_controlled_value(props.value.is_some(), move || value.read().join(","));
+    let mut input_attributes = Vec::new();
+    field.setup_attributes(&mut input_attributes);
+
+    let multiple = props.multiple;
+    let options: Vec<ComboboxOption> = props
+        .options
+        .iter()
+        .chain(
+            known
+                .read()
+                .iter()
+                .filter(|option| !props.options.iter().any(|o| o.value == option.value)),
+        )
+        .cloned()
+        .collect();
+    let label_of = |value: &str| {
+        options
+            .iter()
+            .find(|option| option.value == value)
+            .map_or(value.to_string(), |option| option.label.clone())
+    };
+
+    // The loaded options already match the query
+    let items_for = {
+        let options = options.clone();
+        let (has_loader, creatable) = (props.loader.is_some(), props.creatable);
+        move |query: &str| match has_loader {
+            true => ComboboxItem::list(
+                loaded.read().as_deref().unwrap_or_default(),
+                query,
+                false,
+                creatable,
+            ),
+            false => ComboboxItem::list(&options, query, true, creatable),
+        }
+    };
+    let items = items_for(&query.read());
+
+    let set_values = {
+        let field = field.clone();
+        move |next: Vec<String>| {
+            value.set(next.clone());
+            field.set_value(next.join(","));
+            props.onchange.call(next);
+        }
+    };
+
+    let choose = {
+        let mut set_values = set_values.clone();
+        let items_for = items_for.clone();
+        move |item: ComboboxItem| {
+            let option = match item {
+                ComboboxItem::Option(option) if option.disabled => return,
+                ComboboxItem::Option(option) => option,
+                ComboboxItem::Create(label) => {
+                    props.oncreate.call(label.clone());
+                    ComboboxOption::new(label.clone(), label)
+                }
+            };
+            if !known.peek().iter().any(|o| o.value == option.value) {
+                known.write().push(option.clone());
+            }
+
+            let mut next = value.peek().clone();
+            if !multiple {
+                next = vec![option.value];
+                open.set(false);
+            } else if let Some(index) = next.iter().position(|v| *v == option.value) {
+                next.remove(index);
+            } else {
+                next.push(option.value);
+            }
+            query.set(String::new());
+            active.set(ComboboxItem::first(&items_for("")));
+            set_values(next);
+        }
+    };
+
+    let remove = {
+        let mut set_values = set_values.clone();
+        move |removed: String| {
+            let next = value
+                .peek()
+                .iter()
+                .filter(|v| **v != removed)
+                .cloned()
+                .collect();
+            set_values(next);
+        }
+    };
+
+    let load = {
+        let loader = props.loader.clone();
+        let items_for = items_for.clone();
+        move |text: String| {
+            let Some(loader) = loader.clone() else {
+                return;
+            };
+            *generation.write() += 1;
+            let current = *generation.peek();
+            loading.set(true);
+            let items_for = items_for.clone();
+            spawn(async move {
+                let options = (loader.0)(text.clone()).await;
+                // Results of an outdated query are dropped
+                if *generation.peek() == current {
+                    loaded.set(Some(options));
+                    loading.set(false);
+                    active.set(ComboboxItem::first(&items_for(&text)));
+                }
+            });
+        }
+    };
+
+    let oninput = {
+        let mut load = load.clone();
+        let items_for = items_for.clone();
+        move |event: FormEvent| {
+            let text = event.data.value();
+            query.set(text.clone());
+            open.set(true);
+            active.set(ComboboxItem::first(&items_for(&text)));
+            load(text);
+        }
+    };
+
+    let onfocus = {
+        let mut load = load.clone();
+        move |_| {
+            open.set(true);
+            if loaded.peek().is_none() && !*loading.peek() {
+                load(query.peek().clone());
+            }
+        }
+    };
+
+    let onblur = {
+        let field = field.clone();
+        move |_| {
+            open.set(false);
+            query.set(String::new());
+            field.touch();
+        }
+    };
+
+    let onkeydown = {
+        let items = items.clone();
+        let mut choose = choose.clone();
+        let mut remove = remove.clone();
+        move |event: KeyboardEvent| match event.key() {
+            Key::ArrowDown | Key::ArrowUp => {
+                event.prevent_default();
+                if !open() {
+                    open.set(true);
+                } else if !items.is_empty() {
+                    active.set(ComboboxItem::step(
+                        &items,
+                        active(),
+                        event.key() == Key::ArrowDown,
+                    ));
+                }
+            }
+            Key::Enter => {
+                if let Some(item) = items.get(active()).filter(|_| open()) {
+                    event.prevent_default();
+                    choose(item.clone());
+                }
+            }
+            Key::Escape => open.set(false),
+            Key::Backspace if query.read().is_empty() => {
+                let last = value.peek().last().cloned();
+                if let Some(last) = last {
+                    remove(last);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    let selected = value.read().clone();
+    let input_value = match !multiple && !open() {
+        true => selected
+            .first()
+            .map(|value| label_of(value))
+            .unwrap_or_default(),
+        false => query(),
+    };
+    let placeholder = match multiple && !selected.is_empty() {
+        true => String::new(),
+        false => props.placeholder.clone(),
+    };
+    let active_index = active().min(items.len().saturating_sub(1));
+    let active_descendant =
+        (open() && !items.is_empty()).then(|| format!("{id}-option-{active_index}"));
+
+    rsx! {
+        div { "data-state": if open() { "open" } else { "closed" }, ..props.attributes,
+            div { class: "combobox-control",
+                if multiple {
+                    for selected_value in selected.iter().cloned() {
+                        span { key: "{selected_value}", class: "combobox-tag",
+                            "{label_of(&selected_value)}"
+                            button {
+                                class: "combobox-tag-remove",
+                                r#type: "button",
+                                tabindex: "-1",
+                                aria_label: "Remove {label_of(&selected_value)}",
+                                onclick: {
+                                    let mut remove = remove.clone();
+                                    move |_| remove(selected_value.clone())
+                                },
+                                Icon { icon: Icons::Close }
+                            }
+                        }
+                    }
+                }
+                input {
+                    class: "combobox-input",
+                    r#type: "text",
+                    role: "combobox",
+                    autocomplete: "off",
+                    aria_autocomplete: "list",
+                    aria_expanded: open(),
+                    aria_controls: "{id}-listbox",
+                    "aria-activedescendant": active_descendant,
+                    placeholder,
+                    value: input_value,
+                    oninput,
+                    onfocus,
+                    onblur,
+                    onkeydown,
+                    ..input_attributes,
+                }
+                Icon { class: "combobox-chevron", icon: Icons::ExpandMore }
+            }
+            if open() {
+                ul {
+                    id: "{id}-listbox",
+                    class: "combobox-listbox",
+                    role: "listbox",
+                    aria_multiselectable: multiple,
+                    // Keeps the focus in the input
+                    onmousedown: move |event| event.prevent_default(),
+                    if loading() {
+                        li { class: "combobox-status", "Loading..." }
+                    } else if items.is_empty() {
+                        li { class: "combobox-status", "{props.no_results}" }
+                    }
+                    for (index, item) in items.into_iter().enumerate() {
+                        li {
+                            key: "{index}",
+                            id: "{id}-option-{index}",
+                            class: "combobox-option",
+                            role: "option",
+                            aria_selected: matches!(&item, ComboboxItem::Option(option) if selected.contains(&option.value)),
+                            aria_disabled: item.is_disabled(),
+                            "data-active": index == active_index,
+                            "data-create": matches!(item, ComboboxItem::Create(_)),
+                            onmouseenter: move |_| active.set(index),
+                            onclick: {
+                                let mut choose = choose.clone();
+                                let item = item.clone();
+                                move |_| choose(item.clone())
+                            },
+                            match &item {
+                                ComboboxItem::Option(option) => rsx! { "{option.label}" },
+                                ComboboxItem::Create(label) => rsx! { "Create \"{label}\"" },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
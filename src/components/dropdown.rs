@@ -1,4 +1,5 @@
-use crate::dioxus_core::IntoAttributeValue;
+use crate::components::variants::*;
+use dioxus::dioxus_core::IntoAttributeValue;
 use dioxus::prelude::*;
 use dioxus_core::AttributeValue;
 
@@ -102,6 +103,9 @@ pub struct DropdownContentProps {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
+    #[props(optional)]
+    animation: Option<Animation>,
+
     children: Element,
 }
 
@@ -111,6 +115,11 @@ pub fn DropdownContent(mut props: DropdownContentProps) -> Element {
 
     let default_classes = "dropdown-content";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     rsx! {
         div {
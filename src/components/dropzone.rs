@@ -0,0 +1,436 @@
+use crate::components::{icon::*, progressbar::*, variants::*};
+use base64::Engine;
+use dioxus::html::FileData;
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+
+/// A file read by a [`FileDropzone`].
+#[derive(Clone, PartialEq)]
+pub struct UploadedFile {
+    /// Identifies the file in `onremove`, unlike its name
+    pub id: usize,
+    pub name: String,
+    pub size: u64,
+    pub content_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+impl std::fmt::Debug for UploadedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadedFile")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("size", &self.size)
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A file refused by a [`FileDropzone`], or that could not be read, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileRejection {
+    /// Identifies the file in `onremove`, unlike its name
+    pub id: usize,
+    pub name: String,
+    pub reason: String,
+}
+
+/// Files a [`FileDropzone`] accepts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileLimits {
+    /// Extensions and MIME types, like the `accept` attribute of file inputs: `"image/*,.pdf"`
+    pub accept: Option<String>,
+    /// Maximum size of each file, in bytes
+    pub max_size: Option<u64>,
+    /// Maximum number of files
+    pub max_files: Option<usize>,
+}
+
+impl FileLimits {
+    /// Why a file is refused when `accepted` files are already kept, if it is
+    pub fn rejection(
+        &self,
+        name: &str,
+        content_type: Option<&str>,
+        size: u64,
+        accepted: usize,
+    ) -> Option<String> {
+        if !accepts_file(
+            self.accept.as_deref().unwrap_or_default(),
+            name,
+            content_type,
+        ) {
+            Some("File type not accepted".to_string())
+        } else if let Some(max) = self.max_size.filter(|max| size > *max) {
+            Some(format!("Larger than {}", format_size(max)))
+        } else if self.max_files.is_some_and(|max| accepted >= max) {
+            Some("Too many files".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether a file matches an `accept` list like `"image/*,.pdf"`
+pub fn accepts_file(accept: &str, name: &str, content_type: Option<&str>) -> bool {
+    let name = name.to_lowercase();
+    let content_type = content_type.unwrap_or_default().to_lowercase();
+    let mut patterns = accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .peekable();
+
+    patterns.peek().is_none()
+        || patterns.any(|pattern| {
+            if pattern.starts_with('.') {
+                name.ends_with(&pattern)
+            } else if let Some(kind) = pattern.strip_suffix("/*") {
+                content_type.starts_with(&format!("{kind}/"))
+            } else {
+                content_type == pattern
+            }
+        })
+}
+
+/// `1.5 MB`
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    format!("{size:.1} {unit}")
+}
+
+/// A file listed under the dropzone
+#[derive(Clone, PartialEq)]
+struct Entry {
+    id: usize,
+    name: String,
+    size: u64,
+    progress: u8,
+    done: bool,
+    /// Data URL of images
+    preview: Option<String>,
+    error: Option<String>,
+}
+
+fn update_entry(mut entries: Signal<Vec<Entry>>, id: usize, update: impl FnOnce(&mut Entry)) {
+    if let Some(entry) = entries.write().iter_mut().find(|entry| entry.id == id) {
+        update(entry);
+    }
+}
+
+/// Reads a file chunk by chunk, reporting the progress of its entry
+async fn read_file(
+    file: FileData,
+    entries: Signal<Vec<Entry>>,
+    id: usize,
+) -> Result<UploadedFile, FileRejection> {
+    let size = file.size();
+    // Grown as the chunks arrive, the reported size is not trusted with an allocation
+    let mut bytes = Vec::new();
+    let mut stream = file.byte_stream();
+    while let Some(chunk) = stream.next().await {
+        let Ok(chunk) = chunk else {
+            let reason = "Could not be read".to_string();
+            update_entry(entries, id, |entry| entry.error = Some(reason.clone()));
+            return Err(FileRejection {
+                id,
+                name: file.name(),
+                reason,
+            });
+        };
+        bytes.extend_from_slice(&chunk);
+        let progress = (bytes.len() as u64 * 100).checked_div(size).unwrap_or(100);
+        update_entry(entries, id, |entry| {
+            entry.progress = progress.min(100) as u8
+        });
+    }
+
+    let content_type = file.content_type();
+    let preview = content_type
+        .as_ref()
+        .filter(|content_type| content_type.starts_with("image/"))
+        .map(|content_type| {
+            let data = base64::engine::general_purpose::STANDARD.encode(&bytes);
+            format!("data:{content_type};base64,{data}")
+        });
+    update_entry(entries, id, |entry| {
+        entry.progress = 100;
+        entry.done = true;
+        entry.preview = preview;
+    });
+
+    Ok(UploadedFile {
+        id,
+        name: file.name(),
+        size,
+        content_type,
+        bytes,
+    })
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct FileDropzoneProps {
+    #[props(extends = div, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Extensions and MIME types, like the `accept` attribute of file inputs: `"image/*,.pdf"`
+    #[props(optional)]
+    accept: Option<String>,
+
+    /// Maximum size of each file, in bytes
+    #[props(optional)]
+    max_size: Option<u64>,
+
+    /// Maximum number of files, only one can be picked at a time if 1
+    #[props(optional)]
+    max_files: Option<usize>,
+
+    #[props(default = "Drop files here or click to browse".to_string())]
+    prompt: String,
+
+    /// Called with the files of each drop or browse, once they are all read
+    #[props(optional)]
+    onfiles: EventHandler<Vec<UploadedFile>>,
+
+    /// Called with the files refused by `accept`, `max_size` or `max_files`, and with the
+    /// files that could not be read once the others are
+    #[props(optional)]
+    onreject: EventHandler<Vec<FileRejection>>,
+
+    /// Called with the `id` of a file removed from the list
+    #[props(optional)]
+    onremove: EventHandler<usize>,
+}
+
+/// An area files are dropped on, or clicked to browse them, listing the files with their
+/// preview and reading progress.
+#[component]
+pub fn FileDropzone(mut props: FileDropzoneProps) -> Element {
+    let default_classes = "filedropzone";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let mut entries = use_signal(Vec::<Entry>::new);
+    let mut next_id = use_signal(|| 0usize);
+    // Entered minus left elements, as leaving a child enters the dropzone again
+    let mut drag_depth = use_signal(|| 0usize);
+    // Recreates the input after each pick, so picking the same files again is a change
+    let mut picks = use_signal(|| 0usize);
+
+    let field = crate::use_field_binding(String::new);
+    let mut input_attributes = Vec::new();
+    field.setup_attributes(&mut input_attributes);
+
+    let sync_field = {
+        let field = field.clone();
+        move || {
+            let names: Vec<String> = entries
+                .peek()
+                .iter()
+                .filter(|entry| entry.error.is_none())
+                .map(|entry| entry.name.clone())
+                .collect();
+            field.set_value(names.join(","));
+            field.touch();
+        }
+    };
+
+    let limits = FileLimits {
+        accept: props.accept.clone(),
+        max_size: props.max_size,
+        max_files: props.max_files,
+    };
+    let handle_files = {
+        let sync_field = sync_field.clone();
+        move |files: Vec<FileData>| {
+            let mut count = entries
+                .peek()
+                .iter()
+                .filter(|entry| entry.error.is_none())
+                .count();
+            let mut batch = Vec::new();
+            let mut rejections = Vec::new();
+
+            for file in files {
+                let name = file.name();
+                let error =
+                    limits.rejection(&name, file.content_type().as_deref(), file.size(), count);
+
+                let id = *next_id.peek();
+                next_id += 1;
+                entries.write().push(Entry {
+                    id,
+                    name: name.clone(),
+                    size: file.size(),
+                    progress: 0,
+                    done: false,
+                    preview: None,
+                    error: error.clone(),
+                });
+                match error {
+                    Some(reason) => rejections.push(FileRejection { id, name, reason }),
+                    None => {
+                        count += 1;
+                        batch.push((id, file));
+                    }
+                }
+            }
+
+            if !rejections.is_empty() {
+                props.onreject.call(rejections);
+            }
+            if batch.is_empty() {
+                return;
+            }
+
+            let sync_field = sync_field.clone();
+            spawn(async move {
+                let mut uploaded = Vec::new();
+                let mut failed = Vec::new();
+                for (id, file) in batch {
+                    let file = read_file(file, entries, id).await;
+                    // Files removed while being read are dropped
+                    if !entries.peek().iter().any(|entry| entry.id == id) {
+                        continue;
+                    }
+                    match file {
+                        Ok(file) => uploaded.push(file),
+                        Err(rejection) => failed.push(rejection),
+                    }
+                }
+                sync_field();
+                if !uploaded.is_empty() {
+                    props.onfiles.call(uploaded);
+                }
+                if !failed.is_empty() {
+                    props.onreject.call(failed);
+                }
+            });
+        }
+    };
+
+    let onchange = {
+        let mut handle_files = handle_files.clone();
+        move |event: FormEvent| {
+            handle_files(event.files());
+            picks += 1;
+        }
+    };
+
+    let ondrop = {
+        let mut handle_files = handle_files.clone();
+        move |event: DragEvent| {
+            event.prevent_default();
+            drag_depth.set(0);
+            handle_files(event.data_transfer().files());
+        }
+    };
+
+    let mut hints = Vec::new();
+    if let Some(accept) = &props.accept {
+        hints.push(accept.replace(',', ", "));
+    }
+    if let Some(max_size) = props.max_size {
+        hints.push(format!("up to {}", format_size(max_size)));
+    }
+    if let Some(max_files) = props.max_files.filter(|max| *max > 1) {
+        hints.push(format!("{max_files} files max"));
+    }
+    let hint = hints.join(" · ");
+
+    rsx! {
+        div {
+            "data-dragging": drag_depth() > 0,
+            ondragenter: move |event| {
+                event.prevent_default();
+                drag_depth += 1;
+            },
+            // Drops are only allowed if the default of dragover is prevented
+            ondragover: move |event| event.prevent_default(),
+            ondragleave: move |_| {
+                let depth = drag_depth().saturating_sub(1);
+                drag_depth.set(depth);
+            },
+            ondrop,
+            ..props.attributes,
+            label { class: "filedropzone-area",
+                for pick in [picks()] {
+                    input {
+                        key: "{pick}",
+                        class: "filedropzone-input",
+                        r#type: "file",
+                        accept: props.accept.clone(),
+                        multiple: props.max_files != Some(1),
+                        onchange: onchange.clone(),
+                        ..input_attributes.clone(),
+                    }
+                }
+                Icon { class: "filedropzone-icon", icon: Icons::UploadFile }
+                span { class: "filedropzone-prompt", "{props.prompt}" }
+                if !hint.is_empty() {
+                    span { class: "filedropzone-hint", "{hint}" }
+                }
+            }
+            if !entries.read().is_empty() {
+                ul { class: "filedropzone-files",
+                    for entry in entries.read().iter().cloned() {
+                        li {
+                            key: "{entry.id}",
+                            class: "filedropzone-file",
+                            "data-state": match (&entry.error, entry.done) {
+                                (Some(_), _) => "rejected",
+                                (None, false) => "reading",
+                                (None, true) => "done",
+                            },
+                            if let Some(preview) = &entry.preview {
+                                img {
+                                    class: "filedropzone-preview",
+                                    src: "{preview}",
+                                    alt: "{entry.name}",
+                                }
+                            } else {
+                                Icon { class: "filedropzone-preview", icon: Icons::Description }
+                            }
+                            div { class: "filedropzone-file-info",
+                                span { class: "filedropzone-file-name", "{entry.name}" }
+                                span { class: "filedropzone-file-size", "{format_size(entry.size)}" }
+                                if let Some(error) = &entry.error {
+                                    span { class: "filedropzone-error", role: "alert", "{error}" }
+                                } else if !entry.done {
+                                    ProgressBar { size: Size::Xs,
+                                        ProgressBarInner { progress: entry.progress }
+                                    }
+                                }
+                            }
+                            button {
+                                class: "filedropzone-remove",
+                                r#type: "button",
+                                aria_label: "Remove {entry.name}",
+                                onclick: {
+                                    let sync_field = sync_field.clone();
+                                    move |_| {
+                                        entries.write().retain(|other| other.id != entry.id);
+                                        sync_field();
+                                        props.onremove.call(entry.id);
+                                    }
+                                },
+                                Icon { icon: Icons::Close }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
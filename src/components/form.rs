@@ -0,0 +1,752 @@
+use dioxus::prelude::*;
+use std::{cell::RefCell, future::Future, pin::Pin, rc::Rc};
+
+use regex::Regex;
+
+type Check = Rc<dyn Fn(&str) -> Result<(), String>>;
+type AsyncCheck = Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+#[derive(Clone)]
+enum Rule {
+    Required,
+    MinLength(usize),
+    MaxLength(usize),
+    Pattern(Regex),
+    Custom(Check),
+    Async(AsyncCheck),
+}
+
+/// A validation rule of a [`FormField`].
+/// Apart from `required`, rules accept empty values so that optional fields only validate once filled.
+#[derive(Clone)]
+pub struct Validator {
+    rule: Rule,
+    message: Option<String>,
+}
+
+impl Validator {
+    fn new(rule: Rule) -> Self {
+        Validator {
+            rule,
+            message: None,
+        }
+    }
+
+    /// Fails on empty or whitespace-only values, and on unchecked checkboxes
+    pub fn required() -> Self {
+        Self::new(Rule::Required)
+    }
+
+    /// Fails on values shorter than `min` characters
+    pub fn min_length(min: usize) -> Self {
+        Self::new(Rule::MinLength(min))
+    }
+
+    /// Fails on values longer than `max` characters
+    pub fn max_length(max: usize) -> Self {
+        Self::new(Rule::MaxLength(max))
+    }
+
+    /// Fails on values not matching `pattern`, anchor it to match the whole value
+    pub fn pattern(pattern: Regex) -> Self {
+        Self::new(Rule::Pattern(pattern))
+    }
+
+    /// Fails with the returned message
+    pub fn custom(check: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        Self::new(Rule::Custom(Rc::new(check)))
+    }
+
+    /// Like [`Validator::custom`], for checks needing a server or a database.
+    /// Only runs once every synchronous rule of the field passes.
+    pub fn custom_async<F>(check: impl Fn(String) -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        Self::new(Rule::Async(Rc::new(move |value| Box::pin(check(value)))))
+    }
+
+    /// Replaces the default error message
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    fn is_async(&self) -> bool {
+        matches!(self.rule, Rule::Async(_))
+    }
+
+    fn fail(&self, default: String) -> Result<(), String> {
+        Err(self.message.clone().unwrap_or(default))
+    }
+
+    /// Runs a synchronous rule, async rules always pass
+    fn check(&self, value: &str) -> Result<(), String> {
+        let length = value.chars().count();
+        match &self.rule {
+            Rule::Required if value.trim().is_empty() => {
+                self.fail("This field is required".to_string())
+            }
+            Rule::MinLength(min) if length > 0 && length < *min => {
+                self.fail(format!("Must be at least {min} characters"))
+            }
+            Rule::MaxLength(max) if length > *max => {
+                self.fail(format!("Must be at most {max} characters"))
+            }
+            Rule::Pattern(pattern) if length > 0 && !pattern.is_match(value) => {
+                self.fail("Invalid format".to_string())
+            }
+            Rule::Custom(check) => check(value).or_else(|error| self.fail(error)),
+            _ => Ok(()),
+        }
+    }
+
+    async fn check_async(&self, value: String) -> Result<(), String> {
+        match &self.rule {
+            Rule::Async(check) => check(value).await.or_else(|error| self.fail(error)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl PartialEq for Validator {
+    fn eq(&self, other: &Self) -> bool {
+        let same_rule = match (&self.rule, &other.rule) {
+            (Rule::Required, Rule::Required) => true,
+            (Rule::MinLength(a), Rule::MinLength(b)) | (Rule::MaxLength(a), Rule::MaxLength(b)) => {
+                a == b
+            }
+            (Rule::Pattern(a), Rule::Pattern(b)) => a.as_str() == b.as_str(),
+            (Rule::Custom(a), Rule::Custom(b)) => Rc::ptr_eq(a, b),
+            (Rule::Async(a), Rule::Async(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        };
+        same_rule && self.message == other.message
+    }
+}
+
+/// Validators of a [`FormField`], equal when their rules are the same except for the custom
+/// checks, which an inline closure recreates at each render
+#[derive(Clone)]
+struct FieldRules(Vec<Validator>);
+
+impl PartialEq for FieldRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| match (&a.rule, &b.rule) {
+                    (Rule::Custom(_), Rule::Custom(_)) | (Rule::Async(_), Rule::Async(_)) => {
+                        a.message == b.message
+                    }
+                    _ => a == b,
+                })
+    }
+}
+
+impl std::fmt::Debug for Validator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rule = match &self.rule {
+            Rule::Required => "required".to_string(),
+            Rule::MinLength(min) => format!("min_length({min})"),
+            Rule::MaxLength(max) => format!("max_length({max})"),
+            Rule::Pattern(pattern) => format!("pattern({pattern})"),
+            Rule::Custom(_) => "custom".to_string(),
+            Rule::Async(_) => "custom_async".to_string(),
+        };
+        f.debug_struct("Validator")
+            .field("rule", &rule)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+#[derive(Clone, Default)]
+struct FieldState {
+    value: String,
+    initial: String,
+    validators: Vec<Validator>,
+    /// Set by typed inputs when the value does not parse, shown before the validator errors
+    parse_error: Option<String>,
+    errors: Vec<String>,
+    touched: bool,
+    validating: bool,
+    /// Bumped on each validation, so that outdated async results are dropped
+    generation: usize,
+}
+
+impl FieldState {
+    fn check(&self) -> Vec<String> {
+        self.parse_error
+            .iter()
+            .cloned()
+            .chain(
+                self.validators
+                    .iter()
+                    .filter_map(|validator| validator.check(&self.value).err()),
+            )
+            .collect()
+    }
+
+    fn has_async(&self) -> bool {
+        self.validators.iter().any(Validator::is_async)
+    }
+}
+
+/// Values of a form by field name, in the order the fields were mounted.
+/// Checkboxes are `"on"` when checked and empty otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormValues(Vec<(String, String)>);
+
+impl FromIterator<(String, String)> for FormValues {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        FormValues(iter.into_iter().collect())
+    }
+}
+
+impl FormValues {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// State of a [`Form`], shared with its fields through the context.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormState {
+    fields: Signal<Vec<(String, FieldState)>>,
+    submitted: Signal<bool>,
+    submitting: Signal<bool>,
+}
+
+/// Creates the state of a form, to read or drive it from outside of the [`Form`].
+pub fn use_form() -> FormState {
+    FormState {
+        fields: use_signal(Vec::new),
+        submitted: use_signal(|| false),
+        submitting: use_signal(|| false),
+    }
+}
+
+impl FormState {
+    fn read_field<T>(&self, name: &str, read: impl FnOnce(&FieldState) -> T) -> Option<T> {
+        self.fields
+            .read()
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, state)| read(state))
+    }
+
+    fn write_field<T>(
+        &mut self,
+        name: &str,
+        write: impl FnOnce(&mut FieldState) -> T,
+    ) -> Option<T> {
+        self.fields
+            .write()
+            .iter_mut()
+            .find(|(field, _)| field == name)
+            .map(|(_, state)| write(state))
+    }
+
+    fn register(&mut self, name: &str, validators: Vec<Validator>) {
+        let mut fields = self.fields.write();
+        match fields.iter_mut().find(|(field, _)| field == name) {
+            Some((_, state)) => {
+                state.validators = validators;
+                state.errors = state.check();
+            }
+            None => {
+                let state = FieldState {
+                    validators,
+                    ..Default::default()
+                };
+                let errors = state.check();
+                fields.push((name.to_string(), FieldState { errors, ..state }));
+            }
+        }
+    }
+
+    fn unregister(&mut self, name: &str) {
+        self.fields.write().retain(|(field, _)| field != name);
+    }
+
+    /// Sets the value the field starts with and is reset to, without touching it
+    pub(crate) fn init(&mut self, name: &str, value: String) {
+        self.write_field(name, |state| {
+            state.initial = value.clone();
+            state.value = value;
+            state.errors = state.check();
+        });
+    }
+
+    pub fn value(&self, name: &str) -> Option<String> {
+        self.read_field(name, |state| state.value.clone())
+    }
+
+    /// Like [`FormState::value`], without subscribing to the form
+    fn peek_value(&self, name: &str) -> Option<String> {
+        self.fields
+            .peek()
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, state)| state.value.clone())
+    }
+
+    pub fn values(&self) -> FormValues {
+        FormValues(
+            self.fields
+                .read()
+                .iter()
+                .map(|(name, state)| (name.clone(), state.value.clone()))
+                .collect(),
+        )
+    }
+
+    /// Sets the value of a field and validates it, async rules run in the background
+    pub fn set_value(&mut self, name: &str, value: impl Into<String>) {
+        self.set_parsed_value(name, value.into(), None);
+    }
+
+    /// Like [`FormState::set_value`], with the error of a value that did not parse
+    pub(crate) fn set_parsed_value(
+        &mut self,
+        name: &str,
+        value: String,
+        parse_error: Option<String>,
+    ) {
+        let pending = self.write_field(name, |state| {
+            state.value = value;
+            state.parse_error = parse_error;
+            Self::start_validation(state)
+        });
+        if let Some(Some(pending)) = pending {
+            let mut form = *self;
+            let name = name.to_string();
+            spawn(async move {
+                form.finish_validation(&name, pending).await;
+            });
+        }
+    }
+
+    /// Marks a field as touched, showing its errors
+    pub fn touch(&mut self, name: &str) {
+        self.write_field(name, |state| state.touched = true);
+    }
+
+    /// Errors of the field from its last validation, shown once it is touched or the form submitted
+    pub fn errors(&self, name: &str) -> Vec<String> {
+        self.read_field(name, |state| state.errors.clone())
+            .unwrap_or_default()
+    }
+
+    /// Errors to show under the field
+    pub fn visible_errors(&self, name: &str) -> Vec<String> {
+        let submitted = *self.submitted.read();
+        self.read_field(name, |state| {
+            if state.touched || submitted {
+                state.errors.clone()
+            } else {
+                Vec::new()
+            }
+        })
+        .unwrap_or_default()
+    }
+
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.read_field(name, |state| state.touched)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the field value differs from its initial value
+    pub fn is_dirty(&self, name: &str) -> bool {
+        self.read_field(name, |state| state.value != state.initial)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if any field value differs from its initial value
+    pub fn is_form_dirty(&self) -> bool {
+        self.fields
+            .read()
+            .iter()
+            .any(|(_, state)| state.value != state.initial)
+    }
+
+    /// Returns true if no field has errors and no async rule is running
+    pub fn is_valid(&self) -> bool {
+        self.fields
+            .read()
+            .iter()
+            .all(|(_, state)| state.errors.is_empty() && !state.validating)
+    }
+
+    pub fn is_validating(&self) -> bool {
+        self.fields.read().iter().any(|(_, state)| state.validating)
+    }
+
+    pub fn is_submitting(&self) -> bool {
+        *self.submitting.read()
+    }
+
+    /// Returns true if a submit was attempted since the last reset
+    pub fn is_submitted(&self) -> bool {
+        *self.submitted.read()
+    }
+
+    /// Validates every field, async rules included, and shows all errors
+    pub async fn validate(mut self) -> bool {
+        self.submitted.set(true);
+        let names: Vec<String> = self
+            .fields
+            .peek()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        let mut valid = true;
+        for name in names {
+            let pending = self.write_field(&name, |state| {
+                state.touched = true;
+                Self::start_validation(state)
+            });
+            if let Some(Some(pending)) = pending {
+                self.finish_validation(&name, pending).await;
+            }
+            valid &= self
+                .read_field(&name, |state| state.errors.is_empty())
+                .unwrap_or(true);
+        }
+        valid
+    }
+
+    /// Restores the initial values and hides the errors
+    pub fn reset(&mut self) {
+        self.submitted.set(false);
+        for (_, state) in self.fields.write().iter_mut() {
+            state.value = state.initial.clone();
+            state.parse_error = None;
+            state.touched = false;
+            state.validating = false;
+            state.generation += 1;
+            state.errors = state.check();
+        }
+    }
+
+    /// Runs the synchronous rules, returning what the async ones need if those pass
+    fn start_validation(state: &mut FieldState) -> Option<PendingValidation> {
+        state.generation += 1;
+        state.errors = state.check();
+        state.validating = state.errors.is_empty() && state.has_async();
+
+        state.validating.then(|| PendingValidation {
+            value: state.value.clone(),
+            validators: state.validators.clone(),
+            generation: state.generation,
+        })
+    }
+
+    async fn finish_validation(&mut self, name: &str, pending: PendingValidation) {
+        let mut errors = Vec::new();
+        for validator in pending.validators.iter().filter(|v| v.is_async()) {
+            if let Err(error) = validator.check_async(pending.value.clone()).await {
+                errors.push(error);
+            }
+        }
+
+        self.write_field(name, |state| {
+            if state.generation == pending.generation {
+                state.errors = errors;
+                state.validating = false;
+            }
+        });
+    }
+}
+
+struct PendingValidation {
+    value: String,
+    validators: Vec<Validator>,
+    generation: usize,
+}
+
+/// A field of the enclosing [`FormField`], used by the inputs to bind to the form.
+#[derive(Clone, PartialEq)]
+pub struct FormFieldContext {
+    form: FormState,
+    name: String,
+}
+
+impl FormFieldContext {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> String {
+        self.form.value(&self.name).unwrap_or_default()
+    }
+
+    pub fn set_value(&self, value: impl Into<String>) {
+        let mut form = self.form;
+        form.set_value(&self.name, value);
+    }
+
+    pub fn touch(&self) {
+        let mut form = self.form;
+        form.touch(&self.name);
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        !self.form.visible_errors(&self.name).is_empty()
+    }
+}
+
+/// Returns the field the component is in, if any
+pub fn use_form_field() -> Option<FormFieldContext> {
+    try_use_context::<FormFieldContext>()
+}
+
+/// Binding of an input to the enclosing [`FormField`], doing nothing outside of one.
+// Unused when no input is enabled
+#[cfg_attr(not(feature = "all-components"), allow(dead_code))]
+#[derive(Clone, PartialEq)]
+pub(crate) struct FieldBinding(Option<FormFieldContext>);
+
+#[cfg_attr(not(feature = "all-components"), allow(dead_code))]
+impl FieldBinding {
+    /// Value of the field, or `default` when the input is controlled or outside of a field
+    pub(crate) fn value_or(&self, controlled: bool, default: String) -> String {
+        match &self.0 {
+            Some(field) if !controlled => field.value(),
+            _ => default,
+        }
+    }
+
+    /// Writes the value of a controlled input to the field whenever its signal changes
+    pub(crate) fn use_controlled_value(
+        &self,
+        controlled: bool,
+        value: impl Fn() -> String + 'static,
+    ) {
+        let field = self.0.clone();
+        use_effect(move || {
+            let value = value();
+            if let Some(field) = field.as_ref().filter(|_| controlled)
+                && field
+                    .form
+                    .peek_value(&field.name)
+                    .is_some_and(|old| old != value)
+            {
+                field.set_value(value);
+            }
+        });
+    }
+
+    pub(crate) fn set_value(&self, value: impl Into<String>) {
+        if let Some(field) = &self.0 {
+            field.set_value(value);
+        }
+    }
+
+    /// Sets the text of a typed input, with its error if it does not parse
+    pub(crate) fn set_parsed_value(&self, value: impl Into<String>, parse_error: Option<String>) {
+        if let Some(field) = &self.0 {
+            let mut form = field.form;
+            form.set_parsed_value(&field.name, value.into(), parse_error);
+        }
+    }
+
+    pub(crate) fn touch(&self) {
+        if let Some(field) = &self.0 {
+            field.touch();
+        }
+    }
+
+    pub(crate) fn name(&self) -> Option<&str> {
+        self.0.as_ref().map(FormFieldContext::name)
+    }
+
+    pub(crate) fn is_invalid(&self) -> bool {
+        self.0.as_ref().is_some_and(FormFieldContext::is_invalid)
+    }
+
+    /// Sets the `name` of the input if not given, and `aria-invalid` while it shows errors
+    pub(crate) fn setup_attributes(&self, attributes: &mut Vec<Attribute>) {
+        if let Some(name) = self.name()
+            && !attributes.iter().any(|attr| attr.name == "name")
+        {
+            attributes.push(Attribute::new("name", name.to_string(), None, false));
+        }
+        if self.is_invalid() {
+            attributes.push(Attribute::new("aria-invalid", "true", None, false));
+        }
+    }
+}
+
+/// Binds an input to the enclosing [`FormField`], starting the field with `initial`.
+#[cfg_attr(not(feature = "all-components"), allow(dead_code))]
+pub(crate) fn use_field_binding(initial: impl FnOnce() -> String) -> FieldBinding {
+    let field = use_form_field();
+
+    use_hook(|| {
+        if let Some(field) = &field {
+            let mut form = field.form;
+            form.init(&field.name, initial());
+        }
+    });
+
+    FieldBinding(field)
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct FormProps {
+    #[props(extends = form, extends = GlobalAttributes)]
+    pub(crate) attributes: Vec<Attribute>,
+
+    /// State from [`use_form`], the form creates its own if not given
+    #[props(optional)]
+    pub(crate) form: Option<FormState>,
+
+    /// Only called once every field is valid
+    #[props(optional)]
+    pub(crate) onsubmit: EventHandler<FormValues>,
+
+    /// Called instead of `onsubmit` when a field is invalid
+    #[props(optional)]
+    pub(crate) oninvalid: EventHandler,
+
+    pub(crate) children: Element,
+}
+
+#[component]
+pub fn Form(mut props: FormProps) -> Element {
+    let own_form = use_form();
+    let form = use_context_provider(|| props.form.unwrap_or(own_form));
+
+    let default_classes = "form";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let onsubmit = move |event: FormEvent| {
+        event.prevent_default();
+        if form.is_submitting() {
+            return;
+        }
+
+        let mut form = form;
+        spawn(async move {
+            form.submitting.set(true);
+            if form.validate().await {
+                props.onsubmit.call(form.values());
+            } else {
+                props.oninvalid.call(());
+            }
+            form.submitting.set(false);
+        });
+    };
+
+    rsx! {
+        form {
+            novalidate: true,
+            "data-submitting": form.is_submitting(),
+            onsubmit,
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct FormFieldProps {
+    #[props(extends = div, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Name of the field in the [`FormValues`]
+    #[props(into)]
+    name: String,
+
+    #[props(optional)]
+    validators: Vec<Validator>,
+
+    /// Changing it registers the `validators` again. Custom and async checks are not compared,
+    /// so one replaced by another closure only applies once the key changes.
+    #[props(optional, into)]
+    validators_key: Option<String>,
+
+    children: Element,
+}
+
+/// Registers the input it contains to the enclosing [`Form`] and shows its errors under it.
+#[component]
+pub fn FormField(mut props: FormFieldProps) -> Element {
+    let mut form = use_context::<FormState>();
+
+    // Registered before the input binds to the field, then again after the renders changing
+    // the name, the key or the rules of the validators
+    type Registration = (String, Option<String>, FieldRules);
+    let registered = use_hook(|| {
+        form.register(&props.name, props.validators.clone());
+        let registration: Registration = (
+            props.name.clone(),
+            props.validators_key.clone(),
+            FieldRules(props.validators.clone()),
+        );
+        Rc::new(RefCell::new(registration))
+    });
+    use_effect(use_reactive(
+        (
+            &props.name,
+            &props.validators_key,
+            &FieldRules(props.validators.clone()),
+        ),
+        {
+            let registered = registered.clone();
+            move |registration: Registration| {
+                if *registered.borrow() == registration {
+                    return;
+                }
+                let (name, _, FieldRules(validators)) = &registration;
+                if registered.borrow().0 != *name {
+                    form.unregister(&registered.borrow().0);
+                }
+                form.register(name, validators.clone());
+                registered.replace(registration);
+            }
+        },
+    ));
+
+    use_drop({
+        let registered = registered.clone();
+        move || form.unregister(&registered.borrow().0)
+    });
+
+    use_context_provider(|| FormFieldContext {
+        form,
+        name: props.name.clone(),
+    });
+
+    let default_classes = "form-field";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let errors = form.visible_errors(&props.name);
+    let invalid = !errors.is_empty();
+
+    rsx! {
+        div {
+            "data-invalid": invalid,
+            "data-validating": form.read_field(&props.name, |state| state.validating).unwrap_or_default(),
+            ..props.attributes,
+            {props.children}
+            for error in errors {
+                p { class: "form-error", role: "alert", "{error}" }
+            }
+        }
+    }
+}
@@ -1,69 +1,259 @@
+use crate::components::icon::*;
 use dioxus::prelude::*;
 
-pub struct FormListState {
-    max_size: usize,
-    current_size: usize,
+/// Keys and operations of a [`FormList`], whatever the type of its items
+#[derive(Clone, Copy)]
+struct FormListState {
+    len: Memo<usize>,
+    min: Memo<usize>,
+    max: Memo<Option<usize>>,
+    /// Index of the item being dragged
+    dragged: Signal<Option<usize>>,
+    add: Callback<()>,
+    remove: Callback<usize>,
+    move_item: Callback<(usize, usize)>,
 }
 
 impl FormListState {
-    fn new(current_size: usize) -> Self {
-        FormListState {
-            max_size: 1,
-            current_size,
-        }
+    fn get_current_size(&self) -> usize {
+        (self.len)()
     }
 
-    fn get_max_size(&self) -> usize {
-        self.max_size
+    fn can_add(&self) -> bool {
+        (self.max)().is_none_or(|max| self.get_current_size() < max)
     }
 
-    fn set_max_size(&mut self, max_size: usize) {
-        self.max_size = max_size;
+    fn can_remove(&self) -> bool {
+        self.get_current_size() > (self.min)()
     }
+}
 
-    fn get_current_size(&self) -> usize {
-        self.current_size
-    }
+/// Index of the item rendering the component, for [`FormListTriggerMinus`] and [`FormListHandle`]
+#[derive(Clone, Copy)]
+struct FormListItemState {
+    index: Signal<usize>,
+}
 
-    fn add_one(&mut self) {
-        if self.current_size < self.max_size {
-            self.current_size += 1;
-        }
-    }
+/// An item of a [`FormList`], as given to its `render` closure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormListEntry<T> {
+    pub index: usize,
+    /// Stays the same while the item is moved around, to build the names of its fields
+    pub key: usize,
+    pub value: T,
+}
 
-    fn remove_one(&mut self) {
-        if self.current_size > 1 {
-            self.current_size -= 1;
-        }
-    }
+/// Keys of `items` after they were changed from `previous` outside of the list, keeping the
+/// keys of the items still found in it
+fn sync_keys<T: PartialEq>(
+    previous: &[T],
+    keys: &[usize],
+    items: &[T],
+    next_key: &mut usize,
+) -> Vec<usize> {
+    let mut taken = vec![false; previous.len()];
+    let found: Vec<Option<usize>> = items
+        .iter()
+        .map(|item| {
+            let index =
+                (0..previous.len()).find(|index| !taken[*index] && previous[*index] == *item)?;
+            taken[index] = true;
+            Some(keys[index])
+        })
+        .collect();
+
+    // Items found nowhere were edited in place, and keep the key of their position if free
+    found
+        .into_iter()
+        .enumerate()
+        .map(|(index, key)| match key {
+            Some(key) => key,
+            None if index < previous.len() && !taken[index] => {
+                taken[index] = true;
+                keys[index]
+            }
+            None => {
+                *next_key += 1;
+                *next_key - 1
+            }
+        })
+        .collect()
 }
 
 #[derive(Clone, PartialEq, Props)]
-pub struct FormListProps {
+pub struct FormListProps<T: Clone + PartialEq + 'static> {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
-    #[props(default = 1)]
-    max_size: usize,
-    #[props(default = 1)]
-    current_size: usize,
+    /// Items of the list, rendered in order
+    value: Signal<Vec<T>>,
+
+    /// Renders an item, usually a [`FormField`](crate::FormField) named after its key
+    render: Callback<FormListEntry<T>, Element>,
+
+    /// Creates the items added by [`FormListTriggerPlus`]
+    new_item: Callback<(), T>,
+
+    #[props(default)]
+    min: usize,
+    #[props(optional)]
+    max: Option<usize>,
 
+    /// Rendered after the items, e.g. a [`FormListTriggerPlus`]
     children: Element,
 }
 
+/// A list of items added, removed and reordered by the user.
+///
+/// Each item is rendered by `render` under a stable key, so the state of its fields follows it
+/// when items before it are removed or moved.
+/// Usage:
+/// ```ignore
+/// FormList {
+///     value: emails,
+///     new_item: |_| String::new(),
+///     max: 5,
+///     render: move |entry: FormListEntry<String>| rsx! {
+///         FormListHandle {}
+///         Input { default_value: entry.value }
+///         FormListTriggerMinus { "Remove" }
+///     },
+///     FormListTriggerPlus { "Add an email" }
+/// }
+/// ```
 #[component]
-pub fn FormList(mut props: FormListProps) -> Element {
+pub fn FormList<T: Clone + PartialEq + 'static>(mut props: FormListProps<T>) -> Element {
     let default_classes = "formlist";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
-    let mut state = use_context_provider(|| Signal::new(FormListState::new(props.current_size)));
+    let mut value = props.value;
+    let mut next_key = use_hook(|| CopyValue::new(value.peek().len()));
+    // Not a signal, as they are synced while rendering the items
+    let mut keys = use_hook(|| CopyValue::new((0..value.peek().len()).collect::<Vec<_>>()));
+    let len = use_memo(move || value.read().len());
+    // Items as of the last change made by the list, to tell which ones were changed elsewhere
+    let mut previous = use_hook(|| CopyValue::new(value.peek().clone()));
+
+    let min = use_memo(use_reactive((&props.min,), |(min,)| min));
+    let max = use_memo(use_reactive((&props.max,), |(max,)| max));
+
+    let new_item = props.new_item;
+    let add = use_callback(move |()| {
+        if max.peek().is_some_and(|max| keys.peek().len() >= max) {
+            return;
+        }
+        value.write().push(new_item.call(()));
+        keys.write().push(next_key());
+        next_key += 1;
+        previous.set(value.peek().clone());
+    });
+
+    let remove = use_callback(move |index: usize| {
+        if keys.peek().len() <= *min.peek() || index >= keys.peek().len() {
+            return;
+        }
+        value.write().remove(index);
+        keys.write().remove(index);
+        previous.set(value.peek().clone());
+    });
+
+    let move_item = use_callback(move |(from, to): (usize, usize)| {
+        let len = keys.peek().len();
+        if from == to || from >= len || to >= len {
+            return;
+        }
+        let mut items = value.write();
+        let item = items.remove(from);
+        items.insert(to, item);
+        let mut keys = keys.write();
+        let key = keys.remove(from);
+        keys.insert(to, key);
+        previous.set(items.clone());
+    });
 
-    use_effect(move || {
-        state.write().set_max_size(props.max_size);
+    let state = use_context_provider(|| FormListState {
+        len,
+        min,
+        max,
+        dragged: Signal::new(None),
+        add,
+        remove,
+        move_item,
     });
 
+    let items = value.read().clone();
+    if *previous.peek() != items {
+        let synced = sync_keys(
+            &previous.peek(),
+            &keys.peek(),
+            &items,
+            &mut next_key.write(),
+        );
+        keys.set(synced);
+        previous.set(items.clone());
+    }
+
+    let entries: Vec<(usize, Element)> = keys
+        .peek()
+        .iter()
+        .zip(items)
+        .enumerate()
+        .map(|(index, (key, value))| {
+            let entry = FormListEntry {
+                index,
+                key: *key,
+                value,
+            };
+            (*key, props.render.call(entry))
+        })
+        .collect();
+
     rsx! {
-        div { ..props.attributes, {props.children} }
+        div { "data-dragging": (state.dragged)().is_some(), ..props.attributes,
+            div { class: "formlist-content",
+                for (index, (key, item)) in entries.into_iter().enumerate() {
+                    FormListRow { key: "{key}", index, {item} }
+                }
+            }
+            {props.children}
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
+struct FormListRowProps {
+    index: usize,
+
+    children: Element,
+}
+
+/// Wraps an item, dropping the dragged one on it
+#[component]
+fn FormListRow(props: FormListRowProps) -> Element {
+    let state = use_context::<FormListState>();
+    let mut index = use_signal(|| props.index);
+    use_context_provider(|| FormListItemState { index });
+    if *index.peek() != props.index {
+        index.set(props.index);
+    }
+
+    let mut dragged = state.dragged;
+    let target = props.index;
+
+    rsx! {
+        div {
+            class: "formlist-row",
+            "data-dragged": dragged() == Some(target),
+            ondragover: move |event| event.prevent_default(),
+            ondrop: move |event| {
+                event.prevent_default();
+                if let Some(from) = dragged() {
+                    state.move_item.call((from, target));
+                }
+                dragged.set(None);
+            },
+            {props.children}
+        }
     }
 }
 
@@ -104,51 +294,68 @@ pub fn FormListLabel(mut props: FormListLabelProps) -> Element {
 }
 
 #[derive(Clone, PartialEq, Props)]
-pub struct FormListTriggerPlusProps {
-    #[props(extends = div, extends = GlobalAttributes)]
+pub struct FormListHandleProps {
+    #[props(extends = span, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
-
-    children: Element,
 }
 
+/// Drags its item to another place of the list, or moves it with the arrow keys
 #[component]
-pub fn FormListTriggerPlus(mut props: FormListTriggerPlusProps) -> Element {
-    let mut state = use_context::<Signal<FormListState>>();
+pub fn FormListHandle(mut props: FormListHandleProps) -> Element {
+    let state = use_context::<FormListState>();
+    let item = use_context::<FormListItemState>();
 
-    let default_classes = "formlist-trigger-plus";
+    let default_classes = "formlist-handle";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
+    let mut dragged = state.dragged;
+    let onkeydown = move |event: KeyboardEvent| {
+        let index = (item.index)();
+        let target = match event.key() {
+            Key::ArrowUp if index > 0 => index - 1,
+            Key::ArrowDown => index + 1,
+            _ => return,
+        };
+        event.prevent_default();
+        state.move_item.call((index, target));
+    };
+
     rsx! {
-        div {
-            onclick: move |_| {
-                state.write().add_one();
-            },
+        span {
+            role: "button",
+            tabindex: "0",
+            draggable: "true",
+            aria_label: "Move",
+            ondragstart: move |_| dragged.set(Some((item.index)())),
+            ondragend: move |_| dragged.set(None),
+            onkeydown,
             ..props.attributes,
-            {props.children}
+            Icon { icon: Icons::DragIndicator }
         }
     }
 }
 
 #[derive(Clone, PartialEq, Props)]
-pub struct FormListTriggerMinusProps {
-    #[props(extends = div, extends = GlobalAttributes)]
+pub struct FormListTriggerPlusProps {
+    #[props(extends = button, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
     children: Element,
 }
 
+/// Adds an item, unless the list has `max` of them
 #[component]
-pub fn FormListTriggerMinus(mut props: FormListTriggerMinusProps) -> Element {
-    let mut state = use_context::<Signal<FormListState>>();
+pub fn FormListTriggerPlus(mut props: FormListTriggerPlusProps) -> Element {
+    let state = use_context::<FormListState>();
 
-    let default_classes = "formlist-trigger-minus";
+    let default_classes = "formlist-trigger-plus";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
     rsx! {
-        div {
-            onclick: move |_| {
-                state.write().remove_one();
-            },
+        button {
+            r#type: "button",
+            disabled: !state.can_add(),
+            onclick: move |_| state.add.call(()),
             ..props.attributes,
             {props.children}
         }
@@ -156,51 +363,54 @@ pub fn FormListTriggerMinus(mut props: FormListTriggerMinusProps) -> Element {
 }
 
 #[derive(Clone, PartialEq, Props)]
-pub struct FormListContentProps {
-    #[props(extends = div, extends = GlobalAttributes)]
+pub struct FormListTriggerMinusProps {
+    #[props(extends = button, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
-    #[props(default)]
-    list_fields: Vec<Element>,
+    children: Element,
 }
 
+/// Removes the item it is rendered in, or the last one outside of the items,
+/// unless the list has `min` of them
 #[component]
-pub fn FormListContent(mut props: FormListContentProps) -> Element {
-    let mut state = use_context::<Signal<FormListState>>();
+pub fn FormListTriggerMinus(mut props: FormListTriggerMinusProps) -> Element {
+    let state = use_context::<FormListState>();
+    let item = try_use_context::<FormListItemState>();
 
-    let default_classes = "formlist-content";
+    let default_classes = "formlist-trigger-minus";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
-    let max_size = props.list_fields.len();
-    use_effect(move || {
-        state.write().set_max_size(max_size);
-    });
-
-    let fields = props
-        .list_fields
-        .iter()
-        .take(state.read().get_current_size())
-        .map(|field| {
-            rsx! {
-                {field.clone()}
-            }
-        });
-
     rsx! {
-        div { ..props.attributes,{fields} }
+        button {
+            r#type: "button",
+            disabled: !state.can_remove(),
+            onclick: move |_| {
+                let index = match item {
+                    Some(item) => (item.index)(),
+                    None => state.get_current_size().saturating_sub(1),
+                };
+                state.remove.call(index);
+            },
+            ..props.attributes,
+            {props.children}
+        }
     }
 }
 
 #[component]
 pub fn FormListMaxSize() -> Element {
-    let state = use_context::<Signal<FormListState>>();
+    let state = use_context::<FormListState>();
 
-    rsx! { "{state.read().get_max_size()}" }
+    rsx! {
+        if let Some(max) = (state.max)() {
+            "{max}"
+        }
+    }
 }
 
 #[component]
 pub fn FormListCurrentSize() -> Element {
-    let state = use_context::<Signal<FormListState>>();
+    let state = use_context::<FormListState>();
 
-    rsx! { "{state.read().get_current_size()}" }
+    rsx! { "{state.get_current_size()}" }
 }
@@ -1,4 +1,5 @@
-use crate::dioxus_core::IntoAttributeValue;
+use crate::components::variants::*;
+use dioxus::dioxus_core::IntoAttributeValue;
 use dioxus::prelude::*;
 use dioxus_core::AttributeValue;
 
@@ -100,6 +101,9 @@ pub struct HoverCardContentProps {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
+    #[props(optional)]
+    animation: Option<Animation>,
+
     children: Element,
 }
 
@@ -109,6 +113,11 @@ pub fn HoverCardContent(mut props: HoverCardContentProps) -> Element {
 
     let default_classes = "hovercard-content";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     rsx! {
         div {
@@ -1,6 +1,35 @@
 use dioxus::prelude::*;
 
-#[derive(Default, Clone, PartialEq, Props)]
+#[cfg(feature = "svg-icons")]
+mod svg {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/dioxus-tw-components-svg-icons.rs"
+    ));
+}
+
+/// How an [`Icon`] is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconBackend {
+    /// A ligature of the Material Symbols font loaded by `Bootstrap`.
+    Font,
+    /// An inline SVG, which needs neither the font nor the network.
+    /// Requires the `svg-icons` feature, icons fall back to `Font` without it.
+    Svg,
+}
+
+impl Default for IconBackend {
+    /// `Svg` with the `svg-icons-default` feature, `Font` otherwise.
+    fn default() -> Self {
+        if cfg!(feature = "svg-icons-default") {
+            IconBackend::Svg
+        } else {
+            IconBackend::Font
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
 pub struct IconProps {
     /// Additional attributes to apply to the element
     #[props(extends = p, extends = GlobalAttributes)]
@@ -8,6 +37,36 @@ pub struct IconProps {
 
     /// The icon
     icon: Icons,
+
+    #[props(default)]
+    backend: IconBackend,
+
+    /// Filled or outlined style.
+    /// The `Font` backend needs the `variable-icon-font` feature for this and the weight and grade,
+    /// its default font is the filled instance.
+    #[props(default = true)]
+    filled: bool,
+
+    /// Stroke weight, from 100 to 700.
+    #[props(default = 400)]
+    weight: u16,
+
+    /// Fine stroke thickness adjustment, from -50 to 200.
+    #[props(default = 0)]
+    grade: i16,
+}
+
+impl Default for IconProps {
+    fn default() -> Self {
+        Self {
+            attributes: Vec::default(),
+            icon: Icons::default(),
+            backend: IconBackend::default(),
+            filled: true,
+            weight: 400,
+            grade: 0,
+        }
+    }
 }
 
 #[component]
@@ -15,4111 +74,137 @@ pub fn Icon(mut props: IconProps) -> Element {
     let default_classes = "icon";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
+    let name = props.icon.to_string();
+
+    if let Icons::Custom(CustomIcon {
+        svg: Some((view_box, path)),
+        ..
+    }) = props.icon
+    {
+        return rsx! {
+            svg {
+                view_box,
+                width: "1em",
+                height: "1em",
+                fill: "currentColor",
+                "aria-hidden": "true",
+                ..props.attributes,
+                path { d: path }
+            }
+        };
+    }
+
+    if props.backend == IconBackend::Svg
+        && let Some(path) = svg_path(&name, props.filled)
+    {
+        // The paths are those of the regular weight and grade, bolder icons are emulated with a stroke
+        // and lighter ones by eroding the shape by half as much
+        let thickness = (f32::from(props.weight.clamp(100, 700)) - 400.0) / 5.0
+            + f32::from(props.grade.clamp(-50, 200)) / 10.0;
+        let stroke_width = thickness.max(0.0);
+        let erosion = (-thickness / 2.0).round().max(0.0) as u8;
+        // Same radius, same filter, so repeating the id is harmless
+        let filter_id = format!("icon-erode-{erosion}");
+
+        return rsx! {
+            svg {
+                view_box: "0 -960 960 960",
+                width: "1em",
+                height: "1em",
+                fill: "currentColor",
+                stroke: if stroke_width > 0.0 { "currentColor" } else { "none" },
+                stroke_width: "{stroke_width}",
+                stroke_linejoin: "round",
+                "aria-hidden": "true",
+                ..props.attributes,
+                if erosion > 0 {
+                    defs {
+                        filter { id: "{filter_id}",
+                            feMorphology { operator: "erode", radius: "{erosion}" }
+                        }
+                    }
+                }
+                path {
+                    d: path,
+                    filter: if erosion > 0 { "url(#{filter_id})" },
+                }
+            }
+        };
+    }
+
+    let font_variation_settings = format!(
+        "'FILL' {}, 'wght' {}, 'GRAD' {}",
+        u8::from(props.filled),
+        props.weight,
+        props.grade
+    );
+
     rsx! {
-        p { style: "font-family: 'Material Symbols Rounded';", ..props.attributes, "{props.icon.to_string()}" }
+        p {
+            style: "font-family: 'Material Symbols Rounded'; font-variation-settings: {font_variation_settings};",
+            ..props.attributes,
+            "{name}"
+        }
+    }
+}
+
+#[cfg(feature = "svg-icons")]
+fn svg_path(name: &str, filled: bool) -> Option<&'static str> {
+    svg::svg_paths(name).map(
+        |(filled_path, outlined_path)| {
+            if filled { filled_path } else { outlined_path }
+        },
+    )
+}
+
+#[cfg(not(feature = "svg-icons"))]
+fn svg_path(_name: &str, _filled: bool) -> Option<&'static str> {
+    None
+}
+
+/// An icon that is not part of [`Icons`], rendered with `Icons::Custom`.
+///
+/// ```
+/// # use dioxus::prelude::*;
+/// # use dioxus_tw_components::prelude::*;
+/// const LOGO: CustomIcon = CustomIcon::svg("logo", "0 0 24 24", "M12 2 2 22h20z");
+///
+/// # fn App() -> Element {
+/// rsx! {
+///     Icon { icon: Icons::Custom(LOGO) }
+/// }
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct CustomIcon {
+    name: &'static str,
+    svg: Option<(&'static str, &'static str)>,
+}
+
+impl CustomIcon {
+    /// A Material Symbols icon left out of [`Icons`] at build time, rendered from its ligature `name`.
+    pub const fn ligature(name: &'static str) -> Self {
+        Self { name, svg: None }
+    }
+
+    /// An SVG `path` drawn in `view_box`, rendered as inline SVG whatever the backend.
+    /// The `filled`, `weight` and `grade` props of `Icon` do not apply to it.
+    pub const fn svg(name: &'static str, view_box: &'static str, path: &'static str) -> Self {
+        Self {
+            name,
+            svg: Some((view_box, path)),
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
     }
 }
 
-#[derive(Default, Debug, PartialEq, Copy, Clone, strum::Display)]
-#[strum(serialize_all = "snake_case")]
-pub enum Icons {
-    #[default]
-    Abc,
-    AcUnit,
-    AccessAlarm,
-    AccessAlarms,
-    AccessTime,
-    AccessTimeFilled,
-    Accessibility,
-    AccessibilityNew,
-    Accessible,
-    AccessibleForward,
-    AccessibleMenu,
-    AccountBalance,
-    AccountBalanceWallet,
-    AccountBox,
-    AccountChild,
-    AccountChildInvert,
-    AccountCircle,
-    AccountCircleFilled,
-    AccountCircleOff,
-    AccountTree,
-    ActionKey,
-    ActivityZone,
-    Acupuncture,
-    Acute,
-    Ad,
-    AdGroup,
-    AdGroupOff,
-    AdOff,
-    AdUnits,
-    AdaptiveAudioMic,
-    AdaptiveAudioMicOff,
-    Adb,
-    Add,
-    AddAPhoto,
-    AddAd,
-    AddAlarm,
-    AddAlert,
-    AddBox,
-    AddBusiness,
-    AddCall,
-    AddCard,
-    AddChart,
-    AddCircle,
-    AddCircleOutline,
-    AddColumnLeft,
-    AddColumnRight,
-    AddComment,
-    AddDiamond,
-    AddHome,
-    AddHomeWork,
-    AddIcCall,
-    AddLink,
-    AddLocation,
-    AddLocationAlt,
-    AddModerator,
-    AddNotes,
-    AddPhotoAlternate,
-    AddReaction,
-    AddRoad,
-    AddRowAbove,
-    AddRowBelow,
-    AddShoppingCart,
-    AddTask,
-    AddToDrive,
-    AddToHomeScreen,
-    AddToPhotos,
-    AddToQueue,
-    AddTriangle,
-    Add2,
-    Addchart,
-    AdfScanner,
-    Adjust,
-    AdminMeds,
-    AdminPanelSettings,
-    AdsClick,
-    Agender,
-    Agriculture,
-    Air,
-    AirFreshener,
-    AirPurifier,
-    AirPurifierGen,
-    AirlineSeatFlat,
-    AirlineSeatFlatAngled,
-    AirlineSeatIndividualSuite,
-    AirlineSeatLegroomExtra,
-    AirlineSeatLegroomNormal,
-    AirlineSeatLegroomReduced,
-    AirlineSeatReclineExtra,
-    AirlineSeatReclineNormal,
-    AirlineStops,
-    Airlines,
-    AirplaneTicket,
-    AirplanemodeActive,
-    AirplanemodeInactive,
-    Airplay,
-    AirportShuttle,
-    Airware,
-    Airwave,
-    Alarm,
-    AlarmAdd,
-    AlarmOff,
-    AlarmOn,
-    AlarmPause,
-    AlarmSmartWake,
-    Album,
-    AlignCenter,
-    AlignEnd,
-    AlignFlexCenter,
-    AlignFlexEnd,
-    AlignFlexStart,
-    AlignHorizontalCenter,
-    AlignHorizontalLeft,
-    AlignHorizontalRight,
-    AlignItemsStretch,
-    AlignJustifyCenter,
-    AlignJustifyFlexEnd,
-    AlignJustifyFlexStart,
-    AlignJustifySpaceAround,
-    AlignJustifySpaceBetween,
-    AlignJustifySpaceEven,
-    AlignJustifyStretch,
-    AlignSelfStretch,
-    AlignSpaceAround,
-    AlignSpaceBetween,
-    AlignSpaceEven,
-    AlignStart,
-    AlignStretch,
-    AlignVerticalBottom,
-    AlignVerticalCenter,
-    AlignVerticalTop,
-    AllInbox,
-    AllInclusive,
-    AllMatch,
-    AllOut,
-    Allergies,
-    Allergy,
-    AltRoute,
-    AlternateEmail,
-    Altitude,
-    AmbientScreen,
-    Ambulance,
-    Amend,
-    AmpStories,
-    Analytics,
-    Anchor,
-    Android,
-    AndroidCellDual4Bar,
-    AndroidCellDual4BarAlert,
-    AndroidCellDual4BarPlus,
-    AndroidCellDual5Bar,
-    AndroidCellDual5BarAlert,
-    AndroidCellDual5BarPlus,
-    AndroidCell4Bar,
-    AndroidCell4BarAlert,
-    AndroidCell4BarOff,
-    AndroidCell4BarPlus,
-    AndroidCell5Bar,
-    AndroidCell5BarAlert,
-    AndroidCell5BarOff,
-    AndroidCell5BarPlus,
-    AndroidWifi3Bar,
-    AndroidWifi3BarAlert,
-    AndroidWifi3BarLock,
-    AndroidWifi3BarOff,
-    AndroidWifi3BarPlus,
-    AndroidWifi3BarQuestion,
-    AndroidWifi4Bar,
-    AndroidWifi4BarAlert,
-    AndroidWifi4BarLock,
-    AndroidWifi4BarOff,
-    AndroidWifi4BarPlus,
-    AndroidWifi4BarQuestion,
-    AnimatedImages,
-    Animation,
-    Announcement,
-    Aod,
-    AodTablet,
-    AodWatch,
-    Apartment,
-    Api,
-    ApkDocument,
-    ApkInstall,
-    AppBadging,
-    AppBlocking,
-    AppPromo,
-    AppRegistration,
-    AppSettingsAlt,
-    AppShortcut,
-    Apparel,
-    Approval,
-    ApprovalDelegation,
-    ApprovalDelegationOff,
-    Apps,
-    AppsOutage,
-    Aq,
-    AqIndoor,
-    ArOnYou,
-    ArStickers,
-    Architecture,
-    Archive,
-    AreaChart,
-    ArmingCountdown,
-    ArrowAndEdge,
-    ArrowBack,
-    ArrowBackIos,
-    ArrowBackIosNew,
-    ArrowBack2,
-    ArrowCircleDown,
-    ArrowCircleLeft,
-    ArrowCircleRight,
-    ArrowCircleUp,
-    ArrowCoolDown,
-    ArrowDownward,
-    ArrowDownwardAlt,
-    ArrowDropDown,
-    ArrowDropDownCircle,
-    ArrowDropUp,
-    ArrowForward,
-    ArrowForwardIos,
-    ArrowInsert,
-    ArrowLeft,
-    ArrowLeftAlt,
-    ArrowMenuClose,
-    ArrowMenuOpen,
-    ArrowOrEdge,
-    ArrowOutward,
-    ArrowRange,
-    ArrowRight,
-    ArrowRightAlt,
-    ArrowSelectorTool,
-    ArrowShapeUp,
-    ArrowShapeUpStack,
-    ArrowShapeUpStack2,
-    ArrowSplit,
-    ArrowTopLeft,
-    ArrowTopRight,
-    ArrowUploadProgress,
-    ArrowUploadReady,
-    ArrowUpward,
-    ArrowUpwardAlt,
-    ArrowWarmUp,
-    ArrowsInput,
-    ArrowsMoreDown,
-    ArrowsMoreUp,
-    ArrowsOutput,
-    ArrowsOutward,
-    ArtTrack,
-    Article,
-    ArticlePerson,
-    ArticleShortcut,
-    Artist,
-    AspectRatio,
-    Assessment,
-    Assignment,
-    AssignmentAdd,
-    AssignmentGlobe,
-    AssignmentInd,
-    AssignmentLate,
-    AssignmentReturn,
-    AssignmentReturned,
-    AssignmentTurnedIn,
-    AssistWalker,
-    Assistant,
-    AssistantDevice,
-    AssistantDirection,
-    AssistantNavigation,
-    AssistantOnHub,
-    AssistantPhoto,
-    AssuredWorkload,
-    Asterisk,
-    AstrophotographyAuto,
-    AstrophotographyOff,
-    Atm,
-    Atr,
-    AttachEmail,
-    AttachFile,
-    AttachFileAdd,
-    AttachFileOff,
-    AttachMoney,
-    Attachment,
-    Attractions,
-    Attribution,
-    AudioDescription,
-    AudioFile,
-    AudioVideoReceiver,
-    Audiotrack,
-    AutoActivityZone,
-    AutoAwesome,
-    AutoAwesomeMosaic,
-    AutoAwesomeMotion,
-    AutoDelete,
-    AutoDetectVoice,
-    AutoDrawSolid,
-    AutoFix,
-    AutoFixHigh,
-    AutoFixNormal,
-    AutoFixOff,
-    AutoGraph,
-    AutoLabel,
-    AutoMeetingRoom,
-    AutoMode,
-    AutoReadPause,
-    AutoReadPlay,
-    AutoSchedule,
-    AutoStories,
-    AutoStoriesOff,
-    AutoTimer,
-    AutoTowing,
-    AutoTransmission,
-    AutoVideocam,
-    AutofpsSelect,
-    Automation,
-    Autopause,
-    Autopay,
-    Autoplay,
-    Autorenew,
-    Autostop,
-    Av1,
-    AvTimer,
-    Avc,
-    AvgPace,
-    AvgTime,
-    AwardMeal,
-    AwardStar,
-    Azm,
-    BabyChangingStation,
-    BackHand,
-    BackToTab,
-    BackgroundDotLarge,
-    BackgroundDotSmall,
-    BackgroundGridSmall,
-    BackgroundReplace,
-    BacklightHigh,
-    BacklightHighOff,
-    BacklightLow,
-    Backpack,
-    Backspace,
-    Backup,
-    BackupTable,
-    Badge,
-    BadgeCriticalBattery,
-    Badminton,
-    BakeryDining,
-    Balance,
-    Balcony,
-    Ballot,
-    BarChart,
-    BarChartOff,
-    BarChart4Bars,
-    Barcode,
-    BarcodeReader,
-    BarcodeScanner,
-    Barefoot,
-    BatchPrediction,
-    BathBedrock,
-    BathOutdoor,
-    BathPrivate,
-    BathPublicLarge,
-    BathSoak,
-    Bathroom,
-    Bathtub,
-    BatteryAlert,
-    BatteryAndroidAlert,
-    BatteryAndroidBolt,
-    BatteryAndroidFrameAlert,
-    BatteryAndroidFrameBolt,
-    BatteryAndroidFrameFull,
-    BatteryAndroidFramePlus,
-    BatteryAndroidFrameQuestion,
-    BatteryAndroidFrameShare,
-    BatteryAndroidFrameShield,
-    BatteryAndroidFrame1,
-    BatteryAndroidFrame2,
-    BatteryAndroidFrame3,
-    BatteryAndroidFrame4,
-    BatteryAndroidFrame5,
-    BatteryAndroidFrame6,
-    BatteryAndroidFull,
-    BatteryAndroidPlus,
-    BatteryAndroidQuestion,
-    BatteryAndroidShare,
-    BatteryAndroidShield,
-    BatteryAndroid0,
-    BatteryAndroid1,
-    BatteryAndroid2,
-    BatteryAndroid3,
-    BatteryAndroid4,
-    BatteryAndroid5,
-    BatteryAndroid6,
-    BatteryChange,
-    BatteryChargingFull,
-    BatteryCharging20,
-    BatteryCharging30,
-    BatteryCharging50,
-    BatteryCharging60,
-    BatteryCharging80,
-    BatteryCharging90,
-    BatteryError,
-    BatteryFull,
-    BatteryFullAlt,
-    BatteryHoriz000,
-    BatteryHoriz050,
-    BatteryHoriz075,
-    BatteryLow,
-    BatteryPlus,
-    BatteryProfile,
-    BatterySaver,
-    BatteryShare,
-    BatteryStatusGood,
-    BatteryStd,
-    BatteryUnknown,
-    BatteryVert005,
-    BatteryVert020,
-    BatteryVert050,
-    BatteryVeryLow,
-    Battery0Bar,
-    Battery1Bar,
-    Battery20,
-    Battery2Bar,
-    Battery30,
-    Battery3Bar,
-    Battery4Bar,
-    Battery50,
-    Battery5Bar,
-    Battery60,
-    Battery6Bar,
-    Battery80,
-    Battery90,
-    BeachAccess,
-    Bed,
-    BedroomBaby,
-    BedroomChild,
-    BedroomParent,
-    Bedtime,
-    BedtimeOff,
-    Beenhere,
-    BeerMeal,
-    Bento,
-    Bia,
-    BidLandscape,
-    BidLandscapeDisabled,
-    BigtopUpdates,
-    BikeDock,
-    BikeLane,
-    BikeScooter,
-    Biotech,
-    Blanket,
-    Blender,
-    Blind,
-    Blinds,
-    BlindsClosed,
-    Block,
-    BloodPressure,
-    Bloodtype,
-    Bluetooth,
-    BluetoothAudio,
-    BluetoothConnected,
-    BluetoothDisabled,
-    BluetoothDrive,
-    BluetoothSearching,
-    BlurCircular,
-    BlurLinear,
-    BlurMedium,
-    BlurOff,
-    BlurOn,
-    BlurShort,
-    BoatBus,
-    BoatRailway,
-    BodyFat,
-    BodySystem,
-    Bolt,
-    Bomb,
-    Book,
-    BookOnline,
-    BookRibbon,
-    Book2,
-    Book3,
-    Book4,
-    Book5,
-    Book6,
-    Bookmark,
-    BookmarkAdd,
-    BookmarkAdded,
-    BookmarkBag,
-    BookmarkBorder,
-    BookmarkCheck,
-    BookmarkFlag,
-    BookmarkHeart,
-    BookmarkManager,
-    BookmarkRemove,
-    BookmarkStar,
-    Bookmarks,
-    BooksMoviesAndMusic,
-    BorderAll,
-    BorderBottom,
-    BorderClear,
-    BorderColor,
-    BorderHorizontal,
-    BorderInner,
-    BorderLeft,
-    BorderOuter,
-    BorderRight,
-    BorderStyle,
-    BorderTop,
-    BorderVertical,
-    Borg,
-    BottomAppBar,
-    BottomDrawer,
-    BottomNavigation,
-    BottomPanelClose,
-    BottomPanelOpen,
-    BottomRightClick,
-    BottomSheets,
-    Box,
-    BoxAdd,
-    BoxEdit,
-    Boy,
-    BrandAwareness,
-    BrandFamily,
-    BrandingWatermark,
-    BreakfastDining,
-    BreakingNews,
-    BreakingNewsAlt1,
-    Breastfeeding,
-    Brick,
-    BriefcaseMeal,
-    BrightnessAlert,
-    BrightnessAuto,
-    BrightnessEmpty,
-    BrightnessHigh,
-    BrightnessLow,
-    BrightnessMedium,
-    Brightness1,
-    Brightness2,
-    Brightness3,
-    Brightness4,
-    Brightness5,
-    Brightness6,
-    Brightness7,
-    BringYourOwnIp,
-    BroadcastOnHome,
-    BroadcastOnPersonal,
-    BrokenImage,
-    Browse,
-    BrowseActivity,
-    BrowseGallery,
-    BrowserNotSupported,
-    BrowserUpdated,
-    BrunchDining,
-    Brush,
-    Bubble,
-    BubbleChart,
-    Bubbles,
-    BucketCheck,
-    BugReport,
-    Build,
-    BuildCircle,
-    Bungalow,
-    BurstMode,
-    BusAlert,
-    BusRailway,
-    Business,
-    BusinessCenter,
-    BusinessChip,
-    BusinessMessages,
-    ButtonsAlt,
-    Cabin,
-    Cable,
-    CableCar,
-    Cached,
-    Cadence,
-    Cake,
-    CakeAdd,
-    Calculate,
-    CalendarAddOn,
-    CalendarAppsScript,
-    CalendarCheck,
-    CalendarClock,
-    CalendarLock,
-    CalendarMeal,
-    CalendarMeal2,
-    CalendarMonth,
-    CalendarToday,
-    CalendarViewDay,
-    CalendarViewMonth,
-    CalendarViewWeek,
-    Call,
-    CallEnd,
-    CallEndAlt,
-    CallLog,
-    CallMade,
-    CallMerge,
-    CallMissed,
-    CallMissedOutgoing,
-    CallQuality,
-    CallReceived,
-    CallSplit,
-    CallToAction,
-    Camera,
-    CameraAlt,
-    CameraEnhance,
-    CameraFront,
-    CameraIndoor,
-    CameraOutdoor,
-    CameraRear,
-    CameraRoll,
-    CameraVideo,
-    Cameraswitch,
-    Campaign,
-    Camping,
-    Cancel,
-    CancelPresentation,
-    CancelScheduleSend,
-    Candle,
-    CandlestickChart,
-    Cannabis,
-    CaptivePortal,
-    Capture,
-    CarCrash,
-    CarDefrostLeft,
-    CarDefrostLowLeft,
-    CarDefrostLowRight,
-    CarDefrostMidLeft,
-    CarDefrostMidLowLeft,
-    CarDefrostMidLowRight,
-    CarDefrostMidRight,
-    CarDefrostRight,
-    CarFanLowLeft,
-    CarFanLowMidLeft,
-    CarFanLowRight,
-    CarFanMidLeft,
-    CarFanMidLowRight,
-    CarFanMidRight,
-    CarFanRecirculate,
-    CarGear,
-    CarLock,
-    CarMirrorHeat,
-    CarRental,
-    CarRepair,
-    CarTag,
-    CardGiftcard,
-    CardMembership,
-    CardTravel,
-    CardioLoad,
-    Cardiology,
-    Cards,
-    CardsStar,
-    Carpenter,
-    CarryOnBag,
-    CarryOnBagChecked,
-    CarryOnBagInactive,
-    CarryOnBagQuestion,
-    Cases,
-    Casino,
-    Cast,
-    CastConnected,
-    CastForEducation,
-    CastPause,
-    CastWarning,
-    Castle,
-    Category,
-    CategorySearch,
-    Celebration,
-    CellMerge,
-    CellTower,
-    CellWifi,
-    CenterFocusStrong,
-    CenterFocusWeak,
-    Chair,
-    ChairAlt,
-    ChairCounter,
-    ChairFireplace,
-    ChairUmbrella,
-    Chalet,
-    ChangeCircle,
-    ChangeHistory,
-    Charger,
-    ChargingStation,
-    ChartData,
-    Chat,
-    ChatAddOn,
-    ChatAppsScript,
-    ChatBubble,
-    ChatBubbleOutline,
-    ChatDashed,
-    ChatError,
-    ChatInfo,
-    ChatPasteGo,
-    ChatPasteGo2,
-    Check,
-    CheckBox,
-    CheckBoxOutlineBlank,
-    CheckCircle,
-    CheckCircleFilled,
-    CheckCircleOutline,
-    CheckCircleUnread,
-    CheckInOut,
-    CheckIndeterminateSmall,
-    CheckSmall,
-    Checkbook,
-    CheckedBag,
-    CheckedBagQuestion,
-    Checklist,
-    ChecklistRtl,
-    Checkroom,
-    Cheer,
-    ChefHat,
-    Chess,
-    ChessBishop,
-    ChessBishop2,
-    ChessKing,
-    ChessKing2,
-    ChessKnight,
-    ChessPawn,
-    ChessPawn2,
-    ChessQueen,
-    ChessRook,
-    ChevronBackward,
-    ChevronForward,
-    ChevronLeft,
-    ChevronRight,
-    ChildCare,
-    ChildFriendly,
-    ChildHat,
-    ChipExtraction,
-    Chips,
-    ChromeReaderMode,
-    ChromecastDevice,
-    Chromecast2,
-    Chronic,
-    Church,
-    CinematicBlur,
-    Circle,
-    CircleNotifications,
-    Circles,
-    CirclesExt,
-    Clarify,
-    Class,
-    CleanHands,
-    Cleaning,
-    CleaningBucket,
-    CleaningServices,
-    Clear,
-    ClearAll,
-    ClearDay,
-    ClearNight,
-    ClimateMiniSplit,
-    ClinicalNotes,
-    ClockArrowDown,
-    ClockArrowUp,
-    ClockLoader10,
-    ClockLoader20,
-    ClockLoader40,
-    ClockLoader60,
-    ClockLoader80,
-    ClockLoader90,
-    Close,
-    CloseFullscreen,
-    CloseSmall,
-    ClosedCaption,
-    ClosedCaptionAdd,
-    ClosedCaptionDisabled,
-    ClosedCaptionOff,
-    Cloud,
-    CloudAlert,
-    CloudCircle,
-    CloudDone,
-    CloudDownload,
-    CloudLock,
-    CloudOff,
-    CloudQueue,
-    CloudSync,
-    CloudUpload,
-    Cloudy,
-    CloudyFilled,
-    CloudySnowing,
-    Co2,
-    CoPresent,
-    Code,
-    CodeBlocks,
-    CodeOff,
-    Coffee,
-    CoffeeMaker,
-    Cognition,
-    Cognition2,
-    CollapseAll,
-    CollapseContent,
-    Collections,
-    CollectionsBookmark,
-    ColorLens,
-    Colorize,
-    Colors,
-    CombineColumns,
-    ComedyMask,
-    ComicBubble,
-    Comment,
-    CommentBank,
-    CommentsDisabled,
-    Commit,
-    Communication,
-    Communities,
-    CommunitiesFilled,
-    Commute,
-    Compare,
-    CompareArrows,
-    CompassCalibration,
-    ComponentExchange,
-    Compost,
-    Compress,
-    Computer,
-    ComputerArrowUp,
-    ComputerCancel,
-    Concierge,
-    Conditions,
-    ConfirmationNumber,
-    Congenital,
-    ConnectWithoutContact,
-    ConnectedTv,
-    ConnectingAirports,
-    Construction,
-    ContactEmergency,
-    ContactMail,
-    ContactPage,
-    ContactPhone,
-    ContactPhoneFilled,
-    ContactSupport,
-    Contactless,
-    ContactlessOff,
-    Contacts,
-    ContactsProduct,
-    ContentCopy,
-    ContentCut,
-    ContentPaste,
-    ContentPasteGo,
-    ContentPasteOff,
-    ContentPasteSearch,
-    ContextualToken,
-    ContextualTokenAdd,
-    Contract,
-    ContractDelete,
-    ContractEdit,
-    Contrast,
-    ContrastCircle,
-    ContrastRtlOff,
-    ContrastSquare,
-    ControlCamera,
-    ControlPoint,
-    ControlPointDuplicate,
-    ControllerGen,
-    Conversation,
-    ConversionPath,
-    ConversionPathOff,
-    ConvertToText,
-    ConveyorBelt,
-    Cookie,
-    CookieOff,
-    Cooking,
-    CoolToDry,
-    CopyAll,
-    Copyright,
-    Coronavirus,
-    CorporateFare,
-    Cottage,
-    Counter0,
-    Counter1,
-    Counter2,
-    Counter3,
-    Counter4,
-    Counter5,
-    Counter6,
-    Counter7,
-    Counter8,
-    Counter9,
-    Countertops,
-    Create,
-    CreateNewFolder,
-    CreditCard,
-    CreditCardClock,
-    CreditCardGear,
-    CreditCardHeart,
-    CreditCardOff,
-    CreditScore,
-    Crib,
-    CrisisAlert,
-    Crop,
-    CropDin,
-    CropFree,
-    CropLandscape,
-    CropOriginal,
-    CropPortrait,
-    CropRotate,
-    CropSquare,
-    Crop169,
-    Crop32,
-    Crop54,
-    Crop75,
-    Crop916,
-    Crossword,
-    Crowdsource,
-    Crown,
-    CrueltyFree,
-    Css,
-    Csv,
-    CurrencyBitcoin,
-    CurrencyExchange,
-    CurrencyFranc,
-    CurrencyLira,
-    CurrencyPound,
-    CurrencyRuble,
-    CurrencyRupee,
-    CurrencyRupeeCircle,
-    CurrencyYen,
-    CurrencyYuan,
-    Curtains,
-    CurtainsClosed,
-    CustomTypography,
-    Cut,
-    Cycle,
-    Cyclone,
-    Dangerous,
-    DarkMode,
-    Dashboard,
-    DashboardCustomize,
-    Dashboard2,
-    DataAlert,
-    DataArray,
-    DataCheck,
-    DataExploration,
-    DataInfoAlert,
-    DataLossPrevention,
-    DataObject,
-    DataSaverOff,
-    DataSaverOn,
-    DataTable,
-    DataThresholding,
-    DataUsage,
-    Database,
-    DatabaseOff,
-    DatabaseSearch,
-    DatabaseUpload,
-    Dataset,
-    DatasetLinked,
-    DateRange,
-    Deblur,
-    Deceased,
-    DecimalDecrease,
-    DecimalIncrease,
-    Deck,
-    Dehaze,
-    Delete,
-    DeleteForever,
-    DeleteHistory,
-    DeleteOutline,
-    DeleteSweep,
-    DeliveryDining,
-    DeliveryTruckBolt,
-    DeliveryTruckSpeed,
-    Demography,
-    DensityLarge,
-    DensityMedium,
-    DensitySmall,
-    Dentistry,
-    DepartureBoard,
-    DeployedCode,
-    DeployedCodeAccount,
-    DeployedCodeAlert,
-    DeployedCodeHistory,
-    DeployedCodeUpdate,
-    Dermatology,
-    Description,
-    Deselect,
-    DesignServices,
-    Desk,
-    Deskphone,
-    DesktopAccessDisabled,
-    DesktopCloud,
-    DesktopCloudStack,
-    DesktopLandscape,
-    DesktopLandscapeAdd,
-    DesktopMac,
-    DesktopPortrait,
-    DesktopWindows,
-    Destruction,
-    Details,
-    DetectionAndZone,
-    Detector,
-    DetectorAlarm,
-    DetectorBattery,
-    DetectorCo,
-    DetectorOffline,
-    DetectorSmoke,
-    DetectorStatus,
-    DeveloperBoard,
-    DeveloperBoardOff,
-    DeveloperGuide,
-    DeveloperMode,
-    DeveloperModeTv,
-    DeviceBand,
-    DeviceHub,
-    DeviceReset,
-    DeviceThermostat,
-    DeviceUnknown,
-    Devices,
-    DevicesFold,
-    DevicesFold2,
-    DevicesOff,
-    DevicesOther,
-    DevicesWearables,
-    DewPoint,
-    Diagnosis,
-    DiagonalLine,
-    DialerSip,
-    Dialogs,
-    Dialpad,
-    Diamond,
-    DiamondShine,
-    Dictionary,
-    Difference,
-    DigitalOutOfHome,
-    DigitalWellbeing,
-    DineHeart,
-    DineIn,
-    DineLamp,
-    Dining,
-    DinnerDining,
-    Directions,
-    DirectionsAlt,
-    DirectionsAltOff,
-    DirectionsBike,
-    DirectionsBoat,
-    DirectionsBoatFilled,
-    DirectionsBus,
-    DirectionsBusFilled,
-    DirectionsCar,
-    DirectionsCarFilled,
-    DirectionsOff,
-    DirectionsRailway,
-    DirectionsRailwayFilled,
-    DirectionsRailway2,
-    DirectionsRun,
-    DirectionsSubway,
-    DirectionsSubwayFilled,
-    DirectionsTransit,
-    DirectionsTransitFilled,
-    DirectionsWalk,
-    DirectorySync,
-    DirtyLens,
-    DisabledByDefault,
-    DisabledVisible,
-    DiscFull,
-    DiscoverTune,
-    Dishwasher,
-    DishwasherGen,
-    DisplayExternalInput,
-    DisplaySettings,
-    Distance,
-    Diversity1,
-    Diversity2,
-    Diversity3,
-    Diversity4,
-    Dns,
-    DoDisturb,
-    DoDisturbAlt,
-    DoDisturbOff,
-    DoDisturbOn,
-    DoNotDisturb,
-    DoNotDisturbAlt,
-    DoNotDisturbOff,
-    DoNotDisturbOn,
-    DoNotDisturbOnTotalSilence,
-    DoNotStep,
-    DoNotTouch,
-    Dock,
-    DockToBottom,
-    DockToLeft,
-    DockToRight,
-    Docs,
-    DocsAddOn,
-    DocsAppsScript,
-    DocumentScanner,
-    DocumentSearch,
-    Domain,
-    DomainAdd,
-    DomainDisabled,
-    DomainVerification,
-    DomainVerificationOff,
-    DominoMask,
-    Done,
-    DoneAll,
-    DoneOutline,
-    DonutLarge,
-    DonutSmall,
-    DoorBack,
-    DoorFront,
-    DoorOpen,
-    DoorSensor,
-    DoorSliding,
-    Doorbell,
-    DoorbellChime,
-    Doorbell3P,
-    DoubleArrow,
-    DownhillSkiing,
-    Download,
-    DownloadDone,
-    DownloadForOffline,
-    Download2,
-    Downloading,
-    Draft,
-    DraftOrders,
-    Drafts,
-    DragClick,
-    DragHandle,
-    DragIndicator,
-    DragPan,
-    Draw,
-    DrawAbstract,
-    DrawCollage,
-    DrawingRecognition,
-    Dresser,
-    DriveEta,
-    DriveExport,
-    DriveFileMove,
-    DriveFileMoveOutline,
-    DriveFileMoveRtl,
-    DriveFileRenameOutline,
-    DriveFolderUpload,
-    DriveFusiontable,
-    Drone,
-    Drone2,
-    Dropdown,
-    DropperEye,
-    Dry,
-    DryCleaning,
-    DualScreen,
-    Duo,
-    Dvr,
-    DynamicFeed,
-    DynamicForm,
-    E911Avatar,
-    E911Emergency,
-    EMobiledata,
-    EMobiledataBadge,
-    EarSound,
-    EarbudCase,
-    EarbudLeft,
-    EarbudRight,
-    Earbuds,
-    EarbudsBattery,
-    Earbuds2,
-    EarlyOn,
-    Earthquake,
-    East,
-    Ecg,
-    EcgHeart,
-    Eco,
-    Eda,
-    EdgesensorHigh,
-    EdgesensorLow,
-    Edit,
-    EditArrowDown,
-    EditArrowUp,
-    EditAttributes,
-    EditAudio,
-    EditCalendar,
-    EditDocument,
-    EditLocation,
-    EditLocationAlt,
-    EditNote,
-    EditNotifications,
-    EditOff,
-    EditRoad,
-    EditSquare,
-    EditorChoice,
-    Egg,
-    EggAlt,
-    EightK,
-    EightKPlus,
-    EightMp,
-    EighteenMp,
-    EighteenUpRating,
-    Eject,
-    Elderly,
-    ElderlyWoman,
-    ElectricBike,
-    ElectricBolt,
-    ElectricCar,
-    ElectricMeter,
-    ElectricMoped,
-    ElectricRickshaw,
-    ElectricScooter,
-    ElectricalServices,
-    Elevation,
-    Elevator,
-    ElevenMp,
-    Email,
-    Emergency,
-    EmergencyHeat,
-    EmergencyHeat2,
-    EmergencyHome,
-    EmergencyRecording,
-    EmergencyShare,
-    EmergencyShareOff,
-    EmojiEmotions,
-    EmojiEvents,
-    EmojiFlags,
-    EmojiFoodBeverage,
-    EmojiLanguage,
-    EmojiNature,
-    EmojiObjects,
-    EmojiPeople,
-    EmojiSymbols,
-    EmojiTransportation,
-    Emoticon,
-    EmptyDashboard,
-    Enable,
-    Encrypted,
-    EncryptedAdd,
-    EncryptedAddCircle,
-    EncryptedMinusCircle,
-    EncryptedOff,
-    Endocrinology,
-    Energy,
-    EnergyProgramSaving,
-    EnergyProgramTimeUsed,
-    EnergySavingsLeaf,
-    Engineering,
-    EnhancedEncryption,
-    Ent,
-    Enterprise,
-    EnterpriseOff,
-    Equal,
-    Equalizer,
-    EraserSize1,
-    EraserSize2,
-    EraserSize3,
-    EraserSize4,
-    EraserSize5,
-    Error,
-    ErrorCircleRounded,
-    ErrorMed,
-    ErrorOutline,
-    Escalator,
-    EscalatorWarning,
-    Euro,
-    EuroSymbol,
-    EvCharger,
-    EvMobiledataBadge,
-    EvShadow,
-    EvShadowAdd,
-    EvShadowMinus,
-    EvStation,
-    Event,
-    EventAvailable,
-    EventBusy,
-    EventList,
-    EventNote,
-    EventRepeat,
-    EventSeat,
-    EventUpcoming,
-    Exclamation,
-    Exercise,
-    ExitToApp,
-    Expand,
-    ExpandAll,
-    ExpandCircleDown,
-    ExpandCircleRight,
-    ExpandCircleUp,
-    ExpandContent,
-    ExpandLess,
-    ExpandMore,
-    ExpansionPanels,
-    ExpensionPanels,
-    Experiment,
-    Explicit,
-    Explore,
-    ExploreNearby,
-    ExploreOff,
-    Explosion,
-    ExportNotes,
-    Exposure,
-    ExposureNeg1,
-    ExposureNeg2,
-    ExposurePlus1,
-    ExposurePlus2,
-    ExposureZero,
-    Extension,
-    ExtensionOff,
-    EyeTracking,
-    Eyeglasses,
-    Eyeglasses2,
-    Eyeglasses2Sound,
-    Face,
-    FaceDown,
-    FaceLeft,
-    FaceNod,
-    FaceRetouchingNatural,
-    FaceRetouchingOff,
-    FaceRight,
-    FaceShake,
-    FaceUnlock,
-    FaceUp,
-    Face2,
-    Face3,
-    Face4,
-    Face5,
-    Face6,
-    FactCheck,
-    Factory,
-    Falling,
-    FamiliarFaceAndZone,
-    FamilyGroup,
-    FamilyHistory,
-    FamilyHome,
-    FamilyLink,
-    FamilyRestroom,
-    FamilyStar,
-    FanFocus,
-    FanIndirect,
-    FarsightDigital,
-    FastForward,
-    FastRewind,
-    Fastfood,
-    Faucet,
-    Favorite,
-    FavoriteBorder,
-    Fax,
-    FeatureSearch,
-    FeaturedPlayList,
-    FeaturedSeasonalAndGifts,
-    FeaturedVideo,
-    Feed,
-    Feedback,
-    Female,
-    Femur,
-    FemurAlt,
-    Fence,
-    Fertile,
-    Festival,
-    FiberDvr,
-    FiberManualRecord,
-    FiberNew,
-    FiberPin,
-    FiberSmartRecord,
-    FifteenMp,
-    FileCopy,
-    FileCopyOff,
-    FileDownload,
-    FileDownloadDone,
-    FileDownloadOff,
-    FileExport,
-    FileJson,
-    FileMap,
-    FileMapStack,
-    FileOpen,
-    FilePng,
-    FilePresent,
-    FileSave,
-    FileSaveOff,
-    FileUpload,
-    FileUploadOff,
-    Files,
-    Filter,
-    FilterAlt,
-    FilterAltOff,
-    FilterArrowRight,
-    FilterBAndW,
-    FilterCenterFocus,
-    FilterDrama,
-    FilterFrames,
-    FilterHdr,
-    FilterList,
-    FilterListAlt,
-    FilterListOff,
-    FilterNone,
-    FilterRetrolux,
-    FilterTiltShift,
-    FilterVintage,
-    Filter1,
-    Filter2,
-    Filter3,
-    Filter4,
-    Filter5,
-    Filter6,
-    Filter7,
-    Filter8,
-    Filter9,
-    Filter9Plus,
-    Finance,
-    FinanceChip,
-    FinanceMode,
-    FindInPage,
-    FindReplace,
-    Fingerprint,
-    FingerprintOff,
-    FireExtinguisher,
-    FireHydrant,
-    FireTruck,
-    Fireplace,
-    FirstPage,
-    FitPage,
-    FitPageHeight,
-    FitPageWidth,
-    FitScreen,
-    FitWidth,
-    FitnessCenter,
-    FitnessTracker,
-    FitnessTrackers,
-    FiveG,
-    FiveK,
-    FiveKPlus,
-    FiveMp,
-    Flag,
-    FlagCheck,
-    FlagCircle,
-    FlagFilled,
-    Flag2,
-    Flaky,
-    Flare,
-    FlashAuto,
-    FlashOff,
-    FlashOn,
-    FlashlightOff,
-    FlashlightOn,
-    Flatware,
-    FlexDirection,
-    FlexNoWrap,
-    FlexWrap,
-    Flight,
-    FlightClass,
-    FlightLand,
-    FlightTakeoff,
-    FlightsAndHotels,
-    Flightsmode,
-    Flip,
-    FlipCameraAndroid,
-    FlipCameraIos,
-    FlipToBack,
-    FlipToFront,
-    FloatLandscape2,
-    FloatPortrait2,
-    Flood,
-    Floor,
-    FloorLamp,
-    Flourescent,
-    Flowchart,
-    Flowsheet,
-    Fluid,
-    FluidBalance,
-    FluidMed,
-    Fluorescent,
-    Flutter,
-    FlutterDash,
-    Flyover,
-    FmdBad,
-    FmdGood,
-    Foggy,
-    FoldedHands,
-    Folder,
-    FolderCheck,
-    FolderCheck2,
-    FolderCode,
-    FolderCopy,
-    FolderData,
-    FolderDelete,
-    FolderEye,
-    FolderInfo,
-    FolderLimited,
-    FolderManaged,
-    FolderMatch,
-    FolderOff,
-    FolderOpen,
-    FolderShared,
-    FolderSpecial,
-    FolderSupervised,
-    FolderZip,
-    FollowTheSigns,
-    FontDownload,
-    FontDownloadOff,
-    FoodBank,
-    FootBones,
-    Footprint,
-    ForYou,
-    Forest,
-    ForkLeft,
-    ForkRight,
-    ForkSpoon,
-    Forklift,
-    FormatAlignCenter,
-    FormatAlignJustify,
-    FormatAlignLeft,
-    FormatAlignRight,
-    FormatBold,
-    FormatClear,
-    FormatColorFill,
-    FormatColorReset,
-    FormatColorText,
-    FormatH1,
-    FormatH2,
-    FormatH3,
-    FormatH4,
-    FormatH5,
-    FormatH6,
-    FormatImageLeft,
-    FormatImageRight,
-    FormatIndentDecrease,
-    FormatIndentIncrease,
-    FormatInkHighlighter,
-    FormatItalic,
-    FormatLetterSpacing,
-    FormatLetterSpacingStandard,
-    FormatLetterSpacingWide,
-    FormatLetterSpacingWider,
-    FormatLetterSpacing2,
-    FormatLineSpacing,
-    FormatListBulleted,
-    FormatListBulletedAdd,
-    FormatListNumbered,
-    FormatListNumberedRtl,
-    FormatOverline,
-    FormatPaint,
-    FormatParagraph,
-    FormatQuote,
-    FormatQuoteOff,
-    FormatShapes,
-    FormatSize,
-    FormatStrikethrough,
-    FormatTextClip,
-    FormatTextOverflow,
-    FormatTextWrap,
-    FormatTextdirectionLToR,
-    FormatTextdirectionRToL,
-    FormatTextdirectionVertical,
-    FormatUnderlined,
-    FormatUnderlinedSquiggle,
-    FormsAddOn,
-    FormsAppsScript,
-    Fort,
-    Forum,
-    Forward,
-    ForwardCircle,
-    ForwardMedia,
-    ForwardToInbox,
-    Forward10,
-    Forward30,
-    Forward5,
-    Foundation,
-    FourK,
-    FourKPlus,
-    FourMp,
-    FourteenMp,
-    Fragrance,
-    FrameBug,
-    FrameExclamation,
-    FrameInspect,
-    FramePerson,
-    FramePersonMic,
-    FramePersonOff,
-    FrameReload,
-    FrameSource,
-    FreeBreakfast,
-    FreeCancellation,
-    FrontHand,
-    FrontLoader,
-    FullCoverage,
-    FullHd,
-    FullStackedBarChart,
-    Fullscreen,
-    FullscreenExit,
-    FullscreenPortrait,
-    Function,
-    Functions,
-    Funicular,
-    GMobiledata,
-    GMobiledataBadge,
-    GTranslate,
-    GalleryThumbnail,
-    Gamepad,
-    Games,
-    Garage,
-    GarageCheck,
-    GarageDoor,
-    GarageHome,
-    GarageMoney,
-    GardenCart,
-    GasMeter,
-    Gastroenterology,
-    Gate,
-    Gavel,
-    GeneralDevice,
-    GeneratingTokens,
-    Genetics,
-    Genres,
-    Gesture,
-    GestureSelect,
-    GetApp,
-    Gif,
-    GifBox,
-    Gif2,
-    Girl,
-    Gite,
-    GlassCup,
-    Globe,
-    GlobeAsia,
-    GlobeBook,
-    GlobeLocationPin,
-    GlobeUk,
-    Glucose,
-    Glyphs,
-    GoToLine,
-    GolfCourse,
-    GondolaLift,
-    GoogleHomeDevices,
-    GooglePlusReshare,
-    GoogleTvRemote,
-    GoogleWifi,
-    GppBad,
-    GppGood,
-    GppMaybe,
-    GpsFixed,
-    GpsNotFixed,
-    GpsOff,
-    Grade,
-    Gradient,
-    Grading,
-    Grain,
-    Graph1,
-    Graph2,
-    Graph3,
-    Graph4,
-    Graph5,
-    Graph6,
-    Graph7,
-    GraphicEq,
-    Grass,
-    GridGoldenratio,
-    GridGuides,
-    GridOff,
-    GridOn,
-    GridView,
-    Grid3X3,
-    Grid3X3Off,
-    Grid4X4,
-    Grocery,
-    Group,
-    GroupAdd,
-    GroupOff,
-    GroupRemove,
-    GroupSearch,
-    GroupWork,
-    GroupedBarChart,
-    Groups,
-    Groups2,
-    Groups3,
-    Guardian,
-    Gynecology,
-    HMobiledata,
-    HMobiledataBadge,
-    HPlusMobiledata,
-    HPlusMobiledataBadge,
-    Hail,
-    Hallway,
-    HanamiDango,
-    HandBones,
-    HandGesture,
-    HandGestureOff,
-    HandMeal,
-    HandPackage,
-    HandheldController,
-    Handshake,
-    HandwritingRecognition,
-    Handyman,
-    HangoutVideo,
-    HangoutVideoOff,
-    HardDisk,
-    HardDrive,
-    HardDrive2,
-    Hardware,
-    Hd,
-    HdrAuto,
-    HdrAutoSelect,
-    HdrEnhancedSelect,
-    HdrOff,
-    HdrOffSelect,
-    HdrOn,
-    HdrOnSelect,
-    HdrPlus,
-    HdrPlusOff,
-    HdrStrong,
-    HdrWeak,
-    HeadMountedDevice,
-    Headphones,
-    HeadphonesBattery,
-    Headset,
-    HeadsetMic,
-    HeadsetOff,
-    Healing,
-    HealthAndBeauty,
-    HealthAndSafety,
-    HealthCross,
-    HealthMetrics,
-    HeapSnapshotLarge,
-    HeapSnapshotMultiple,
-    HeapSnapshotThumbnail,
-    Hearing,
-    HearingAid,
-    HearingAidDisabled,
-    HearingAidDisabledLeft,
-    HearingAidLeft,
-    HearingDisabled,
-    HeartBroken,
-    HeartCheck,
-    HeartMinus,
-    HeartPlus,
-    HeartSmile,
-    Heat,
-    HeatPump,
-    HeatPumpBalance,
-    Height,
-    Helicopter,
-    Help,
-    HelpCenter,
-    HelpClinic,
-    HelpOutline,
-    Hematology,
-    Hevc,
-    Hexagon,
-    Hide,
-    HideImage,
-    HideSource,
-    HighChair,
-    HighDensity,
-    HighQuality,
-    HighRes,
-    Highlight,
-    HighlightAlt,
-    HighlightKeyboardFocus,
-    HighlightMouseCursor,
-    HighlightOff,
-    HighlightTextCursor,
-    HighlighterSize1,
-    HighlighterSize2,
-    HighlighterSize3,
-    HighlighterSize4,
-    HighlighterSize5,
-    Hiking,
-    History,
-    HistoryEdu,
-    HistoryOff,
-    HistoryToggleOff,
-    History2,
-    Hive,
-    Hls,
-    HlsOff,
-    HolidayVillage,
-    Home,
-    HomeAndGarden,
-    HomeAppLogo,
-    HomeFilled,
-    HomeHealth,
-    HomeImprovementAndTools,
-    HomeIotDevice,
-    HomeMax,
-    HomeMaxDots,
-    HomeMini,
-    HomePin,
-    HomeRepairService,
-    HomeSpeaker,
-    HomeStorage,
-    HomeWork,
-    HorizontalDistribute,
-    HorizontalRule,
-    HorizontalSplit,
-    Host,
-    HotTub,
-    Hotel,
-    HotelClass,
-    Hourglass,
-    HourglassArrowDown,
-    HourglassArrowUp,
-    HourglassBottom,
-    HourglassDisabled,
-    HourglassEmpty,
-    HourglassFull,
-    HourglassPause,
-    HourglassTop,
-    House,
-    HouseSiding,
-    HouseWithShield,
-    Houseboat,
-    HouseholdSupplies,
-    Hov,
-    HowToReg,
-    HowToVote,
-    HrResting,
-    Html,
-    Http,
-    Https,
-    Hub,
-    Humerus,
-    HumerusAlt,
-    HumidityHigh,
-    HumidityIndoor,
-    HumidityLow,
-    HumidityMid,
-    HumidityPercentage,
-    Hvac,
-    HvacMaxDefrost,
-    IceSkating,
-    Icecream,
-    IdCard,
-    IdentityAwareProxy,
-    IdentityPlatform,
-    Ifl,
-    Iframe,
-    IframeOff,
-    Image,
-    ImageArrowUp,
-    ImageAspectRatio,
-    ImageInset,
-    ImageNotSupported,
-    ImageSearch,
-    ImagesearchRoller,
-    Imagesmode,
-    Immunology,
-    ImportContacts,
-    ImportExport,
-    ImportantDevices,
-    InHomeMode,
-    InactiveOrder,
-    Inbox,
-    InboxCustomize,
-    InboxText,
-    InboxTextAsterisk,
-    InboxTextPerson,
-    InboxTextShare,
-    IncompleteCircle,
-    IndeterminateCheckBox,
-    IndeterminateQuestionBox,
-    Info,
-    InfoI,
-    Infrared,
-    InkEraser,
-    InkEraserOff,
-    InkHighlighter,
-    InkHighlighterMove,
-    InkMarker,
-    InkPen,
-    InkSelection,
-    Inpatient,
-    Input,
-    InputCircle,
-    InsertChart,
-    InsertChartFilled,
-    InsertChartOutlined,
-    InsertComment,
-    InsertDriveFile,
-    InsertEmoticon,
-    InsertInvitation,
-    InsertLink,
-    InsertPageBreak,
-    InsertPhoto,
-    InsertText,
-    Insights,
-    InstallDesktop,
-    InstallMobile,
-    InstantMix,
-    IntegrationInstructions,
-    InteractiveSpace,
-    Interests,
-    InterpreterMode,
-    Inventory,
-    Inventory2,
-    InvertColors,
-    InvertColorsOff,
-    Ios,
-    IosShare,
-    Iron,
-    Iso,
-    JamboardKiosk,
-    JapaneseCurry,
-    JapaneseFlag,
-    Javascript,
-    Join,
-    JoinFull,
-    JoinInner,
-    JoinLeft,
-    JoinRight,
-    Joystick,
-    JumpToElement,
-    KanjiAlcohol,
-    Kayaking,
-    KebabDining,
-    Keep,
-    KeepOff,
-    KeepPin,
-    KeepPublic,
-    Kettle,
-    Key,
-    KeyOff,
-    KeyVertical,
-    KeyVisualizer,
-    Keyboard,
-    KeyboardAlt,
-    KeyboardArrowDown,
-    KeyboardArrowLeft,
-    KeyboardArrowRight,
-    KeyboardArrowUp,
-    KeyboardBackspace,
-    KeyboardCapslock,
-    KeyboardCapslockBadge,
-    KeyboardCommandKey,
-    KeyboardControlKey,
-    KeyboardDoubleArrowDown,
-    KeyboardDoubleArrowLeft,
-    KeyboardDoubleArrowRight,
-    KeyboardDoubleArrowUp,
-    KeyboardExternalInput,
-    KeyboardFull,
-    KeyboardHide,
-    KeyboardKeys,
-    KeyboardLock,
-    KeyboardLockOff,
-    KeyboardOff,
-    KeyboardOnscreen,
-    KeyboardOptionKey,
-    KeyboardPreviousLanguage,
-    KeyboardReturn,
-    KeyboardTab,
-    KeyboardTabRtl,
-    KeyboardVoice,
-    KidStar,
-    KingBed,
-    Kitchen,
-    Kitesurfing,
-    LabPanel,
-    LabProfile,
-    LabResearch,
-    Label,
-    LabelImportant,
-    LabelImportantOutline,
-    LabelOff,
-    LabelOutline,
-    Labs,
-    Lan,
-    Landscape,
-    Landscape2,
-    Landscape2Edit,
-    Landscape2Off,
-    Landslide,
-    Language,
-    LanguageChineseArray,
-    LanguageChineseCangjie,
-    LanguageChineseDayi,
-    LanguageChinesePinyin,
-    LanguageChineseQuick,
-    LanguageChineseWubi,
-    LanguageFrench,
-    LanguageGbEnglish,
-    LanguageInternational,
-    LanguageJapaneseKana,
-    LanguageKoreanLatin,
-    LanguagePinyin,
-    LanguageSpanish,
-    LanguageUs,
-    LanguageUsColemak,
-    LanguageUsDvorak,
-    Laps,
-    Laptop,
-    LaptopCar,
-    LaptopChromebook,
-    LaptopMac,
-    LaptopWindows,
-    LassoSelect,
-    LastPage,
-    Launch,
-    Laundry,
-    Layers,
-    LayersClear,
-    Lda,
-    Leaderboard,
-    LeakAdd,
-    LeakRemove,
-    LeftClick,
-    LeftPanelClose,
-    LeftPanelOpen,
-    LegendToggle,
-    Lens,
-    LensBlur,
-    LetterSwitch,
-    LibraryAdd,
-    LibraryAddCheck,
-    LibraryBooks,
-    LibraryMusic,
-    License,
-    LiftToTalk,
-    Light,
-    LightGroup,
-    LightMode,
-    LightOff,
-    Lightbulb,
-    LightbulbCircle,
-    LightbulbOutline,
-    Lightbulb2,
-    LightningStand,
-    LineAxis,
-    LineCurve,
-    LineEnd,
-    LineEndArrow,
-    LineEndArrowNotch,
-    LineEndCircle,
-    LineEndDiamond,
-    LineEndSquare,
-    LineStart,
-    LineStartArrow,
-    LineStartArrowNotch,
-    LineStartCircle,
-    LineStartDiamond,
-    LineStartSquare,
-    LineStyle,
-    LineWeight,
-    LinearScale,
-    Link,
-    LinkOff,
-    LinkedCamera,
-    LinkedServices,
-    Liquor,
-    List,
-    ListAlt,
-    ListAltAdd,
-    ListAltCheck,
-    Lists,
-    LiveHelp,
-    LiveTv,
-    Living,
-    LocalActivity,
-    LocalAirport,
-    LocalAtm,
-    LocalBar,
-    LocalCafe,
-    LocalCarWash,
-    LocalConvenienceStore,
-    LocalDining,
-    LocalDrink,
-    LocalFireDepartment,
-    LocalFlorist,
-    LocalGasStation,
-    LocalGroceryStore,
-    LocalHospital,
-    LocalHotel,
-    LocalLaundryService,
-    LocalLibrary,
-    LocalMall,
-    LocalMovies,
-    LocalOffer,
-    LocalParking,
-    LocalPharmacy,
-    LocalPhone,
-    LocalPizza,
-    LocalPlay,
-    LocalPolice,
-    LocalPostOffice,
-    LocalPrintshop,
-    LocalSee,
-    LocalShipping,
-    LocalTaxi,
-    LocationAutomation,
-    LocationAway,
-    LocationChip,
-    LocationCity,
-    LocationDisabled,
-    LocationHome,
-    LocationOff,
-    LocationOn,
-    LocationPin,
-    LocationSearching,
-    LocatorTag,
-    Lock,
-    LockClock,
-    LockOpen,
-    LockOpenCircle,
-    LockOpenRight,
-    LockOutline,
-    LockPerson,
-    LockReset,
-    Login,
-    LogoDev,
-    Logout,
-    Looks,
-    LooksOne,
-    LooksTwo,
-    #[strum(serialize = "looks_3")]
-    Looks3,
-    #[strum(serialize = "looks_4")]
-    Looks4,
-    #[strum(serialize = "looks_5")]
-    Looks5,
-    #[strum(serialize = "looks_6")]
-    Looks6,
-    Loop,
-    Loupe,
-    LowDensity,
-    LowPriority,
-    Lowercase,
-    Loyalty,
-    LteMobiledata,
-    LteMobiledataBadge,
-    LtePlusMobiledata,
-    LtePlusMobiledataBadge,
-    Luggage,
-    LunchDining,
-    Lyrics,
-    MacroAuto,
-    MacroOff,
-    MagicButton,
-    MagicExchange,
-    MagicTether,
-    MagnificationLarge,
-    MagnificationSmall,
-    MagnifyDocked,
-    MagnifyFullscreen,
-    Mail,
-    MailAsterisk,
-    MailLock,
-    MailOff,
-    MailOutline,
-    MailShield,
-    Male,
-    Man,
-    Man2,
-    Man3,
-    Man4,
-    ManageAccounts,
-    ManageHistory,
-    ManageSearch,
-    Manga,
-    Manufacturing,
-    Map,
-    MapPinHeart,
-    MapPinReview,
-    MapSearch,
-    MapsHomeWork,
-    MapsUgc,
-    Margin,
-    MarkAsUnread,
-    MarkChatRead,
-    MarkChatUnread,
-    MarkEmailRead,
-    MarkEmailUnread,
-    MarkUnreadChatAlt,
-    Markdown,
-    MarkdownCopy,
-    MarkdownPaste,
-    Markunread,
-    MarkunreadMailbox,
-    MaskedTransitions,
-    MaskedTransitionsAdd,
-    Masks,
-    Massage,
-    MatchCase,
-    MatchCaseOff,
-    MatchWord,
-    Matter,
-    Maximize,
-    MealDinner,
-    MealLunch,
-    MeasuringTape,
-    MediaBluetoothOff,
-    MediaBluetoothOn,
-    MediaLink,
-    MediaOutput,
-    MediaOutputOff,
-    Mediation,
-    MedicalInformation,
-    MedicalMask,
-    MedicalServices,
-    Medication,
-    MedicationLiquid,
-    MeetingRoom,
-    Memory,
-    MemoryAlt,
-    MenstrualHealth,
-    Menu,
-    MenuBook,
-    MenuBook2,
-    MenuOpen,
-    Merge,
-    MergeType,
-    Message,
-    Metabolism,
-    Metro,
-    MfgNestYaleLock,
-    Mic,
-    MicAlert,
-    MicDouble,
-    MicExternalOff,
-    MicExternalOn,
-    MicNone,
-    MicOff,
-    Microbiology,
-    Microwave,
-    MicrowaveGen,
-    MilitaryTech,
-    Mimo,
-    MimoDisconnect,
-    Mindfulness,
-    Minimize,
-    MinorCrash,
-    Mintmark,
-    MissedVideoCall,
-    MissedVideoCallFilled,
-    MissingController,
-    Mist,
-    Mitre,
-    MixtureMed,
-    Mms,
-    Mobile,
-    MobileAlert,
-    MobileArrowDown,
-    MobileArrowRight,
-    MobileArrowUpRight,
-    MobileBlock,
-    MobileCamera,
-    MobileCameraFront,
-    MobileCameraRear,
-    MobileCancel,
-    MobileCast,
-    MobileCharge,
-    MobileChat,
-    MobileCheck,
-    MobileCode,
-    MobileDots,
-    MobileFriendly,
-    MobileGear,
-    MobileHand,
-    MobileHandLeft,
-    MobileHandLeftOff,
-    MobileHandOff,
-    MobileInfo,
-    MobileLandscape,
-    MobileLayout,
-    MobileLockLandscape,
-    MobileLockPortrait,
-    MobileLoupe,
-    MobileMenu,
-    MobileOff,
-    MobileQuestion,
-    MobileRotate,
-    MobileRotateLock,
-    MobileScreenShare,
-    MobileScreensaver,
-    MobileSensorHi,
-    MobileSensorLo,
-    MobileShare,
-    MobileShareStack,
-    MobileSound,
-    MobileSoundOff,
-    MobileSound2,
-    MobileSpeaker,
-    MobileText,
-    MobileText2,
-    MobileTheft,
-    MobileTicket,
-    MobileVibrate,
-    MobileWrench,
-    Mobile2,
-    Mobile3,
-    Mobiledata3g,
-    Mobiledata4g,
-    Mobiledata4gPlus,
-    MobiledataOff,
-    Mode,
-    ModeComment,
-    ModeCool,
-    ModeCoolOff,
-    ModeDual,
-    ModeEdit,
-    ModeEditOutline,
-    ModeFan,
-    ModeFanOff,
-    ModeHeat,
-    ModeHeatCool,
-    ModeHeatOff,
-    ModeNight,
-    ModeOfTravel,
-    ModeOffOn,
-    ModeStandby,
-    ModelTraining,
-    Modeling,
-    MonetizationOn,
-    Money,
-    MoneyBag,
-    MoneyOff,
-    MoneyOffCsred,
-    MoneyRange,
-    Monitor,
-    MonitorHeart,
-    MonitorWeight,
-    MonitorWeightGain,
-    MonitorWeightLoss,
-    Monitoring,
-    MonochromePhotos,
-    Monorail,
-    Mood,
-    MoodBad,
-    MoonStars,
-    Mop,
-    Moped,
-    MopedPackage,
-    More,
-    MoreDown,
-    MoreHoriz,
-    MoreTime,
-    MoreUp,
-    MoreVert,
-    Mosque,
-    MotionBlur,
-    MotionMode,
-    MotionPhotosAuto,
-    MotionPhotosOff,
-    MotionPhotosOn,
-    MotionPhotosPause,
-    MotionPhotosPaused,
-    MotionPlay,
-    MotionSensorActive,
-    MotionSensorAlert,
-    MotionSensorIdle,
-    MotionSensorUrgent,
-    Motorcycle,
-    MountainFlag,
-    MountainSteam,
-    Mouse,
-    MouseLock,
-    MouseLockOff,
-    Move,
-    MoveDown,
-    MoveGroup,
-    MoveItem,
-    MoveLocation,
-    MoveSelectionDown,
-    MoveSelectionLeft,
-    MoveSelectionRight,
-    MoveSelectionUp,
-    MoveToInbox,
-    MoveUp,
-    MovedLocation,
-    Movie,
-    MovieCreation,
-    MovieEdit,
-    MovieFilter,
-    MovieInfo,
-    MovieOff,
-    MovieSpeaker,
-    Moving,
-    MovingBeds,
-    MovingMinistry,
-    Mp,
-    Multicooker,
-    MultilineChart,
-    MultimodalHandEye,
-    MultipleAirports,
-    MultipleStop,
-    Museum,
-    MusicCast,
-    MusicHistory,
-    MusicNote,
-    MusicNoteAdd,
-    MusicOff,
-    MusicVideo,
-    MyLocation,
-    Mystery,
-    Nat,
-    Nature,
-    NaturePeople,
-    NavigateBefore,
-    NavigateNext,
-    Navigation,
-    NearMe,
-    NearMeDisabled,
-    Nearby,
-    NearbyError,
-    NearbyOff,
-    Nephrology,
-    NestAudio,
-    NestCamFloodlight,
-    NestCamIndoor,
-    NestCamIq,
-    NestCamIqOutdoor,
-    NestCamMagnetMount,
-    NestCamOutdoor,
-    NestCamStand,
-    NestCamWallMount,
-    NestCamWiredStand,
-    NestClockFarsightAnalog,
-    NestClockFarsightDigital,
-    NestConnect,
-    NestDetect,
-    NestDisplay,
-    NestDisplayMax,
-    NestDoorbellVisitor,
-    NestEcoLeaf,
-    NestFarsightCool,
-    NestFarsightDual,
-    NestFarsightEco,
-    NestFarsightHeat,
-    NestFarsightSeasonal,
-    NestFarsightWeather,
-    NestFoundSavings,
-    NestGaleWifi,
-    NestHeatLinkE,
-    NestHeatLinkGen3,
-    NestHelloDoorbell,
-    NestLocatorTag,
-    NestMini,
-    NestMultiRoom,
-    NestProtect,
-    NestRemote,
-    NestRemoteComfortSensor,
-    NestSecureAlarm,
-    NestSunblock,
-    NestTag,
-    NestThermostat,
-    NestThermostatEEu,
-    NestThermostatGen3,
-    NestThermostatSensor,
-    NestThermostatSensorEu,
-    NestThermostatZirconiumEu,
-    NestTrueRadiant,
-    NestWakeOnApproach,
-    NestWakeOnPress,
-    NestWifiGale,
-    NestWifiMistral,
-    NestWifiPoint,
-    NestWifiPointVento,
-    NestWifiPro,
-    NestWifiPro2,
-    NestWifiRouter,
-    NetworkCell,
-    NetworkCheck,
-    NetworkIntelNode,
-    NetworkIntelligence,
-    NetworkIntelligenceHistory,
-    NetworkIntelligenceUpdate,
-    NetworkLocked,
-    NetworkManage,
-    NetworkNode,
-    NetworkPing,
-    NetworkWifi,
-    NetworkWifiLocked,
-    NetworkWifi1Bar,
-    NetworkWifi1BarLocked,
-    NetworkWifi2Bar,
-    NetworkWifi2BarLocked,
-    NetworkWifi3Bar,
-    NetworkWifi3BarLocked,
-    Neurology,
-    NewLabel,
-    NewReleases,
-    NewWindow,
-    News,
-    Newsmode,
-    Newspaper,
-    Newsstand,
-    NextPlan,
-    NextWeek,
-    Nfc,
-    NfcOff,
-    NightShelter,
-    NightSightAuto,
-    NightSightAutoOff,
-    NightSightMax,
-    Nightlife,
-    Nightlight,
-    NightlightRound,
-    NightsStay,
-    NineK,
-    NineKPlus,
-    NineMp,
-    NineteenMp,
-    NoAccounts,
-    NoAdultContent,
-    NoBackpack,
-    NoCrash,
-    NoDrinks,
-    NoEncryption,
-    NoEncryptionGmailerrorred,
-    NoFlash,
-    NoFood,
-    NoLuggage,
-    NoMeals,
-    NoMeetingRoom,
-    NoPhotography,
-    NoSim,
-    NoSound,
-    NoStroller,
-    NoTransfer,
-    NoiseAware,
-    NoiseControlOff,
-    NoiseControlOn,
-    NordicWalking,
-    North,
-    NorthEast,
-    NorthWest,
-    NotAccessible,
-    NotAccessibleForward,
-    NotInterested,
-    NotListedLocation,
-    NotStarted,
-    Note,
-    NoteAdd,
-    NoteAlt,
-    NoteStack,
-    NoteStackAdd,
-    Notes,
-    NotificationAdd,
-    NotificationImportant,
-    NotificationMultiple,
-    NotificationSettings,
-    NotificationSound,
-    Notifications,
-    NotificationsActive,
-    NotificationsNone,
-    NotificationsOff,
-    NotificationsPaused,
-    NotificationsUnread,
-    Numbers,
-    Nutrition,
-    Ods,
-    Odt,
-    OfflineBolt,
-    OfflinePin,
-    OfflinePinOff,
-    OfflineShare,
-    OilBarrel,
-    Okonomiyaki,
-    OnDeviceTraining,
-    OnHubDevice,
-    Oncology,
-    OndemandVideo,
-    OneK,
-    OneKPlus,
-    OneTwoThree,
-    OneXMobiledata,
-    OnlinePrediction,
-    Onsen,
-    Opacity,
-    OpenInBrowser,
-    OpenInFull,
-    OpenInNew,
-    OpenInNewDown,
-    OpenInNewOff,
-    OpenInPhone,
-    OpenJam,
-    OpenRun,
-    OpenWith,
-    Ophthalmology,
-    OralDisease,
-    Orbit,
-    OrderApprove,
-    OrderPlay,
-    Orders,
-    Orthopedics,
-    OtherAdmission,
-    OtherHouses,
-    Outbound,
-    Outbox,
-    OutboxAlt,
-    OutdoorGarden,
-    OutdoorGrill,
-    OutgoingMail,
-    Outlet,
-    OutlinedFlag,
-    Outpatient,
-    OutpatientMed,
-    Output,
-    OutputCircle,
-    Oven,
-    OvenGen,
-    Overview,
-    OverviewKey,
-    Owl,
-    OxygenSaturation,
-    P2P,
-    Pace,
-    Pacemaker,
-    Package,
-    Package2,
-    Padding,
-    Padel,
-    PageControl,
-    PageFooter,
-    PageHeader,
-    PageInfo,
-    PageMenuIos,
-    Pageless,
-    Pages,
-    Pageview,
-    Paid,
-    Palette,
-    Pallet,
-    PanTool,
-    PanToolAlt,
-    PanZoom,
-    Panorama,
-    PanoramaFishEye,
-    PanoramaHorizontal,
-    PanoramaPhotosphere,
-    PanoramaVertical,
-    PanoramaWideAngle,
-    Paragliding,
-    ParentChildDining,
-    Park,
-    ParkingMeter,
-    ParkingSign,
-    ParkingValet,
-    PartlyCloudyDay,
-    PartlyCloudyNight,
-    PartnerExchange,
-    PartnerHeart,
-    PartnerReports,
-    PartyMode,
-    Passkey,
-    Password,
-    Password2,
-    Password2Off,
-    PatientList,
-    Pattern,
-    Pause,
-    PauseCircle,
-    PauseCircleFilled,
-    PauseCircleOutline,
-    PausePresentation,
-    Payment,
-    PaymentArrowDown,
-    PaymentCard,
-    Payments,
-    PedalBike,
-    Pediatrics,
-    PenSize1,
-    PenSize2,
-    PenSize3,
-    PenSize4,
-    PenSize5,
-    Pending,
-    PendingActions,
-    Pentagon,
-    People,
-    PeopleAlt,
-    PeopleOutline,
-    Percent,
-    PercentDiscount,
-    PerformanceMax,
-    Pergola,
-    PermCameraMic,
-    PermContactCalendar,
-    PermDataSetting,
-    PermDeviceInformation,
-    PermIdentity,
-    PermMedia,
-    PermPhoneMsg,
-    PermScanWifi,
-    Person,
-    PersonAdd,
-    PersonAddAlt,
-    PersonAddDisabled,
-    PersonAlert,
-    PersonApron,
-    PersonBook,
-    PersonCancel,
-    PersonCelebrate,
-    PersonCheck,
-    PersonEdit,
-    PersonFilled,
-    PersonHeart,
-    PersonOff,
-    PersonOutline,
-    PersonPin,
-    PersonPinCircle,
-    PersonPlay,
-    PersonRaisedHand,
-    PersonRemove,
-    PersonSearch,
-    PersonShield,
-    Person2,
-    Person3,
-    Person4,
-    PersonalBag,
-    PersonalBagOff,
-    PersonalBagQuestion,
-    PersonalInjury,
-    PersonalPlaces,
-    PersonalVideo,
-    PestControl,
-    PestControlRodent,
-    PetSupplies,
-    Pets,
-    Phishing,
-    Phone,
-    PhoneAlt,
-    PhoneAndroid,
-    PhoneBluetoothSpeaker,
-    PhoneCallback,
-    PhoneDisabled,
-    PhoneEnabled,
-    PhoneForwarded,
-    PhoneInTalk,
-    PhoneIphone,
-    PhoneLocked,
-    PhoneMissed,
-    PhonePaused,
-    Phonelink,
-    PhonelinkErase,
-    PhonelinkLock,
-    PhonelinkOff,
-    PhonelinkRing,
-    PhonelinkRingOff,
-    PhonelinkSetup,
-    Photo,
-    PhotoAlbum,
-    PhotoAutoMerge,
-    PhotoCamera,
-    PhotoCameraBack,
-    PhotoCameraFront,
-    PhotoFilter,
-    PhotoFrame,
-    PhotoLibrary,
-    PhotoPrints,
-    PhotoSizeSelectActual,
-    PhotoSizeSelectLarge,
-    PhotoSizeSelectSmall,
-    Php,
-    PhysicalTherapy,
-    Piano,
-    PianoOff,
-    Pickleball,
-    PictureAsPdf,
-    PictureInPicture,
-    PictureInPictureAlt,
-    PictureInPictureCenter,
-    PictureInPictureLarge,
-    PictureInPictureMedium,
-    PictureInPictureMobile,
-    PictureInPictureOff,
-    PictureInPictureSmall,
-    PieChart,
-    PieChartFilled,
-    PieChartOutline,
-    PieChartOutlined,
-    Pill,
-    PillOff,
-    Pin,
-    PinDrop,
-    PinEnd,
-    PinInvoke,
-    Pinboard,
-    PinboardUnread,
-    Pinch,
-    PinchZoomIn,
-    PinchZoomOut,
-    Pip,
-    PipExit,
-    PivotTableChart,
-    Place,
-    PlaceItem,
-    Plagiarism,
-    PlaneContrails,
-    Planet,
-    PlannerBannerAdPt,
-    PlannerReview,
-    PlayArrow,
-    PlayCircle,
-    PlayDisabled,
-    PlayForWork,
-    PlayLesson,
-    PlayMusic,
-    PlayPause,
-    PlayShapes,
-    Playground,
-    Playground2,
-    PlayingCards,
-    PlaylistAdd,
-    PlaylistAddCheck,
-    PlaylistAddCheckCircle,
-    PlaylistAddCircle,
-    PlaylistPlay,
-    PlaylistRemove,
-    PlugConnect,
-    Plumbing,
-    PlusOne,
-    Podcasts,
-    Podiatry,
-    Podium,
-    PointOfSale,
-    PointScan,
-    PokerChip,
-    Policy,
-    PolicyAlert,
-    Poll,
-    Polyline,
-    Polymer,
-    Pool,
-    PortableWifiOff,
-    Portrait,
-    PositionBottomLeft,
-    PositionBottomRight,
-    PositionTopRight,
-    Post,
-    PostAdd,
-    PottedPlant,
-    Power,
-    PowerInput,
-    PowerOff,
-    PowerRounded,
-    PowerSettingsCircle,
-    PowerSettingsNew,
-    PrayerTimes,
-    PrecisionManufacturing,
-    Pregnancy,
-    PregnantWoman,
-    Preliminary,
-    Prescriptions,
-    PresentToAll,
-    Preview,
-    PreviewOff,
-    PriceChange,
-    PriceCheck,
-    Print,
-    PrintAdd,
-    PrintConnect,
-    PrintDisabled,
-    PrintError,
-    PrintLock,
-    Priority,
-    PriorityHigh,
-    Privacy,
-    PrivacyTip,
-    PrivateConnectivity,
-    Problem,
-    Procedure,
-    ProcessChart,
-    ProductionQuantityLimits,
-    Productivity,
-    ProgressActivity,
-    PromptSuggestion,
-    Propane,
-    PropaneTank,
-    Psychiatry,
-    Psychology,
-    PsychologyAlt,
-    Public,
-    PublicOff,
-    Publish,
-    PublishedWithChanges,
-    Pulmonology,
-    PulseAlert,
-    PunchClock,
-    PushPin,
-    QrCode,
-    QrCodeScanner,
-    QrCode2,
-    QrCode2Add,
-    QueryBuilder,
-    QueryStats,
-    QuestionAnswer,
-    QuestionExchange,
-    QuestionMark,
-    Queue,
-    QueueMusic,
-    QueuePlayNext,
-    QuickPhrases,
-    QuickReference,
-    QuickReferenceAll,
-    QuickReorder,
-    Quickreply,
-    QuietTime,
-    QuietTimeActive,
-    Quiz,
-    RMobiledata,
-    Radar,
-    Radio,
-    RadioButtonChecked,
-    RadioButtonPartial,
-    RadioButtonUnchecked,
-    Radiology,
-    RailwayAlert,
-    RailwayAlert2,
-    Rainy,
-    RainyHeavy,
-    RainyLight,
-    RainySnow,
-    RamenDining,
-    RampLeft,
-    RampRight,
-    RangeHood,
-    RateReview,
-    RateReviewRtl,
-    Raven,
-    RawOff,
-    RawOn,
-    ReadMore,
-    ReadinessScore,
-    RealEstateAgent,
-    RearCamera,
-    Rebase,
-    RebaseEdit,
-    Receipt,
-    ReceiptLong,
-    ReceiptLongOff,
-    RecentActors,
-    RecentPatient,
-    Recenter,
-    Recommend,
-    RecordVoiceOver,
-    Rectangle,
-    Recycling,
-    Redeem,
-    Redo,
-    ReduceCapacity,
-    Refresh,
-    RegularExpression,
-    Relax,
-    ReleaseAlert,
-    RememberMe,
-    Reminder,
-    RemindersAlt,
-    RemoteGen,
-    Remove,
-    RemoveCircle,
-    RemoveCircleOutline,
-    RemoveDone,
-    RemoveFromQueue,
-    RemoveModerator,
-    RemoveRedEye,
-    RemoveRoad,
-    RemoveSelection,
-    RemoveShoppingCart,
-    ReopenWindow,
-    Reorder,
-    Repartition,
-    Repeat,
-    RepeatOn,
-    RepeatOne,
-    RepeatOneOn,
-    ReplaceAudio,
-    ReplaceImage,
-    ReplaceVideo,
-    Replay,
-    ReplayCircleFilled,
-    Replay10,
-    Replay30,
-    Replay5,
-    Reply,
-    ReplyAll,
-    Report,
-    ReportGmailerrorred,
-    ReportOff,
-    ReportProblem,
-    RequestPage,
-    RequestQuote,
-    ResetBrightness,
-    ResetExposure,
-    ResetFocus,
-    ResetImage,
-    ResetIso,
-    ResetSettings,
-    ResetShadow,
-    ResetShutterSpeed,
-    ResetTv,
-    ResetWhiteBalance,
-    ResetWrench,
-    Resize,
-    RespiratoryRate,
-    ResponsiveLayout,
-    RestArea,
-    RestartAlt,
-    Restaurant,
-    RestaurantMenu,
-    Restore,
-    RestoreFromTrash,
-    RestorePage,
-    Resume,
-    Reviews,
-    RewardedAds,
-    Rheumatology,
-    RibCage,
-    RiceBowl,
-    RightClick,
-    RightPanelClose,
-    RightPanelOpen,
-    RingVolume,
-    RingVolumeFilled,
-    Ripples,
-    Road,
-    Robot,
-    Robot2,
-    Rocket,
-    RocketLaunch,
-    RollerShades,
-    RollerShadesClosed,
-    RollerSkating,
-    Roofing,
-    Room,
-    RoomPreferences,
-    RoomService,
-    RotateAuto,
-    RotateLeft,
-    RotateRight,
-    Rotate90DegreesCcw,
-    Rotate90DegreesCw,
-    Rotation3d,
-    RoundaboutLeft,
-    RoundaboutRight,
-    RoundedCorner,
-    Route,
-    Router,
-    RouterOff,
-    Routine,
-    Rowing,
-    RssFeed,
-    Rsvp,
-    Rtt,
-    Rubric,
-    Rule,
-    RuleFolder,
-    RuleSettings,
-    RunCircle,
-    RunningWithErrors,
-    RvHookup,
-    SafetyCheck,
-    SafetyCheckOff,
-    SafetyDivider,
-    Sailing,
-    Salinity,
-    Sanitizer,
-    Satellite,
-    SatelliteAlt,
-    Sauna,
-    Save,
-    SaveAlt,
-    SaveAs,
-    SaveClock,
-    SavedSearch,
-    Savings,
-    Scale,
-    Scan,
-    ScanDelete,
-    Scanner,
-    ScatterPlot,
-    Scene,
-    Schedule,
-    ScheduleSend,
-    Schema,
-    School,
-    Science,
-    ScienceOff,
-    Scooter,
-    Score,
-    Scoreboard,
-    ScreenLockLandscape,
-    ScreenLockPortrait,
-    ScreenLockRotation,
-    ScreenRecord,
-    ScreenRotation,
-    ScreenRotationAlt,
-    ScreenRotationUp,
-    ScreenSearchDesktop,
-    ScreenShare,
-    Screenshot,
-    ScreenshotFrame,
-    ScreenshotFrame2,
-    ScreenshotKeyboard,
-    ScreenshotMonitor,
-    ScreenshotRegion,
-    ScreenshotTablet,
-    Script,
-    ScrollableHeader,
-    ScubaDiving,
-    Sd,
-    SdCard,
-    SdCardAlert,
-    SdStorage,
-    Sdk,
-    Search,
-    SearchActivity,
-    SearchCheck,
-    SearchCheck2,
-    SearchGear,
-    SearchHandsFree,
-    SearchInsights,
-    SearchOff,
-    SeatCoolLeft,
-    SeatCoolRight,
-    SeatHeatLeft,
-    SeatHeatRight,
-    SeatVentLeft,
-    SeatVentRight,
-    Security,
-    SecurityKey,
-    SecurityUpdate,
-    SecurityUpdateGood,
-    SecurityUpdateWarning,
-    Segment,
-    Select,
-    SelectAll,
-    SelectCheckBox,
-    SelectToSpeak,
-    SelectWindow,
-    SelectWindowOff,
-    SelectWindow2,
-    SelfCare,
-    SelfImprovement,
-    Sell,
-    Send,
-    SendAndArchive,
-    SendMoney,
-    SendTimeExtension,
-    SendToMobile,
-    SensorDoor,
-    SensorOccupied,
-    SensorWindow,
-    Sensors,
-    SensorsKrx,
-    SensorsKrxOff,
-    SensorsOff,
-    SentimentCalm,
-    SentimentContent,
-    SentimentDissatisfied,
-    SentimentExcited,
-    SentimentExtremelyDissatisfied,
-    SentimentFrustrated,
-    SentimentNeutral,
-    SentimentSad,
-    SentimentSatisfied,
-    SentimentSatisfiedAlt,
-    SentimentStressed,
-    SentimentVeryDissatisfied,
-    SentimentVerySatisfied,
-    SentimentWorried,
-    Serif,
-    ServerPerson,
-    ServiceToolbox,
-    SetMeal,
-    Settings,
-    SettingsAccessibility,
-    SettingsAccountBox,
-    SettingsAlert,
-    SettingsApplications,
-    SettingsBRoll,
-    SettingsBackupRestore,
-    SettingsBluetooth,
-    SettingsBrightness,
-    SettingsCell,
-    SettingsCinematicBlur,
-    SettingsEthernet,
-    SettingsHeart,
-    SettingsInputAntenna,
-    SettingsInputComponent,
-    SettingsInputComposite,
-    SettingsInputHdmi,
-    SettingsInputSvideo,
-    SettingsMotionMode,
-    SettingsNightSight,
-    SettingsOverscan,
-    SettingsPanorama,
-    SettingsPhone,
-    SettingsPhotoCamera,
-    SettingsPower,
-    SettingsRemote,
-    SettingsSeating,
-    SettingsSlowMotion,
-    SettingsSuggest,
-    SettingsSystemDaydream,
-    SettingsTimelapse,
-    SettingsVideoCamera,
-    SettingsVoice,
-    SettopComponent,
-    SevenK,
-    SevenKPlus,
-    SevenMp,
-    SeventeenMp,
-    SevereCold,
-    Shadow,
-    ShadowAdd,
-    ShadowMinus,
-    ShapeLine,
-    ShapeRecognition,
-    Shapes,
-    Share,
-    ShareEta,
-    ShareLocation,
-    ShareOff,
-    ShareReviews,
-    ShareWindows,
-    ShavedIce,
-    SheetsRtl,
-    ShelfAutoHide,
-    ShelfPosition,
-    Shelves,
-    Shield,
-    ShieldLock,
-    ShieldLocked,
-    ShieldMoon,
-    ShieldPerson,
-    ShieldQuestion,
-    ShieldToggle,
-    ShieldWatch,
-    ShieldWithHeart,
-    ShieldWithHouse,
-    Shift,
-    ShiftLock,
-    ShiftLockOff,
-    Shop,
-    ShopTwo,
-    Shop2,
-    ShoppingBag,
-    ShoppingBagSpeed,
-    ShoppingBasket,
-    ShoppingCart,
-    ShoppingCartCheckout,
-    ShoppingCartOff,
-    Shoppingmode,
-    ShortStay,
-    ShortText,
-    Shortcut,
-    ShowChart,
-    Shower,
-    Shuffle,
-    ShuffleOn,
-    ShutterSpeed,
-    ShutterSpeedAdd,
-    ShutterSpeedMinus,
-    Sick,
-    SideNavigation,
-    SignLanguage,
-    SignLanguage2,
-    SignalCellularAdd,
-    SignalCellularAlt,
-    SignalCellularAlt1Bar,
-    SignalCellularAlt2Bar,
-    SignalCellularConnectedNoInternet0Bar,
-    SignalCellularConnectedNoInternet4Bar,
-    SignalCellularNoSim,
-    SignalCellularNodata,
-    SignalCellularNull,
-    SignalCellularOff,
-    SignalCellularPause,
-    SignalCellular0Bar,
-    SignalCellular1Bar,
-    SignalCellular2Bar,
-    SignalCellular3Bar,
-    SignalCellular4Bar,
-    SignalDisconnected,
-    SignalWifiBad,
-    SignalWifiConnectedNoInternet4,
-    SignalWifiOff,
-    SignalWifiStatusbarNotConnected,
-    SignalWifiStatusbarNull,
-    SignalWifiStatusbar4Bar,
-    SignalWifi0Bar,
-    SignalWifi4Bar,
-    SignalWifi4BarLock,
-    Signature,
-    Signpost,
-    SimCard,
-    SimCardAlert,
-    SimCardDownload,
-    Simulation,
-    SingleBed,
-    Sip,
-    Siren,
-    SirenCheck,
-    SirenOpen,
-    SirenQuestion,
-    SixFtApart,
-    SixK,
-    SixKPlus,
-    SixMp,
-    SixteenMp,
-    SixtyFps,
-    SixtyFpsSelect,
-    Skateboarding,
-    Skeleton,
-    Skillet,
-    SkilletCooktop,
-    SkipNext,
-    SkipPrevious,
-    Skull,
-    SkullList,
-    SlabSerif,
-    Sledding,
-    Sleep,
-    SleepScore,
-    SlideLibrary,
-    Sliders,
-    Slideshow,
-    SlowMotionVideo,
-    SmartButton,
-    SmartCardReader,
-    SmartCardReaderOff,
-    SmartDisplay,
-    SmartOutlet,
-    SmartScreen,
-    SmartToy,
-    Smartphone,
-    SmartphoneCamera,
-    SmbShare,
-    SmokeFree,
-    SmokingRooms,
-    Sms,
-    SmsFailed,
-    SnippetFolder,
-    Snooze,
-    Snowboarding,
-    Snowing,
-    SnowingHeavy,
-    Snowmobile,
-    Snowshoeing,
-    Soap,
-    Soba,
-    SocialDistance,
-    SocialLeaderboard,
-    SolarPower,
-    SoloDining,
-    Sort,
-    SortByAlpha,
-    Sos,
-    SoundDetectionDogBarking,
-    SoundDetectionGlassBreak,
-    SoundDetectionLoudSound,
-    SoundSampler,
-    SoupKitchen,
-    Source,
-    SourceEnvironment,
-    SourceNotes,
-    South,
-    SouthAmerica,
-    SouthEast,
-    SouthWest,
-    Spa,
-    SpaceBar,
-    SpaceDashboard,
-    SpatialAudio,
-    SpatialAudioOff,
-    SpatialSpeaker,
-    SpatialTracking,
-    Speaker,
-    SpeakerGroup,
-    SpeakerNotes,
-    SpeakerNotesOff,
-    SpeakerPhone,
-    SpecialCharacter,
-    SpecificGravity,
-    SpeechToText,
-    Speed,
-    SpeedCamera,
-    Speed025,
-    Speed02X,
-    Speed05,
-    Speed05X,
-    Speed075,
-    Speed07X,
-    Speed12,
-    Speed125,
-    Speed12X,
-    Speed15,
-    Speed15X,
-    Speed175,
-    Speed17X,
-    Speed2X,
-    Spellcheck,
-    SplitScene,
-    SplitSceneDown,
-    SplitSceneLeft,
-    SplitSceneRight,
-    SplitSceneUp,
-    Splitscreen,
-    SplitscreenAdd,
-    SplitscreenBottom,
-    SplitscreenLandscape,
-    SplitscreenLeft,
-    SplitscreenPortrait,
-    SplitscreenRight,
-    SplitscreenTop,
-    SplitscreenVerticalAdd,
-    Spo2,
-    Spoke,
-    Sports,
-    SportsAndOutdoors,
-    SportsBar,
-    SportsBaseball,
-    SportsBasketball,
-    SportsCricket,
-    SportsEsports,
-    SportsFootball,
-    SportsGolf,
-    SportsGymnastics,
-    SportsHandball,
-    SportsHockey,
-    SportsKabaddi,
-    SportsMartialArts,
-    SportsMma,
-    SportsMotorsports,
-    SportsRugby,
-    SportsScore,
-    SportsSoccer,
-    SportsTennis,
-    SportsVolleyball,
-    Sprinkler,
-    Sprint,
-    Square,
-    SquareDot,
-    SquareFoot,
-    SsidChart,
-    Stack,
-    StackGroup,
-    StackHexagon,
-    StackOff,
-    StackStar,
-    StackedBarChart,
-    StackedEmail,
-    StackedInbox,
-    StackedLineChart,
-    Stacks,
-    StadiaController,
-    Stadium,
-    Stairs,
-    Stairs2,
-    Star,
-    StarBorder,
-    StarBorderPurple500,
-    StarHalf,
-    StarOutline,
-    StarPurple500,
-    StarRate,
-    StarRateHalf,
-    StarShine,
-    Stars,
-    Stars2,
-    Start,
-    StatMinus1,
-    StatMinus2,
-    StatMinus3,
-    Stat0,
-    Stat1,
-    Stat2,
-    Stat3,
-    StayCurrentLandscape,
-    StayCurrentPortrait,
-    StayPrimaryLandscape,
-    StayPrimaryPortrait,
-    SteeringWheelHeat,
-    Step,
-    StepInto,
-    StepOut,
-    StepOver,
-    Steppers,
-    Steps,
-    Stethoscope,
-    StethoscopeArrow,
-    StethoscopeCheck,
-    StickyNote,
-    StickyNote2,
-    StockMedia,
-    Stockpot,
-    Stop,
-    StopCircle,
-    StopScreenShare,
-    Storage,
-    Store,
-    StoreMallDirectory,
-    Storefront,
-    Storm,
-    Straight,
-    Straighten,
-    Strategy,
-    Stream,
-    StreamApps,
-    Streetview,
-    StressManagement,
-    StrikethroughS,
-    StrokeFull,
-    StrokePartial,
-    Stroller,
-    Style,
-    Styler,
-    Stylus,
-    StylusBrush,
-    StylusFountainPen,
-    StylusHighlighter,
-    StylusLaserPointer,
-    StylusNote,
-    StylusPen,
-    StylusPencil,
-    SubdirectoryArrowLeft,
-    SubdirectoryArrowRight,
-    Subheader,
-    Subject,
-    Subscript,
-    Subscriptions,
-    Subtitles,
-    SubtitlesGear,
-    SubtitlesOff,
-    Subway,
-    SubwayWalk,
-    Summarize,
-    Sunny,
-    SunnySnowing,
-    Superscript,
-    SupervisedUserCircle,
-    SupervisedUserCircleOff,
-    SupervisorAccount,
-    Support,
-    SupportAgent,
-    Surfing,
-    Surgical,
-    SurroundSound,
-    SwapCalls,
-    SwapDrivingApps,
-    SwapDrivingAppsWheel,
-    SwapHoriz,
-    SwapHorizontalCircle,
-    SwapVert,
-    SwapVerticalCircle,
-    Sweep,
-    Swipe,
-    SwipeDown,
-    SwipeDownAlt,
-    SwipeLeft,
-    SwipeLeftAlt,
-    SwipeRight,
-    SwipeRightAlt,
-    SwipeUp,
-    SwipeUpAlt,
-    SwipeVertical,
-    Switch,
-    SwitchAccess,
-    SwitchAccessShortcut,
-    SwitchAccessShortcutAdd,
-    SwitchAccess2,
-    SwitchAccess3,
-    SwitchAccount,
-    SwitchCamera,
-    SwitchLeft,
-    SwitchRight,
-    SwitchVideo,
-    Switches,
-    SwordRose,
-    Swords,
-    Symptoms,
-    Synagogue,
-    Sync,
-    SyncAlt,
-    SyncArrowDown,
-    SyncArrowUp,
-    SyncDesktop,
-    SyncDisabled,
-    SyncLock,
-    SyncProblem,
-    SyncSavedLocally,
-    SyncSavedLocallyOff,
-    Syringe,
-    SystemSecurityUpdate,
-    SystemSecurityUpdateGood,
-    SystemSecurityUpdateWarning,
-    SystemUpdate,
-    SystemUpdateAlt,
-    Tab,
-    TabClose,
-    TabCloseInactive,
-    TabCloseRight,
-    TabDuplicate,
-    TabGroup,
-    TabInactive,
-    TabMove,
-    TabNewRight,
-    TabRecent,
-    TabSearch,
-    TabUnselected,
-    Table,
-    TableBar,
-    TableChart,
-    TableChartView,
-    TableConvert,
-    TableEdit,
-    TableEye,
-    TableLamp,
-    TableLarge,
-    TableRestaurant,
-    TableRows,
-    TableRowsNarrow,
-    TableSign,
-    TableView,
-    Tablet,
-    TabletAndroid,
-    TabletCamera,
-    TabletMac,
-    Tabs,
-    Tactic,
-    Tag,
-    TagFaces,
-    TakeoutDining,
-    TakeoutDining2,
-    TamperDetectionOff,
-    TamperDetectionOn,
-    TapAndPlay,
-    Tapas,
-    Target,
-    Task,
-    TaskAlt,
-    TatamiSeat,
-    Taunt,
-    TaxiAlert,
-    TeamDashboard,
-    TempPreferencesCustom,
-    TempPreferencesEco,
-    TempleBuddhist,
-    TempleHindu,
-    TenK,
-    TenMp,
-    Tenancy,
-    Terminal,
-    Terrain,
-    TextAd,
-    TextCompare,
-    TextDecrease,
-    TextFields,
-    TextFieldsAlt,
-    TextFormat,
-    TextIncrease,
-    TextRotateUp,
-    TextRotateVertical,
-    TextRotationAngledown,
-    TextRotationAngleup,
-    TextRotationDown,
-    TextRotationNone,
-    TextSelectEnd,
-    TextSelectJumpToBeginning,
-    TextSelectJumpToEnd,
-    TextSelectMoveBackCharacter,
-    TextSelectMoveBackWord,
-    TextSelectMoveDown,
-    TextSelectMoveForwardCharacter,
-    TextSelectMoveForwardWord,
-    TextSelectMoveUp,
-    TextSelectStart,
-    TextSnippet,
-    TextToSpeech,
-    TextUp,
-    Textsms,
-    Texture,
-    TextureAdd,
-    TextureMinus,
-    TheaterComedy,
-    Theaters,
-    Thermometer,
-    ThermometerAdd,
-    ThermometerGain,
-    ThermometerLoss,
-    ThermometerMinus,
-    Thermostat,
-    ThermostatArrowDown,
-    ThermostatArrowUp,
-    ThermostatAuto,
-    ThermostatCarbon,
-    ThingsToDo,
-    ThirteenMp,
-    ThirtyFps,
-    ThirtyFpsSelect,
-    ThreadUnread,
-    ThreatIntelligence,
-    ThreeK,
-    ThreeKPlus,
-    ThreeMp,
-    ThreeP,
-    ThreeSixty,
-    ThumbDown,
-    ThumbDownAlt,
-    ThumbDownFilled,
-    ThumbDownOff,
-    ThumbDownOffAlt,
-    ThumbUp,
-    ThumbUpAlt,
-    ThumbUpFilled,
-    ThumbUpOff,
-    ThumbUpOffAlt,
-    ThumbnailBar,
-    ThumbsUpDouble,
-    ThumbsUpDown,
-    Thunderstorm,
-    Tibia,
-    TibiaAlt,
-    TileLarge,
-    TileMedium,
-    TileSmall,
-    TimeAuto,
-    TimeToLeave,
-    Timelapse,
-    Timeline,
-    Timer,
-    TimerArrowDown,
-    TimerArrowUp,
-    TimerOff,
-    TimerPause,
-    TimerPlay,
-    Timer1,
-    Timer10,
-    Timer10Alt1,
-    Timer10Select,
-    Timer2,
-    Timer3,
-    Timer3Alt1,
-    Timer3Select,
-    Timer5,
-    Timer5Shutter,
-    TipsAndUpdates,
-    TireRepair,
-    Title,
-    Titlecase,
-    Toast,
-    Toc,
-    Today,
-    ToggleOff,
-    ToggleOn,
-    Token,
-    Toll,
-    Tonality,
-    Tonality2,
-    Toolbar,
-    ToolsFlatHead,
-    ToolsInstallationKit,
-    ToolsLadder,
-    ToolsLevel,
-    ToolsPhillips,
-    ToolsPliersWireStripper,
-    ToolsPowerDrill,
-    ToolsWrench,
-    Tooltip,
-    Tooltip2,
-    TopPanelClose,
-    TopPanelOpen,
-    Topic,
-    Tornado,
-    TotalDissolvedSolids,
-    TouchApp,
-    TouchDouble,
-    TouchLong,
-    TouchTriple,
-    TouchpadMouse,
-    TouchpadMouseOff,
-    Tour,
-    Toys,
-    ToysAndGames,
-    ToysFan,
-    TrackChanges,
-    TrackpadInput,
-    TrackpadInput2,
-    TrackpadInput3,
-    Traffic,
-    TrafficJam,
-    TrailLength,
-    TrailLengthMedium,
-    TrailLengthShort,
-    Train,
-    Tram,
-    Transcribe,
-    TransferWithinAStation,
-    Transform,
-    Transgender,
-    TransitEnterexit,
-    TransitTicket,
-    TransitionChop,
-    TransitionDissolve,
-    TransitionFade,
-    TransitionPush,
-    TransitionSlide,
-    Translate,
-    TranslateIndic,
-    Transportation,
-    Travel,
-    TravelExplore,
-    TravelLuggageAndBags,
-    TrendingDown,
-    TrendingFlat,
-    TrendingUp,
-    Trip,
-    TripOrigin,
-    Trolley,
-    TrolleyCableCar,
-    Trophy,
-    Troubleshoot,
-    Try,
-    Tsunami,
-    Tsv,
-    Tty,
-    Tune,
-    Tungsten,
-    TurnLeft,
-    TurnRight,
-    TurnSharpLeft,
-    TurnSharpRight,
-    TurnSlightLeft,
-    TurnSlightRight,
-    TurnedIn,
-    TurnedInNot,
-    Tv,
-    TvDisplays,
-    TvGen,
-    TvGuide,
-    TvNext,
-    TvOff,
-    TvOptionsEditChannels,
-    TvOptionsInputSettings,
-    TvRemote,
-    TvSignin,
-    TvWithAssistant,
-    TwelveMp,
-    TwentyFourMp,
-    TwentyMp,
-    TwentyOneMp,
-    TwentyThreeMp,
-    TwentyTwoMp,
-    TwoK,
-    TwoKPlus,
-    TwoMp,
-    TwoPager,
-    TwoPagerStore,
-    TwoWheeler,
-    TypeSpecimen,
-    UTurnLeft,
-    UTurnRight,
-    Udon,
-    UlnaRadius,
-    UlnaRadiusAlt,
-    Umbrella,
-    Unarchive,
-    Undo,
-    UnfoldLess,
-    UnfoldLessDouble,
-    UnfoldMore,
-    UnfoldMoreDouble,
-    Ungroup,
-    UniversalCurrency,
-    UniversalCurrencyAlt,
-    UniversalLocal,
-    UnknownDocument,
-    UnknownMed,
-    Unknown2,
-    Unknown5,
-    Unknown7,
-    Unlicense,
-    UnpavedRoad,
-    Unpin,
-    Unpublished,
-    Unsubscribe,
-    Upcoming,
-    Update,
-    UpdateDisabled,
-    Upgrade,
-    UpiPay,
-    Upload,
-    UploadFile,
-    Upload2,
-    Uppercase,
-    Urology,
-    Usb,
-    UsbOff,
-    UserAttributes,
-    Vaccines,
-    Vacuum,
-    Valve,
-    VapeFree,
-    VapingRooms,
-    VariableAdd,
-    VariableInsert,
-    VariableRemove,
-    Variables,
-    Ventilator,
-    Verified,
-    VerifiedOff,
-    VerifiedUser,
-    VerticalAlignBottom,
-    VerticalAlignCenter,
-    VerticalAlignTop,
-    VerticalDistribute,
-    VerticalShades,
-    VerticalShadesClosed,
-    VerticalSplit,
-    Vibration,
-    VideoCall,
-    VideoCameraBack,
-    VideoCameraBackAdd,
-    VideoCameraFront,
-    VideoCameraFrontOff,
-    VideoChat,
-    VideoFile,
-    VideoLabel,
-    VideoLibrary,
-    VideoSearch,
-    VideoSettings,
-    VideoStable,
-    Videocam,
-    VideocamAlert,
-    VideocamOff,
-    VideogameAsset,
-    VideogameAssetOff,
-    ViewAgenda,
-    ViewApps,
-    ViewArray,
-    ViewCarousel,
-    ViewColumn,
-    ViewColumn2,
-    ViewComfy,
-    ViewComfyAlt,
-    ViewCompact,
-    ViewCompactAlt,
-    ViewCozy,
-    ViewDay,
-    ViewHeadline,
-    ViewInAr,
-    ViewInArNew,
-    ViewInArOff,
-    ViewKanban,
-    ViewList,
-    ViewModule,
-    ViewObjectTrack,
-    ViewQuilt,
-    ViewRealSize,
-    ViewSidebar,
-    ViewStream,
-    ViewTimeline,
-    ViewWeek,
-    Vignette,
-    Vignette2,
-    Villa,
-    Visibility,
-    VisibilityLock,
-    VisibilityOff,
-    VitalSigns,
-    Vitals,
-    Vo2Max,
-    VoiceChat,
-    VoiceOverOff,
-    VoiceSelection,
-    VoiceSelectionOff,
-    Voicemail,
-    Voicemail2,
-    Volcano,
-    VolumeDown,
-    VolumeDownAlt,
-    VolumeMute,
-    VolumeOff,
-    VolumeUp,
-    VolunteerActivism,
-    VotingChip,
-    VpnKey,
-    VpnKeyAlert,
-    VpnKeyOff,
-    VpnLock,
-    VpnLock2,
-    Vr180Create2D,
-    Vr180Create2DOff,
-    Vrpano,
-    WallArt,
-    WallLamp,
-    Wallet,
-    Wallpaper,
-    WallpaperSlideshow,
-    WandShine,
-    WandStars,
-    Ward,
-    Warehouse,
-    Warning,
-    WarningAmber,
-    WarningOff,
-    Wash,
-    Washoku,
-    Watch,
-    WatchArrow,
-    WatchButtonPress,
-    WatchCheck,
-    WatchLater,
-    WatchOff,
-    WatchScreentime,
-    WatchVibration,
-    WatchWake,
-    Water,
-    WaterBottle,
-    WaterBottleLarge,
-    WaterDamage,
-    WaterDo,
-    WaterDrop,
-    WaterEc,
-    WaterFull,
-    WaterHeater,
-    WaterLock,
-    WaterLoss,
-    WaterLux,
-    WaterMedium,
-    WaterOrp,
-    WaterPh,
-    WaterPump,
-    WaterVoc,
-    WaterfallChart,
-    Waves,
-    WavingHand,
-    WbAuto,
-    WbCloudy,
-    WbIncandescent,
-    WbIridescent,
-    WbShade,
-    WbSunny,
-    WbTwilight,
-    Wc,
-    WeatherHail,
-    WeatherMix,
-    WeatherSnowy,
-    Web,
-    WebAsset,
-    WebAssetOff,
-    WebStories,
-    WebTraffic,
-    Webhook,
-    Weekend,
-    Weight,
-    West,
-    Whatshot,
-    WheelchairPickup,
-    WhereToVote,
-    WidgetMedium,
-    WidgetSmall,
-    WidgetWidth,
-    Widgets,
-    Width,
-    WidthFull,
-    WidthNormal,
-    WidthWide,
-    Wifi,
-    WifiAdd,
-    WifiCalling,
-    WifiCallingBar1,
-    WifiCallingBar2,
-    WifiCallingBar3,
-    WifiCalling1,
-    WifiCalling2,
-    WifiCalling3,
-    WifiChannel,
-    WifiFind,
-    WifiHome,
-    WifiLock,
-    WifiNotification,
-    WifiOff,
-    WifiPassword,
-    WifiProtectedSetup,
-    WifiProxy,
-    WifiTethering,
-    WifiTetheringError,
-    WifiTetheringOff,
-    Wifi1Bar,
-    Wifi2Bar,
-    WindPower,
-    Window,
-    WindowClosed,
-    WindowOpen,
-    WindowSensor,
-    WindshieldDefrostAuto,
-    WindshieldDefrostFront,
-    WindshieldDefrostRear,
-    WindshieldHeatFront,
-    WineBar,
-    Woman,
-    Woman2,
-    Work,
-    WorkAlert,
-    WorkHistory,
-    WorkOff,
-    WorkOutline,
-    WorkUpdate,
-    Workflow,
-    WorkspacePremium,
-    Workspaces,
-    WorkspacesOutline,
-    WoundsInjuries,
-    WrapText,
-    Wrist,
-    WrongLocation,
-    Wysiwyg,
-    Yakitori,
-    Yard,
-    Yoshoku,
-    YourTrips,
-    YoutubeActivity,
-    YoutubeSearchedFor,
-    ZonePersonAlert,
-    ZonePersonIdle,
-    ZonePersonUrgent,
-    ZoomIn,
-    ZoomInMap,
-    ZoomOut,
-    ZoomOutMap,
+impl std::fmt::Display for CustomIcon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)
+    }
 }
+
+include!(concat!(env!("OUT_DIR"), "/dioxus-tw-components-icons.rs"));
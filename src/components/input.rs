@@ -1,4 +1,8 @@
+use crate::components::{icon::*, variants::*};
+use dioxus::dioxus_core::AttributeValue;
+use dioxus::html::{FileData, FormValue, HasFileData, HasFormData};
 use dioxus::prelude::*;
+use std::rc::Rc;
 
 #[derive(Default, Clone, PartialEq, Props)]
 pub struct InputProps {
@@ -8,28 +12,212 @@ pub struct InputProps {
     #[props(default)]
     default_value: String,
 
-    #[props(default)]
-    value: Signal<String>,
+    /// Controls the value, the input keeps its own starting from `default_value` if not given
+    #[props(optional)]
+    value: Option<Signal<String>>,
 
+    /// Called with each edit of the text, and with an empty value when the clear button empties it
     #[props(default)]
     onchange: EventHandler<FormEvent>,
+
+    #[props(optional)]
+    color: Option<Color>,
+    #[props(optional)]
+    size: Option<Size>,
+
+    /// Rendered inside the field before the text, e.g. an icon or a currency
+    #[props(optional)]
+    prefix: Option<Element>,
+
+    /// Rendered inside the field after the text, e.g. a unit or a button
+    #[props(optional)]
+    suffix: Option<Element>,
+
+    /// Shows a button emptying the field while it holds some text
+    #[props(default)]
+    clearable: bool,
+
+    /// Called once the field is emptied by its clear button, after `onchange`
+    #[props(optional)]
+    onclear: EventHandler<()>,
+
+    /// Shows a button revealing the text of a `password` field
+    #[props(default)]
+    password_toggle: bool,
+
+    /// Counts the text under the field, against its `maxlength` for characters
+    #[props(optional)]
+    counter: Option<Counter>,
 }
 
+/// A text field, rendered bare unless given adornments, a clear button, a password toggle
+/// or a counter.
 #[component]
 pub fn Input(mut props: InputProps) -> Element {
     let default_classes = "input";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-size",
+        props.size.map(|size| size.as_str()),
+    );
+
+    let mut value = crate::use_controlled(props.value, || props.default_value.clone());
+    let mut revealed = use_signal(|| false);
+    let mut mounted = use_signal(|| None::<Rc<MountedData>>);
 
-    let oninput = move |event: FormEvent| {
-        props.value.set(event.data.value().clone());
-        props.onchange.call(event);
+    let field = crate::use_field_binding(|| value.peek().clone());
+    field.setup_attributes(&mut props.attributes);
+    field.use_controlled_value(props.value.is_some(), move || value.cloned());
+    let current_value = field.value_or(props.value.is_some(), value());
+
+    let onblur = {
+        let field = field.clone();
+        move |_| field.touch()
     };
 
-    rsx! {
+    let oninput = {
+        let field = field.clone();
+        move |event: FormEvent| {
+            value.set(event.data.value());
+            field.set_value(event.data.value());
+            props.onchange.call(event);
+        }
+    };
+
+    let onclear = move |_| {
+        value.set(String::new());
+        field.set_value(String::new());
+        props
+            .onchange
+            .call(Event::new(Rc::new(FormData::new(ClearedInput)), false));
+        props.onclear.call(());
+        if let Some(mounted) = mounted() {
+            spawn(async move {
+                _ = mounted.set_focus(true).await;
+            });
+        }
+    };
+
+    let is_password =
+        crate::attribute_text(&props.attributes, "type").as_deref() == Some("password");
+    let toggles_password = props.password_toggle && is_password;
+    if toggles_password && revealed() {
+        props.attributes.retain(|attr| attr.name != "type");
+        props
+            .attributes
+            .push(Attribute::new("type", "text", None, false));
+    }
+
+    let is_locked = ["disabled", "readonly"].iter().any(|name| {
+        props
+            .attributes
+            .iter()
+            .any(|attr| attr.name == *name && !matches!(attr.value, AttributeValue::Bool(false)))
+    });
+    let max_length = crate::attribute_text(&props.attributes, "maxlength")
+        .and_then(|max_length| max_length.parse().ok());
+    let counter = props
+        .counter
+        .map(|counter| (counter, counter.format(&current_value, max_length)));
+    let is_full = matches!(
+        (props.counter, max_length),
+        (Some(Counter::Characters), Some(max)) if Counter::Characters.count(&current_value) >= max
+    );
+    let shows_clear = props.clearable && !current_value.is_empty() && !is_locked;
+
+    let input = rsx! {
         input {
             oninput,
-            value: props.default_value,
+            onblur,
+            onmounted: move |event: MountedEvent| mounted.set(Some(event.data())),
+            value: current_value,
             ..props.attributes,
         }
+    };
+
+    let is_adorned = props.prefix.is_some()
+        || props.suffix.is_some()
+        || props.clearable
+        || toggles_password
+        || counter.is_some();
+    if !is_adorned {
+        return input;
+    }
+
+    rsx! {
+        div { class: "input-group",
+            div { class: "input-control",
+                if let Some(prefix) = props.prefix {
+                    span { class: "input-prefix", {prefix} }
+                }
+                {input}
+                if shows_clear {
+                    button {
+                        class: "input-action",
+                        r#type: "button",
+                        tabindex: "-1",
+                        aria_label: "Clear",
+                        onclick: onclear,
+                        Icon { icon: Icons::Close }
+                    }
+                }
+                if toggles_password {
+                    button {
+                        class: "input-action",
+                        r#type: "button",
+                        aria_label: if revealed() { "Hide password" } else { "Show password" },
+                        aria_pressed: revealed(),
+                        disabled: is_locked,
+                        onclick: move |_| revealed.toggle(),
+                        Icon { icon: if revealed() { Icons::VisibilityOff } else { Icons::Visibility } }
+                    }
+                }
+                if let Some(suffix) = props.suffix {
+                    span { class: "input-suffix", {suffix} }
+                }
+            }
+            if let Some((counter, text)) = counter {
+                span {
+                    class: "input-counter",
+                    "data-counter": counter.as_str(),
+                    "data-full": is_full,
+                    aria_live: "polite",
+                    "{text}"
+                }
+            }
+        }
+    }
+}
+
+/// The data of the `onchange` event sent by the clear button, which the browser does not report.
+struct ClearedInput;
+
+impl HasFileData for ClearedInput {
+    fn files(&self) -> Vec<FileData> {
+        Vec::new()
+    }
+}
+
+impl HasFormData for ClearedInput {
+    fn value(&self) -> String {
+        String::new()
+    }
+
+    fn valid(&self) -> bool {
+        true
+    }
+
+    fn values(&self) -> Vec<(String, FormValue)> {
+        Vec::new()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
     }
 }
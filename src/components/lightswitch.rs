@@ -6,25 +6,60 @@ use serde::{Deserialize, Serialize};
 /// Application theme mode.
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum ThemeMode {
-    #[default]
     Dark,
     Light,
+    /// Follows the operating system color scheme.
+    #[default]
+    System,
 }
 
 impl ThemeMode {
-    /// Returns the theme mode as a string (e.g., "dark" or "light").
+    /// Returns the theme mode as a string (e.g., "dark", "light" or "system").
     pub const fn as_str(&self) -> &'static str {
         match self {
             ThemeMode::Light => "light",
             ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
         }
     }
 
     /// Toggle between light and dark themes.
+    /// `System` has no opposite and is returned unchanged.
+    #[deprecated(
+        note = "`System`, the default mode, is returned unchanged: use `toggle_resolved` or `cycle`"
+    )]
     pub const fn toggle(self) -> Self {
         match self {
             ThemeMode::Light => ThemeMode::Dark,
             ThemeMode::Dark => ThemeMode::Light,
+            ThemeMode::System => ThemeMode::System,
+        }
+    }
+
+    /// Toggle between light and dark themes, resolving `System` from the system preference
+    /// first, so that the opposite of the theme displayed is returned.
+    pub const fn toggle_resolved(self, system_prefers_dark: bool) -> Self {
+        match self.resolve(system_prefers_dark) {
+            ThemeMode::Dark => ThemeMode::Light,
+            _ => ThemeMode::Dark,
+        }
+    }
+
+    /// Cycle through light, dark and system themes.
+    pub const fn cycle(self) -> Self {
+        match self {
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::System,
+            ThemeMode::System => ThemeMode::Light,
+        }
+    }
+
+    /// Resolves `System` to `Dark` or `Light` depending on the system preference.
+    pub const fn resolve(self, system_prefers_dark: bool) -> Self {
+        match self {
+            ThemeMode::System if system_prefers_dark => ThemeMode::Dark,
+            ThemeMode::System => ThemeMode::Light,
+            mode => mode,
         }
     }
 
@@ -37,9 +72,159 @@ impl ThemeMode {
     pub const fn is_light(&self) -> bool {
         matches!(self, ThemeMode::Light)
     }
+
+    /// Returns true if this theme follows the operating system.
+    pub const fn is_system(&self) -> bool {
+        matches!(self, ThemeMode::System)
+    }
+}
+
+impl std::str::FromStr for ThemeMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dark" => Ok(ThemeMode::Dark),
+            "light" => Ok(ThemeMode::Light),
+            "system" => Ok(ThemeMode::System),
+            _ => Err(()),
+        }
+    }
 }
 
 const STORAGE_KEY: &str = "theme_mode";
+const PREFERS_DARK_COOKIE: &str = "theme_prefers_dark";
+const COOKIE_ATTRIBUTES: &str = "Path=/; Max-Age=31536000; SameSite=Lax";
+
+/// Listens to `prefers-color-scheme` and sends every change back.
+/// On desktop, the webview reports the theme of the window.
+/// The preference is also stored in a cookie for server-side rendering.
+fn prefers_dark_script() -> String {
+    format!(
+        r#"
+    const query = window.matchMedia("(prefers-color-scheme: dark)");
+    const update = (isDark) => {{
+        document.cookie = "{PREFERS_DARK_COOKIE}=" + isDark + "; {COOKIE_ATTRIBUTES}";
+        dioxus.send(isDark);
+    }};
+    update(query.matches);
+    query.addEventListener("change", (event) => update(event.matches));
+    await new Promise(() => {{}});
+"#
+    )
+}
+
+/// Runs inside ThemedRoot before the page is painted, to apply the theme stored in
+/// the `theme_mode` cookie and resolve `system` before hydration.
+fn theme_hint_script() -> String {
+    format!(
+        r#"(function () {{
+    var root = document.currentScript && document.currentScript.parentElement;
+    if (!root) return;
+    var match = document.cookie.match(/(?:^|; ){STORAGE_KEY}=(\w+)/);
+    var mode = match ? match[1] : root.getAttribute("data-theme-mode");
+    var isDark = mode === "dark" || (mode === "system" && window.matchMedia("(prefers-color-scheme: dark)").matches);
+    root.classList.toggle("dark", isDark);
+    root.setAttribute("data-theme", isDark ? "dark" : "light");
+}})();"#
+    )
+}
+
+/// Whether the page is rendered to HTML on the server, or hydrated from such a page.
+/// Scripts of the page only run in this case, client renders skip them.
+fn use_server_rendered() -> bool {
+    let server_rendered = || {
+        !cfg!(any(
+            target_arch = "wasm32",
+            feature = "desktop",
+            feature = "mobile"
+        ))
+    };
+
+    // The hydrating client reads the value sent by the server
+    #[cfg(feature = "fullstack")]
+    return dioxus_fullstack_core::use_server_cached(server_rendered);
+
+    #[cfg(not(feature = "fullstack"))]
+    server_rendered()
+}
+
+/// Theme preferences sent by the browser as cookies.
+/// Used to render the right theme on the server, before the persisted preference is available.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ThemeHint {
+    /// Mode selected with ThemeSwitch, from the `theme_mode` cookie.
+    pub mode: Option<ThemeMode>,
+    /// Operating system preference, from the `theme_prefers_dark` cookie.
+    pub system_prefers_dark: Option<bool>,
+}
+
+impl ThemeHint {
+    /// Parses a `Cookie` request header (e.g. `"theme_mode=dark; theme_prefers_dark=true"`).
+    pub fn from_cookie_header(header: &str) -> Self {
+        let mut hint = Self::default();
+
+        for (name, value) in header
+            .split(';')
+            .filter_map(|cookie| cookie.trim().split_once('='))
+        {
+            match name {
+                STORAGE_KEY => hint.mode = value.parse().ok(),
+                PREFERS_DARK_COOKIE => hint.system_prefers_dark = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        hint
+    }
+
+    /// Reads the hint from the cookies of the request being rendered.
+    /// Returns None on the client, or outside of a request.
+    #[cfg(feature = "server")]
+    pub fn from_request() -> Option<Self> {
+        let context = dioxus_fullstack_core::FullstackContext::current()?;
+        let parts = context.parts_mut();
+        let header = parts.headers.get("cookie")?.to_str().ok()?;
+
+        Some(Self::from_cookie_header(header))
+    }
+
+    #[cfg(not(feature = "server"))]
+    pub fn from_request() -> Option<Self> {
+        None
+    }
+}
+
+impl ThemeMode {
+    /// Returns the `Set-Cookie` value storing this mode, read back by [`ThemeHint`].
+    pub fn to_cookie(&self) -> String {
+        format!("{}={}; {}", STORAGE_KEY, self.as_str(), COOKIE_ATTRIBUTES)
+    }
+}
+
+/// Whether the operating system prefers a dark color scheme, provided by ThemeProvider.
+#[derive(Clone, Copy)]
+struct SystemPrefersDark(Signal<bool>);
+
+/// Hook that tracks whether the operating system prefers a dark color scheme.
+/// Updates live when the setting changes, and stays false where it cannot be
+/// queried (e.g. during server-side rendering).
+pub fn use_system_prefers_dark() -> Signal<bool> {
+    use_system_prefers_dark_or(false)
+}
+
+fn use_system_prefers_dark_or(initial: bool) -> Signal<bool> {
+    let mut prefers_dark = use_signal(|| initial);
+
+    use_future(move || async move {
+        let mut eval = document::eval(&prefers_dark_script());
+        while let Ok(is_dark) = eval.recv::<bool>().await {
+            prefers_dark.set(is_dark);
+        }
+    });
+
+    prefers_dark
+}
 
 /// Hook to access the theme state from context.
 /// Returns the signal containing the current theme.
@@ -56,18 +241,49 @@ pub fn try_use_theme() -> Option<Signal<ThemeMode>> {
     try_use_context::<Signal<ThemeMode>>()
 }
 
+/// Hook returning the theme actually displayed: `System` is resolved to `Dark`
+/// or `Light` from the operating system preference.
+///
+/// # Panics
+/// Panics if no ThemeProvider is present in the component tree.
+pub fn use_resolved_theme() -> ThemeMode {
+    try_use_resolved_theme().expect("ThemeProvider not found")
+}
+
+/// Hook returning the theme actually displayed, or None if no ThemeProvider is present
+/// in the component tree.
+pub fn try_use_resolved_theme() -> Option<ThemeMode> {
+    let theme = try_use_theme();
+    let system = try_use_context::<SystemPrefersDark>();
+
+    let system_prefers_dark = system.is_some_and(|system| (system.0)());
+    theme.map(|theme| theme.read().resolve(system_prefers_dark))
+}
+
 #[derive(Clone, PartialEq, Props)]
 pub struct ThemeProviderProps {
+    /// Theme to render with before the persisted preference is available.
+    /// With the `server` feature, it is read from the request cookies when not set.
+    #[props(optional)]
+    hint: Option<ThemeHint>,
+
     children: Element,
 }
 
 /// Provider component that manages theme state and makes it available via context.
 /// Uses dioxus-sdk-storage for cross-platform persistent storage.
+/// Also tracks the operating system color scheme for `ThemeMode::System`.
+///
+/// During server-side rendering the persisted preference is not available yet, so the
+/// theme is taken from the [`ThemeHint`] cookies written by ThemeSwitch to avoid a flash
+/// of the wrong theme.
 ///
 /// Wrap your app (or the part that needs theming) with this component.
 ///
 /// # Example
 /// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_tw_components::prelude::*;
 /// fn App() -> Element {
 ///     rsx! {
 ///         ThemeProvider {
@@ -85,8 +301,18 @@ pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
     // This works cross-platform: localStorage on web, file storage on desktop/mobile
     let theme = use_persistent(STORAGE_KEY, ThemeMode::default);
 
+    let hint = use_hook(|| {
+        props
+            .hint
+            .or_else(ThemeHint::from_request)
+            .unwrap_or_default()
+    });
+
     // Provide the state via context
-    let state = use_context_provider(|| Signal::new(*theme.read()));
+    let state = use_context_provider(|| Signal::new(hint.mode.unwrap_or(*theme.read())));
+
+    let system_prefers_dark = use_system_prefers_dark_or(hint.system_prefers_dark.unwrap_or(false));
+    use_context_provider(|| SystemPrefersDark(system_prefers_dark));
 
     // Keep context in sync with persistent storage
     use_effect(move || {
@@ -108,10 +334,17 @@ pub struct ThemedRootProps {
 }
 
 /// A wrapper component that applies theme attributes based on the theme context.
-/// Applies the "dark" class and sets `data-theme` attribute.
+/// Applies the "dark" class and sets `data-theme` attribute to the resolved theme
+/// ("dark" or "light"), and `data-theme-mode` to the selected mode.
+///
+/// When rendered on the server, it also emits a small inline script applying the theme
+/// from the cookies before hydration, so that server-rendered pages never flash the wrong
+/// theme. Fullstack clients need the `fullstack` feature to hydrate it.
 ///
 /// # Example
 /// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_tw_components::prelude::*;
 /// fn App() -> Element {
 ///     rsx! {
 ///         ThemeProvider {
@@ -127,8 +360,11 @@ pub struct ThemedRootProps {
 #[component]
 pub fn ThemedRoot(mut props: ThemedRootProps) -> Element {
     let theme = use_context::<Signal<ThemeMode>>();
-    let is_dark = theme.read().is_dark();
-    let theme_str = theme.read().as_str();
+    let resolved = use_resolved_theme();
+    let is_dark = resolved.is_dark();
+    let theme_str = resolved.as_str();
+    let mode_str = theme.read().as_str();
+    let server_rendered = use_server_rendered();
 
     // Add "dark" class if dark mode is enabled
     if is_dark {
@@ -138,7 +374,11 @@ pub fn ThemedRoot(mut props: ThemedRootProps) -> Element {
     rsx! {
         div {
             "data-theme": theme_str,
+            "data-theme-mode": mode_str,
             ..props.attributes,
+            if server_rendered {
+                script { dangerous_inner_html: theme_hint_script() }
+            }
             {props.children}
         }
     }
@@ -156,18 +396,22 @@ pub struct ThemeSwitchProps {
     children: Element,
 }
 
-/// A toggle button that switches between light and dark themes.
+/// A toggle button that cycles between light, dark and system themes.
 /// Must be used within a ThemeProvider.
 ///
 /// Uses dioxus-sdk-storage for cross-platform persistent storage of the theme preference.
 ///
 /// # Example
 /// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_tw_components::prelude::*;
+/// # fn App() -> Element {
 /// rsx! {
 ///     ThemeSwitch {
 ///         class: "my-button-class",
 ///     }
 /// }
+/// # }
 /// ```
 #[component]
 pub fn ThemeSwitch(mut props: ThemeSwitchProps) -> Element {
@@ -180,29 +424,29 @@ pub fn ThemeSwitch(mut props: ThemeSwitchProps) -> Element {
     // Also update the context when we toggle
     let mut theme_context = use_context::<Signal<ThemeMode>>();
 
-    let icon = if theme_storage.read().is_dark() {
-        rsx! {
-            Icon { icon: Icons::DarkMode }
-        }
-    } else {
-        rsx! {
-            Icon { icon: Icons::LightMode }
-        }
+    let mode = *theme_storage.read();
+    let icon = match mode {
+        ThemeMode::Dark => Icons::DarkMode,
+        ThemeMode::Light => Icons::LightMode,
+        ThemeMode::System => Icons::BrightnessAuto,
     };
 
     rsx! {
         button {
             r#type: "button",
+            title: "Theme: {mode.as_str()}",
             onclick: move |e| {
-                let new_value = theme_storage.read().toggle();
+                let new_value = theme_storage.read().cycle();
                 theme_storage.set(new_value);
                 theme_context.set(new_value);
+                // Lets the server render the right theme on the next request
+                document::eval(&format!("document.cookie = {:?};", new_value.to_cookie()));
                 if let Some(ref handler) = props.onclick {
                     handler.call(e);
                 }
             },
             ..props.attributes,
-            {icon}
+            Icon { icon }
         }
     }
 }
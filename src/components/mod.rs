@@ -1,29 +1,71 @@
+#[cfg(feature = "accordion")]
 pub mod accordion;
+#[cfg(feature = "button")]
 pub mod button;
+#[cfg(feature = "buttongroup")]
 pub mod buttongroup;
+#[cfg(feature = "calendar")]
+pub mod calendar;
+#[cfg(feature = "callout")]
 pub mod callout;
+#[cfg(feature = "carousel")]
 pub mod carousel;
+#[cfg(feature = "checkbox")]
 pub mod checkbox;
+#[cfg(feature = "combobox")]
+pub mod combobox;
+#[cfg(feature = "dropdown")]
 pub mod dropdown;
+#[cfg(feature = "dropzone")]
+pub mod dropzone;
+#[cfg(feature = "form")]
+pub mod form;
+#[cfg(feature = "formlist")]
 pub mod formlist;
+#[cfg(feature = "hovercard")]
 pub mod hovercard;
+#[cfg(feature = "icon")]
 pub mod icon;
+#[cfg(feature = "input")]
 pub mod input;
+#[cfg(feature = "lightswitch")]
 pub mod lightswitch;
+#[cfg(feature = "markdown")]
 pub mod markdown;
+#[cfg(feature = "modal")]
 pub mod modal;
+#[cfg(feature = "modelform")]
+pub mod modelform;
+#[cfg(feature = "pagination")]
 pub mod pagination;
+#[cfg(feature = "placeholder")]
 pub mod placeholder;
+#[cfg(feature = "progressbar")]
 pub mod progressbar;
+#[cfg(feature = "radio")]
 pub mod radio;
+#[cfg(feature = "scrollable")]
 pub mod scrollable;
+#[cfg(feature = "select")]
 pub mod select;
+#[cfg(feature = "separator")]
 pub mod separator;
+#[cfg(feature = "sidepanel")]
 pub mod sidepanel;
+#[cfg(feature = "slider")]
 pub mod slider;
+#[cfg(feature = "sorttable")]
 pub mod sorttable;
+#[cfg(feature = "table")]
 pub mod table;
+#[cfg(feature = "tabs")]
 pub mod tabs;
+#[cfg(feature = "textarea")]
 pub mod textarea;
+#[cfg(feature = "toast")]
 pub mod toast;
+#[cfg(feature = "toggle")]
 pub mod toggle;
+#[cfg(feature = "typedinput")]
+pub mod typedinput;
+pub mod variants;
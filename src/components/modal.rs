@@ -1,5 +1,6 @@
 use crate::components::icon::*;
-use crate::dioxus_core::IntoAttributeValue;
+use crate::components::variants::*;
+use dioxus::dioxus_core::IntoAttributeValue;
 use dioxus::prelude::*;
 use dioxus_core::AttributeValue;
 
@@ -136,6 +137,11 @@ pub struct ModalContentProps {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
+    #[props(optional)]
+    size: Option<Size>,
+    #[props(optional)]
+    animation: Option<Animation>,
+
     children: Element,
 }
 
@@ -145,6 +151,16 @@ pub fn ModalContent(mut props: ModalContentProps) -> Element {
 
     let default_classes = "modal-content";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-size",
+        props.size.map(|size| size.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     rsx! {
         div {
@@ -166,6 +182,11 @@ pub struct ModalBackgroundProps {
     #[props(optional, default)]
     onclick: EventHandler<MouseEvent>,
 
+    #[props(optional)]
+    color: Option<Color>,
+    #[props(optional)]
+    animation: Option<Animation>,
+
     children: Element,
 }
 
@@ -175,6 +196,16 @@ pub fn ModalBackground(mut props: ModalBackgroundProps) -> Element {
 
     let default_classes = "modal-background";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     let onclick = move |event: Event<MouseData>| {
         event.stop_propagation();
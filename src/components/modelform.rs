@@ -0,0 +1,269 @@
+use crate::components::{
+    checkbox::*, form::*, input::*, radio::*, select::*, slider::*, textarea::*,
+};
+use dioxus::prelude::*;
+
+pub use dioxus_tw_components_sorttable_macro::Form;
+
+/// How a field of a [`FormModel`] is rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Widget {
+    /// [`Input`] of the given `type`
+    Input(&'static str),
+    TextArea,
+    Select(&'static [&'static str]),
+    Checkbox,
+    Radio(&'static [&'static str]),
+    Slider {
+        min: i64,
+        max: i64,
+        step: i64,
+    },
+}
+
+/// A field of a [`FormModel`], as described by its `#[field(...)]` attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSpec<F> {
+    pub field: F,
+    pub name: &'static str,
+    pub label: &'static str,
+    pub widget: Widget,
+    pub validators: Vec<Validator>,
+}
+
+/// A validation or conversion error of a field of a [`FormModel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError<F> {
+    pub field: F,
+    pub message: String,
+}
+
+/// A struct rendered as a form by [`ModelForm`], implemented by `#[derive(Form)]`.
+pub trait FormModel: Clone + PartialEq + 'static {
+    /// Fields of the struct, generated as `{Struct}Field`
+    type Field: std::fmt::Debug + Clone + Copy + PartialEq + 'static;
+
+    fn fields() -> Vec<FieldSpec<Self::Field>>;
+
+    fn to_values(&self) -> FormValues;
+
+    fn from_values(values: &FormValues) -> Result<Self, Vec<FieldError<Self::Field>>>;
+}
+
+/// Conversion of a struct field from and to the string value of its input.
+pub trait FormValue: Sized {
+    fn to_form_value(&self) -> String;
+
+    fn from_form_value(value: &str) -> Result<Self, String>;
+}
+
+impl FormValue for String {
+    fn to_form_value(&self) -> String {
+        self.clone()
+    }
+
+    fn from_form_value(value: &str) -> Result<Self, String> {
+        Ok(value.to_string())
+    }
+}
+
+/// Checked checkboxes are "on", like HTML forms
+impl FormValue for bool {
+    fn to_form_value(&self) -> String {
+        if *self { "on" } else { "" }.to_string()
+    }
+
+    fn from_form_value(value: &str) -> Result<Self, String> {
+        Ok(value == "on")
+    }
+}
+
+/// Empty values are `None`
+impl<T: FormValue> FormValue for Option<T> {
+    fn to_form_value(&self) -> String {
+        self.as_ref().map(T::to_form_value).unwrap_or_default()
+    }
+
+    fn from_form_value(value: &str) -> Result<Self, String> {
+        match value.trim() {
+            "" => Ok(None),
+            value => T::from_form_value(value).map(Some),
+        }
+    }
+}
+
+macro_rules! impl_number_form_value {
+    ($($number:ty),*) => {
+        $(
+            impl FormValue for $number {
+                fn to_form_value(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_form_value(value: &str) -> Result<Self, String> {
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|_| "Must be a number".to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_number_form_value!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+#[derive(Clone, PartialEq, Props)]
+pub struct ModelFormProps<T: FormModel> {
+    #[props(extends = form, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Initial values of the fields, overwritten on each valid submit.
+    /// Writing to it resets the fields to the new values.
+    value: Signal<T>,
+
+    #[props(optional)]
+    onsubmit: EventHandler<T>,
+
+    /// Called with the errors of every field when a submit is blocked
+    #[props(optional)]
+    onerror: EventHandler<Vec<FieldError<T::Field>>>,
+
+    /// State from [`use_form`], the form creates its own if not given
+    #[props(optional)]
+    form: Option<FormState>,
+
+    /// Rendered after the fields, e.g. the submit button
+    children: Element,
+}
+
+/// Renders a [`FormField`] per field of a `#[derive(Form)]` struct.
+#[component]
+pub fn ModelForm<T: FormModel>(mut props: ModelFormProps<T>) -> Element {
+    let own_form = use_form();
+    let mut form = props.form.unwrap_or(own_form);
+
+    let default_classes = "modelform";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let initial = props.value.peek().to_values();
+    let specs = T::fields();
+
+    // Follows the writes made to the value outside of the form
+    use_effect(move || {
+        for (name, value) in props.value.read().to_values().iter() {
+            form.init(name, value.to_string());
+        }
+    });
+
+    // Links the labels to their widgets
+    let id_prefix = use_hook(crate::use_unique_id);
+
+    let onsubmit = move |values: FormValues| match T::from_values(&values) {
+        Ok(value) => {
+            props.value.set(value.clone());
+            props.onsubmit.call(value);
+        }
+        Err(errors) => props.onerror.call(errors),
+    };
+
+    let oninvalid = {
+        let specs = specs.clone();
+        move |()| {
+            let errors = specs
+                .iter()
+                .flat_map(|spec| {
+                    form.errors(spec.name)
+                        .into_iter()
+                        .map(|message| FieldError {
+                            field: spec.field,
+                            message,
+                        })
+                })
+                .collect();
+            props.onerror.call(errors);
+        }
+    };
+
+    let fields = rsx! {
+        for spec in specs {
+            FormField {
+                key: "{spec.name}",
+                name: spec.name,
+                validators: spec.validators.clone(),
+                label {
+                    class: "form-label",
+                    id: "{id_prefix}-{spec.name}-label",
+                    r#for: (!matches!(spec.widget, Widget::Radio(_)))
+                        .then(|| format!("{id_prefix}-{}", spec.name)),
+                    "{spec.label}"
+                }
+                {
+                    render_widget(
+                        &spec.widget,
+                        format!("{id_prefix}-{}", spec.name),
+                        initial.get(spec.name).unwrap_or_default(),
+                    )
+                }
+            }
+        }
+        {props.children}
+    };
+
+    rsx! {
+        Form {
+            ..FormProps {
+                attributes: props.attributes,
+                form: Some(form),
+                onsubmit: EventHandler::new(onsubmit),
+                oninvalid: EventHandler::new(oninvalid),
+                children: fields,
+            }
+        }
+    }
+}
+
+/// Renders the widget with the `id` its label points to.
+/// A radio group is labelled by `{id}-label` instead, each option having its own label.
+fn render_widget(widget: &Widget, id: String, initial: &str) -> Element {
+    let initial = initial.to_string();
+    match widget {
+        Widget::Input(r#type) => rsx! {
+            Input { id, r#type: *r#type, default_value: initial }
+        },
+        Widget::TextArea => rsx! {
+            TextArea { id, default_value: initial }
+        },
+        Widget::Select(options) => rsx! {
+            SelectGroup { id, default_value: initial.clone(),
+                for option in options.iter() {
+                    SelectItem { value: *option, selected: *option == initial, "{option}" }
+                }
+            }
+        },
+        Widget::Checkbox => rsx! {
+            Checkbox { id, default_checked: initial == "on" }
+        },
+        Widget::Radio(options) => rsx! {
+            RadioGroup { "aria-labelledby": "{id}-label", default_value: initial,
+                for option in options.iter() {
+                    label { class: "form-option",
+                        RadioItem { value: *option }
+                        "{option}"
+                    }
+                }
+            }
+        },
+        Widget::Slider { min, max, step } => rsx! {
+            Slider {
+                id,
+                min: *min,
+                max: *max,
+                step: *step,
+                default_value: initial.parse().unwrap_or(*min),
+            }
+        },
+    }
+}
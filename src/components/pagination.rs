@@ -1,5 +1,4 @@
-use crate::components::{button::*, icon::*};
-use dioxus::dioxus_core::AttributeValue;
+use crate::components::{button::*, icon::*, variants::*};
 use dioxus::prelude::*;
 
 #[derive(Clone, PartialEq, Props)]
@@ -14,6 +13,14 @@ pub struct PaginationProps {
     #[props(into)]
     pub page_size: ReadSignal<usize>,
     pub page_number: Signal<usize>,
+
+    /// Applied to every button
+    #[props(optional)]
+    pub color: Option<Color>,
+    #[props(optional)]
+    pub variant: Option<ButtonVariant>,
+    #[props(optional)]
+    pub size: Option<Size>,
 }
 
 #[component]
@@ -21,36 +28,11 @@ pub fn Pagination(mut props: PaginationProps) -> Element {
     let default_classes = "pagination";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
-    let max_pages = use_memo(move || (*props.data_size.read() / *props.page_size.read()) + 1);
+    // Raw attributes still style the buttons, the typed props take precedence over them
+    let data_style = crate::attribute_text(&props.attributes, "data-style");
+    let data_variant = crate::attribute_text(&props.attributes, "data-variant");
 
-    let data_variant_attribute = match props
-        .attributes
-        .iter()
-        .find(|attr| attr.name == "data-variant")
-    {
-        Some(attribute) => {
-            if let AttributeValue::Text(ref value) = attribute.value {
-                value.clone()
-            } else {
-                "none".to_string()
-            }
-        }
-        _ => "none".to_string(),
-    };
-    let data_color_attribute = match props
-        .attributes
-        .iter()
-        .find(|attr| attr.name == "data-style")
-    {
-        Some(attribute) => {
-            if let AttributeValue::Text(ref value) = attribute.value {
-                value.clone()
-            } else {
-                "none".to_string()
-            }
-        }
-        _ => "none".to_string(),
-    };
+    let max_pages = use_memo(move || (*props.data_size.read() / *props.page_size.read()) + 1);
 
     let prev_dots = use_memo(move || *props.page_number.read() > 2);
     let next_dots =
@@ -61,8 +43,11 @@ pub fn Pagination(mut props: PaginationProps) -> Element {
             ..props.attributes,
             Button {
                 class: format!("pagination-nav-button {}", props.class),
-                "data-variant": data_variant_attribute.clone(),
-                "data-style": data_color_attribute.clone(),
+                color: props.color,
+                variant: props.variant,
+                size: props.size,
+                "data-style": data_style.clone(),
+                "data-variant": data_variant.clone(),
                 disabled: *props.page_number.read() == 1,
                 onclick: move |_event: MouseEvent| {
                     let value = *props.page_number.peek();
@@ -74,8 +59,11 @@ pub fn Pagination(mut props: PaginationProps) -> Element {
                 Button {
                     class: props.class,
                     disabled: *props.page_number.read() == 1,
-                    "data-variant": data_variant_attribute.clone(),
-                    "data-style": data_color_attribute.clone(),
+                    color: props.color,
+                    variant: props.variant,
+                    size: props.size,
+                    "data-style": data_style.clone(),
+                    "data-variant": data_variant.clone(),
                     onclick: move |_event: MouseEvent| {
                         props.page_number.set(1);
                     },
@@ -88,8 +76,11 @@ pub fn Pagination(mut props: PaginationProps) -> Element {
             {
                 Button {
                     class: props.class,
-                    "data-variant": data_variant_attribute.clone(),
-                    "data-style": data_color_attribute.clone(),
+                    color: props.color,
+                    variant: props.variant,
+                    size: props.size,
+                    "data-style": data_style.clone(),
+                    "data-variant": data_variant.clone(),
                     disabled: *props.page_number.read() == page,
                     onclick: move |_event: MouseEvent| {
                         props.page_number.set(page);
@@ -101,8 +92,11 @@ pub fn Pagination(mut props: PaginationProps) -> Element {
                 p { class: "pagination-dots", "..." }
                 Button {
                     class: props.class,
-                    "data-variant": data_variant_attribute.clone(),
-                    "data-style": data_color_attribute.clone(),
+                    color: props.color,
+                    variant: props.variant,
+                    size: props.size,
+                    "data-style": data_style.clone(),
+                    "data-variant": data_variant.clone(),
                     disabled: *props.page_number.read() == *max_pages.read(),
                     onclick: move |_event: MouseEvent| {
                         props.page_number.set(*max_pages.peek());
@@ -112,8 +106,11 @@ pub fn Pagination(mut props: PaginationProps) -> Element {
             }
             Button {
                 class: format!("pagination-nav-button {}", props.class),
-                "data-variant": data_variant_attribute.clone(),
-                "data-style": data_color_attribute.clone(),
+                color: props.color,
+                variant: props.variant,
+                size: props.size,
+                "data-style": data_style.clone(),
+                "data-variant": data_variant.clone(),
                 disabled: *props.page_number.read() == *max_pages.read(),
                 onclick: move |_event: MouseEvent| {
                     let value = *props.page_number.peek();
@@ -1,3 +1,4 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
 #[derive(Clone, PartialEq, Props)]
@@ -5,12 +6,28 @@ pub struct PlaceholderProps {
     /// Additional attributes to apply to the element
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
+
+    #[props(optional)]
+    color: Option<Color>,
+    /// Defaults to [`Animation::Full`]
+    #[props(optional)]
+    animation: Option<Animation>,
 }
 
 #[component]
 pub fn Placeholder(mut props: PlaceholderProps) -> Element {
     let default_classes = "placeholder";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     // Placeholders are fully animated by default
     if !props
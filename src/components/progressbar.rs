@@ -1,3 +1,4 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
 #[derive(Clone, PartialEq, Props)]
@@ -5,6 +6,11 @@ pub struct ProgressBarProps {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
+    #[props(optional)]
+    color: Option<Color>,
+    #[props(optional)]
+    size: Option<Size>,
+
     children: Element,
 }
 
@@ -12,6 +18,16 @@ pub struct ProgressBarProps {
 pub fn ProgressBar(mut props: ProgressBarProps) -> Element {
     let default_classes = "progressbar";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-size",
+        props.size.map(|size| size.as_str()),
+    );
 
     rsx! {
         div { ..props.attributes, {props.children} }
@@ -26,6 +42,9 @@ pub struct ProgressBarInnerProps {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
+    #[props(optional)]
+    color: Option<Color>,
+
     children: Element,
 }
 
@@ -33,6 +52,11 @@ pub struct ProgressBarInnerProps {
 pub fn ProgressBarInner(mut props: ProgressBarInnerProps) -> Element {
     let default_classes = "progressbar-inner";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
 
     // Avoid ProgressBar's data-style being applied to ProgressBarInner
     if !props
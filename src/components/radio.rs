@@ -1,9 +1,12 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct RadioGroupCtx {
     value: Signal<String>,
+    controlled: bool,
     onchange: EventHandler<MouseEvent>,
+    field: crate::FieldBinding,
 }
 
 #[derive(Clone, PartialEq, Props)]
@@ -15,8 +18,9 @@ pub struct RadioGroupProps {
     #[props(optional)]
     default_value: String,
 
+    /// Controls the checked value, the group keeps its own starting from `default_value` if not given
     #[props(optional)]
-    value: Signal<String>,
+    value: Option<Signal<String>>,
 
     #[props(optional)]
     onchange: EventHandler<MouseEvent>,
@@ -24,16 +28,29 @@ pub struct RadioGroupProps {
 
 #[component]
 pub fn RadioGroup(mut props: RadioGroupProps) -> Element {
-    use_context_provider(|| RadioGroupCtx {
-        value: props.value,
+    let value = crate::use_controlled(props.value, || props.default_value.clone());
+    let controlled = props.value.is_some();
+    let field = crate::use_field_binding(|| value.peek().clone());
+    field.use_controlled_value(controlled, move || value.cloned());
+    let ctx = use_context_provider(|| RadioGroupCtx {
+        value,
+        controlled,
         onchange: props.onchange,
+        field,
     });
 
     let default_classes = "radio";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
+    // The items carry the name, the group only shows the validity
+    if ctx.field.is_invalid() {
+        props
+            .attributes
+            .push(Attribute::new("aria-invalid", "true", None, false));
+    }
+
     rsx! {
-        div { ..props.attributes, {props.children} }
+        div { role: "radiogroup", ..props.attributes, {props.children} }
     }
 }
 
@@ -42,6 +59,9 @@ pub struct RadioItemProps {
     #[props(extends = input, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
     value: String,
+
+    #[props(optional)]
+    color: Option<Color>,
 }
 
 #[component]
@@ -50,9 +70,21 @@ pub fn RadioItem(mut props: RadioItemProps) -> Element {
 
     let default_classes = "radio-input";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
+
+    if let Some(name) = state.field.name()
+        && !props.attributes.iter().any(|attr| attr.name == "name")
+    {
+        props
+            .attributes
+            .push(Attribute::new("name", name.to_string(), None, false));
+    }
 
-    let value = props.value.clone();
-    let checked = use_memo(move || (state.value)() == value);
+    let checked = state.field.value_or(state.controlled, (state.value)()) == props.value;
 
     rsx! {
         input {
@@ -60,6 +92,8 @@ pub fn RadioItem(mut props: RadioItemProps) -> Element {
             checked,
             onclick: move |event| {
                 state.value.set(props.value.clone());
+                state.field.set_value(props.value.clone());
+                state.field.touch();
                 state.onchange.call(event);
             },
             ..props.attributes,
@@ -1,3 +1,4 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
 #[derive(Clone, PartialEq, Props)]
@@ -5,6 +6,9 @@ pub struct ScrollableProps {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
+    #[props(optional)]
+    orientation: Option<Orientation>,
+
     children: Element,
 }
 
@@ -12,6 +16,11 @@ pub struct ScrollableProps {
 pub fn Scrollable(mut props: ScrollableProps) -> Element {
     let default_classes = "scrollable";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-orientation",
+        props.orientation.map(|orientation| orientation.as_str()),
+    );
 
     rsx! {
         div { ..props.attributes, {props.children} }
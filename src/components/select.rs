@@ -8,8 +8,9 @@ pub struct SelectGroupProps {
     #[props(optional)]
     default_value: String,
 
+    /// Controls the value, the input keeps its own starting from `default_value` if not given
     #[props(optional)]
-    value: Signal<String>,
+    value: Option<Signal<String>>,
 
     #[props(optional)]
     onchange: EventHandler<FormEvent>,
@@ -22,15 +23,29 @@ pub fn SelectGroup(mut props: SelectGroupProps) -> Element {
     let default_classes = "select-group";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
+    let mut value = crate::use_controlled(props.value, || props.default_value.clone());
+
+    let field = crate::use_field_binding(|| value.peek().clone());
+    field.setup_attributes(&mut props.attributes);
+    field.use_controlled_value(props.value.is_some(), move || value.cloned());
+    let current_value = field.value_or(props.value.is_some(), value());
+
+    let onblur = {
+        let field = field.clone();
+        move |_| field.touch()
+    };
+
     let oninput = move |event: FormEvent| {
-        props.value.set(event.data.value().clone());
+        value.set(event.data.value());
+        field.set_value(event.data.value());
         props.onchange.call(event);
     };
 
     rsx! {
         select {
             oninput,
-            value: props.default_value,
+            onblur,
+            value: current_value,
             ..props.attributes,
             {props.children}
         }
@@ -1,3 +1,4 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
 #[derive(Clone, PartialEq, Props)]
@@ -5,12 +6,20 @@ pub struct SeparatorProps {
     /// Additional attributes to apply to the element
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
+
+    #[props(optional)]
+    orientation: Option<Orientation>,
 }
 
 #[component]
 pub fn Separator(mut props: SeparatorProps) -> Element {
     let default_classes = "separator";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-orientation",
+        props.orientation.map(|orientation| orientation.as_str()),
+    );
 
     rsx! {
         div { ..props.attributes }
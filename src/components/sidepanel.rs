@@ -1,8 +1,29 @@
 use crate::components::icon::*;
-use crate::dioxus_core::IntoAttributeValue;
+use crate::components::variants::*;
+use dioxus::dioxus_core::IntoAttributeValue;
 use dioxus::prelude::*;
 use dioxus_core::AttributeValue;
 
+/// Side of the screen a side panel slides from, rendered as `data-side`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Side {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Side::Left => "left",
+            Side::Right => "right",
+            Side::Top => "top",
+            Side::Bottom => "bottom",
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct SidePanelState {
     is_active: bool,
@@ -132,6 +153,11 @@ pub struct SidePanelContentProps {
     #[props(extends = div, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
+    #[props(optional)]
+    side: Option<Side>,
+    #[props(optional)]
+    animation: Option<Animation>,
+
     children: Element,
 }
 
@@ -141,6 +167,16 @@ pub fn SidePanelContent(mut props: SidePanelContentProps) -> Element {
 
     let default_classes = "sidepanel-content";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-side",
+        props.side.map(|side| side.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     rsx! {
         div {
@@ -162,6 +198,11 @@ pub struct SidePanelBackgroundProps {
     #[props(optional, default)]
     onclick: EventHandler<MouseEvent>,
 
+    #[props(optional)]
+    color: Option<Color>,
+    #[props(optional)]
+    animation: Option<Animation>,
+
     children: Element,
 }
 
@@ -171,6 +212,16 @@ pub fn SidePanelBackground(mut props: SidePanelBackgroundProps) -> Element {
 
     let default_classes = "sidepanel-background";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-style",
+        props.color.map(|color| color.as_str()),
+    );
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-animation",
+        props.animation.map(|animation| animation.as_str()),
+    );
 
     let onclick = move |event: Event<MouseData>| {
         event.stop_propagation();
@@ -1,3 +1,4 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
 #[derive(Default, Clone, PartialEq, Props)]
@@ -14,8 +15,9 @@ pub struct SliderProps {
     #[props(optional, default = 10)]
     step: i64,
 
+    /// Controls the value, the slider keeps its own starting from `default_value` if not given
     #[props(optional)]
-    value: Signal<i64>,
+    value: Option<Signal<i64>>,
 
     #[props(optional)]
     onchange: EventHandler<FormEvent>,
@@ -26,8 +28,27 @@ pub fn Slider(mut props: SliderProps) -> Element {
     let default_classes = "slider";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
+    let mut value = crate::use_controlled(props.value, || props.default_value);
+
+    let field = crate::use_field_binding(|| value.peek().to_string());
+    field.setup_attributes(&mut props.attributes);
+    field.use_controlled_value(props.value.is_some(), move || value().to_string());
+    let current_value = field.value_or(props.value.is_some(), value().to_string());
+
+    let onblur = {
+        let field = field.clone();
+        move |_| field.touch()
+    };
+
     let oninput = move |event: FormEvent| {
-        props.value.set(event.data.value().parse().unwrap_or(0));
+        let parse_error = match event.data.value().parse() {
+            Ok(parsed) => {
+                value.set(parsed);
+                None
+            }
+            Err(_) => Some("Must be a number".to_string()),
+        };
+        field.set_parsed_value(event.data.value(), parse_error);
         props.onchange.call(event);
     };
 
@@ -38,8 +59,9 @@ pub fn Slider(mut props: SliderProps) -> Element {
             min: props.min.to_string(),
             max: props.max.to_string(),
             step: props.step.to_string(),
-            value: props.default_value.to_string(),
+            value: current_value,
             oninput,
+            onblur,
             ..props.attributes
         }
     }
@@ -69,3 +91,184 @@ pub fn SliderLabel(mut props: SliderLabelProps) -> Element {
         }
     }
 }
+
+/// A value marked under the track of a [`RangeSlider`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderMark {
+    pub value: f64,
+    pub label: Option<String>,
+}
+
+impl SliderMark {
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// A mark labelled by its value
+impl From<f64> for SliderMark {
+    fn from(value: f64) -> Self {
+        SliderMark::new(value).label(value.to_string())
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct RangeSliderProps {
+    #[props(extends = div, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    /// Defaults to the whole range
+    #[props(optional)]
+    default_value: Option<(f64, f64)>,
+
+    /// Controls the range, the slider keeps its own starting from `default_value` if not given
+    #[props(optional)]
+    value: Option<Signal<(f64, f64)>>,
+
+    #[props(default = 0.0)]
+    min: f64,
+    #[props(default = 100.0)]
+    max: f64,
+    #[props(default = 1.0)]
+    step: f64,
+
+    #[props(default)]
+    marks: Vec<SliderMark>,
+
+    #[props(optional)]
+    orientation: Option<Orientation>,
+
+    /// Shows the value over a thumb while it is dragged or focused
+    #[props(default = true)]
+    tooltip: bool,
+
+    #[props(optional)]
+    onchange: EventHandler<(f64, f64)>,
+}
+
+/// A slider selecting a range with two thumbs, each one stopping at the other.
+#[component]
+pub fn RangeSlider(mut props: RangeSliderProps) -> Element {
+    let default_classes = "rangeslider";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+    crate::setup_data_attribute(
+        &mut props.attributes,
+        "data-orientation",
+        props.orientation.map(|orientation| orientation.as_str()),
+    );
+
+    let (min, max) = (props.min, props.max);
+    let mut value =
+        crate::use_controlled(props.value, || props.default_value.unwrap_or((min, max)));
+    let mut dragging = use_signal(|| None::<usize>);
+
+    let to_form_value = |(lower, upper): (f64, f64)| format!("{lower},{upper}");
+    let field = crate::use_field_binding(|| to_form_value(*value.peek()));
+    field.setup_attributes(&mut props.attributes);
+    field.use_controlled_value(props.value.is_some(), move || to_form_value(value()));
+
+    // The field holds the range when uncontrolled, so that resetting the form moves the thumbs
+    let controlled = props.value.is_some();
+    let current_value = {
+        let field = field.clone();
+        move |own: (f64, f64)| {
+            field
+                .value_or(controlled, to_form_value(own))
+                .split_once(',')
+                .and_then(|(lower, upper)| Some((lower.parse().ok()?, upper.parse().ok()?)))
+                .unwrap_or(own)
+        }
+    };
+
+    // Unitless, for the stylesheet to offset the positions by half a thumb
+    let percent = move |value: f64| match max > min {
+        true => ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0),
+        false => 0.0,
+    };
+
+    let set_thumb = {
+        let field = field.clone();
+        let current_value = current_value.clone();
+        move |thumb: usize, event: FormEvent| {
+            let Ok(parsed) = event.data.value().parse::<f64>() else {
+                return;
+            };
+            let (lower, upper) = current_value(*value.peek());
+            let next = match thumb {
+                0 => (parsed.min(upper), upper),
+                _ => (lower, parsed.max(lower)),
+            };
+            value.set(next);
+            field.set_value(to_form_value(next));
+            props.onchange.call(next);
+        }
+    };
+
+    let (lower, upper) = current_value(value());
+    let thumbs = [("Minimum", lower), ("Maximum", upper)];
+
+    rsx! {
+        div { ..props.attributes,
+            div { class: "rangeslider-track",
+                div {
+                    class: "rangeslider-range",
+                    style: "--start: {percent(lower)}; --end: {percent(upper)}",
+                }
+            }
+            for (thumb, (label, thumb_value)) in thumbs.into_iter().enumerate() {
+                input {
+                    key: "{thumb}",
+                    class: "rangeslider-thumb",
+                    r#type: "range",
+                    aria_label: label,
+                    min: min.to_string(),
+                    max: max.to_string(),
+                    step: props.step.to_string(),
+                    value: thumb_value.to_string(),
+                    // The lower thumb stays reachable when both are at the maximum
+                    "data-top": thumb == 0 && lower >= max,
+                    oninput: {
+                        let mut set_thumb = set_thumb.clone();
+                        move |event| set_thumb(thumb, event)
+                    },
+                    onpointerdown: move |_| dragging.set(Some(thumb)),
+                    onpointerup: move |_| dragging.set(None),
+                    onblur: {
+                        let field = field.clone();
+                        move |_| {
+                            dragging.set(None);
+                            field.touch();
+                        }
+                    },
+                }
+                if props.tooltip {
+                    output {
+                        class: "rangeslider-tooltip",
+                        style: "--position: {percent(thumb_value)}",
+                        "data-active": dragging() == Some(thumb),
+                        "{thumb_value}"
+                    }
+                }
+            }
+            if !props.marks.is_empty() {
+                div { class: "rangeslider-marks",
+                    for mark in props.marks.iter() {
+                        span {
+                            class: "rangeslider-mark",
+                            style: "--position: {percent(mark.value)}",
+                            "data-in-range": (lower..=upper).contains(&mark.value),
+                            if let Some(label) = &mark.label {
+                                "{label}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::components::variants::*;
 use dioxus::prelude::*;
 
 #[derive(Default, Clone, PartialEq, Props)]
@@ -8,28 +9,113 @@ pub struct TextAreaProps {
     #[props(optional)]
     default_value: String,
 
+    /// Controls the value, the input keeps its own starting from `default_value` if not given
     #[props(optional)]
-    value: Signal<String>,
+    value: Option<Signal<String>>,
 
     #[props(optional)]
     onchange: EventHandler<FormEvent>,
+
+    /// Counts the text under the field, against its `maxlength` for characters
+    #[props(optional)]
+    counter: Option<Counter>,
+
+    /// Grows with its text from its `rows`, instead of scrolling
+    #[props(default)]
+    auto_grow: bool,
+
+    /// Rows the field grows to before scrolling
+    #[props(optional)]
+    max_rows: Option<usize>,
 }
 
+/// A multiline text field, with an optional counter under it.
 #[component]
 pub fn TextArea(mut props: TextAreaProps) -> Element {
     let default_classes = "textarea";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
+    let mut value = crate::use_controlled(props.value, || props.default_value.clone());
+
+    let field = crate::use_field_binding(|| value.peek().clone());
+    field.setup_attributes(&mut props.attributes);
+    field.use_controlled_value(props.value.is_some(), move || value.cloned());
+    let current_value = field.value_or(props.value.is_some(), value());
+
+    if props.auto_grow {
+        let min_rows = crate::attribute_text(&props.attributes, "rows")
+            .and_then(|rows| rows.parse().ok())
+            .unwrap_or(2);
+        // Browsers without `field-sizing` only grow with the lines, not with the wrapped text
+        let lines = current_value.split('\n').count();
+        let max_rows = props.max_rows.unwrap_or(usize::MAX).max(min_rows);
+        let rows = lines.clamp(min_rows, max_rows);
+        props.attributes.retain(|attr| attr.name != "rows");
+        props
+            .attributes
+            .push(Attribute::new("rows", rows.to_string(), None, false));
+        crate::setup_data_attribute(&mut props.attributes, "data-autogrow", Some("true"));
+
+        if let Some(max_rows) = props.max_rows {
+            let max_rows = format!("--max-rows: {max_rows}");
+            // Added to the style given, a second `style` attribute would replace it
+            let style = match crate::attribute_text(&props.attributes, "style") {
+                Some(style) if !style.trim().is_empty() => {
+                    format!("{}; {max_rows}", style.trim().trim_end_matches(';'))
+                }
+                _ => max_rows,
+            };
+            props.attributes.retain(|attr| attr.name != "style");
+            props
+                .attributes
+                .push(Attribute::new("style", style, None, false));
+        }
+    }
+
+    let max_length = crate::attribute_text(&props.attributes, "maxlength")
+        .and_then(|max_length| max_length.parse().ok());
+    let counter = props
+        .counter
+        .map(|counter| (counter, counter.format(&current_value, max_length)));
+    let is_full = matches!(
+        (props.counter, max_length),
+        (Some(Counter::Characters), Some(max)) if Counter::Characters.count(&current_value) >= max
+    );
+
+    let onblur = {
+        let field = field.clone();
+        move |_| field.touch()
+    };
+
     let oninput = move |event: FormEvent| {
-        props.value.set(event.data.value().clone());
+        value.set(event.data.value());
+        field.set_value(event.data.value());
         props.onchange.call(event);
     };
 
-    rsx! {
+    let textarea = rsx! {
         textarea {
             oninput,
-            value: props.default_value,
+            onblur,
+            value: current_value,
             ..props.attributes,
         }
+    };
+
+    let Some((counter, text)) = counter else {
+        return textarea;
+    };
+
+    rsx! {
+        div { class: "textarea-group",
+            {textarea}
+            span {
+                class: "textarea-counter",
+                "data-counter": counter.as_str(),
+                "data-full": is_full,
+                aria_live: "polite",
+                "{text}"
+            }
+        }
     }
 }
@@ -4,8 +4,14 @@ use dioxus::prelude::*;
 pub struct ToggleProps {
     #[props(extends = button, extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
+
+    /// State the toggle starts in, formerly given as `checked`
+    #[props(optional)]
+    default_checked: bool,
+
+    /// Controls the state, the toggle keeps its own starting from `default_checked` if not given
     #[props(optional)]
-    checked: Option<bool>,
+    checked: Option<Signal<bool>>,
 
     #[props(optional)]
     onclick: EventHandler<MouseEvent>,
@@ -18,16 +24,16 @@ pub fn Toggle(mut props: ToggleProps) -> Element {
     let default_classes = "toggle";
     crate::setup_class_attribute(&mut props.attributes, default_classes);
 
-    let mut interior_sig = use_signal(|| props.checked.unwrap_or_default());
+    let mut checked = crate::use_controlled(props.checked, || props.default_checked);
 
     let onclick = move |event| {
-        interior_sig.toggle();
+        checked.toggle();
         props.onclick.call(event);
     };
 
     rsx! {
         button {
-            "data-state": if interior_sig() { "checked" } else { "unchecked" },
+            "data-state": if checked() { "checked" } else { "unchecked" },
             r#type: "button",
             onclick,
             ..props.attributes,
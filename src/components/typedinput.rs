@@ -0,0 +1,538 @@
+use crate::components::icon::*;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use dioxus::prelude::*;
+use std::{fmt::Display, str::FromStr};
+
+/// Separators of the numbers typed in a [`NumberInput`].
+/// Whitespace is ignored whatever the locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    pub decimal: char,
+    pub group: char,
+}
+
+impl NumberLocale {
+    /// `1,234.5`
+    pub const EN: NumberLocale = NumberLocale {
+        decimal: '.',
+        group: ',',
+    };
+    /// `1 234,5`
+    pub const FR: NumberLocale = NumberLocale {
+        decimal: ',',
+        group: '\u{202f}',
+    };
+    /// `1.234,5`
+    pub const DE: NumberLocale = NumberLocale {
+        decimal: ',',
+        group: '.',
+    };
+
+    pub fn parse<T: FromStr>(&self, text: &str) -> Option<T> {
+        text.chars()
+            .filter(|c| *c != self.group && !c.is_whitespace())
+            .map(|c| if c == self.decimal { '.' } else { c })
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+
+    /// Formats without group separators, to keep the text easy to edit
+    pub fn format<T: Display>(&self, value: &T) -> String {
+        value.to_string().replace('.', &self.decimal.to_string())
+    }
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        NumberLocale::EN
+    }
+}
+
+/// Numbers held by a [`NumberInput`].
+pub trait Number: Copy + PartialOrd + Display + FromStr + 'static {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn step_up(self, step: Self) -> Self;
+
+    fn step_down(self, step: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl Number for $integer {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn step_up(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+            }
+        )*
+    };
+}
+
+/// Rounds a stepped float to the decimals of the value and the step, so 0.2 + 0.1 is 0.3
+/// instead of 0.30000000000000004
+fn round_step<T: Display + FromStr + Copy>(next: T, value: T, step: T) -> T {
+    let decimals = |number: T| {
+        number
+            .to_string()
+            .split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len())
+    };
+    let precision = decimals(value).max(decimals(step));
+    format!("{next:.precision$}").parse().unwrap_or(next)
+}
+
+macro_rules! impl_float {
+    ($($float:ty),*) => {
+        $(
+            impl Number for $float {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn step_up(self, step: Self) -> Self {
+                    round_step(self + step, self, step)
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    round_step(self - step, self, step)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_float!(f32, f64);
+
+/// Values held by the date and time inputs.
+pub trait Temporal: Clone + PartialOrd + 'static {
+    /// `type` of the native input
+    const INPUT_TYPE: &'static str;
+    /// Error of a text that does not parse
+    const PARSE_ERROR: &'static str;
+
+    /// Parses the value of the native input
+    fn parse_input(text: &str) -> Option<Self>;
+
+    /// Formats as the value of the native input
+    fn format_input(&self) -> String;
+}
+
+impl Temporal for NaiveDate {
+    const INPUT_TYPE: &'static str = "date";
+    const PARSE_ERROR: &'static str = "Invalid date";
+
+    fn parse_input(text: &str) -> Option<Self> {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+    }
+
+    fn format_input(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+/// Seconds are omitted when zero, like browsers do
+impl Temporal for NaiveTime {
+    const INPUT_TYPE: &'static str = "time";
+    const PARSE_ERROR: &'static str = "Invalid time";
+
+    fn parse_input(text: &str) -> Option<Self> {
+        NaiveTime::parse_from_str(text, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(text, "%H:%M"))
+            .ok()
+    }
+
+    fn format_input(&self) -> String {
+        match self.second() {
+            0 => self.format("%H:%M").to_string(),
+            _ => self.format("%H:%M:%S").to_string(),
+        }
+    }
+}
+
+impl Temporal for NaiveDateTime {
+    const INPUT_TYPE: &'static str = "datetime-local";
+    const PARSE_ERROR: &'static str = "Invalid date and time";
+
+    fn parse_input(text: &str) -> Option<Self> {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M"))
+            .ok()
+    }
+
+    fn format_input(&self) -> String {
+        format!(
+            "{}T{}",
+            self.date().format_input(),
+            self.time().format_input()
+        )
+    }
+}
+
+/// Text typed in a typed input, and the value it stood for when typed
+#[derive(Clone, PartialEq)]
+struct TypedText<T> {
+    text: String,
+    value: Option<T>,
+}
+
+/// Value and text of a typed input, and its binding to the enclosing form field
+#[derive(Clone)]
+struct TypedInput<T: 'static> {
+    value: Signal<Option<T>>,
+    typed: Signal<TypedText<T>>,
+    controlled: bool,
+    field: crate::FieldBinding,
+}
+
+fn use_typed_input<T: Clone + PartialEq + 'static>(
+    value: Option<Signal<Option<T>>>,
+    default_value: Option<T>,
+    format: impl Fn(&T) -> String + Copy + 'static,
+) -> TypedInput<T> {
+    let controlled = value.is_some();
+    let value = crate::use_controlled(value, || default_value);
+    let typed = use_signal(|| TypedText {
+        text: value.peek().as_ref().map(&format).unwrap_or_default(),
+        value: value.peek().clone(),
+    });
+    let field = crate::use_field_binding(|| typed.peek().text.clone());
+
+    let input = TypedInput {
+        value,
+        typed,
+        controlled,
+        field,
+    };
+    input.field.use_controlled_value(controlled, {
+        let input = input.clone();
+        move || input.typed_text(format)
+    });
+    input
+}
+
+impl<T: Clone + PartialEq + 'static> TypedInput<T> {
+    /// The typed text, unless the value was written elsewhere since
+    fn typed_text(&self, format: impl Fn(&T) -> String) -> String {
+        let typed = self.typed.read();
+        let value = self.value.read();
+        if typed.value == *value {
+            typed.text.clone()
+        } else {
+            value.as_ref().map(format).unwrap_or_default()
+        }
+    }
+
+    /// Text to show: the typed one, or the text of the field when uncontrolled
+    fn text(&self, format: impl Fn(&T) -> String) -> String {
+        self.field
+            .value_or(self.controlled, self.typed_text(format))
+    }
+
+    /// Stores the typed text, and its value if it parsed
+    fn set_text(&mut self, text: String, parsed: Result<Option<T>, String>) -> Option<String> {
+        let (value, error) = match parsed {
+            Ok(value) => {
+                self.value.set(value.clone());
+                (value, None)
+            }
+            Err(error) => (self.value.peek().clone(), Some(error)),
+        };
+        self.field.set_parsed_value(text.clone(), error.clone());
+        self.typed.set(TypedText { text, value });
+        error
+    }
+}
+
+/// Error of a value out of `min..=max`
+fn range_error<T: PartialOrd>(
+    value: &Option<T>,
+    min: &Option<T>,
+    max: &Option<T>,
+    format: impl Fn(&T) -> String,
+) -> Option<String> {
+    let value = value.as_ref()?;
+    match (min, max) {
+        (Some(min), _) if value < min => Some(format!("Must be at least {}", format(min))),
+        (_, Some(max)) if value > max => Some(format!("Must be at most {}", format(max))),
+        _ => None,
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct NumberInputProps<T: Number> {
+    #[props(extends = input, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    #[props(optional)]
+    default_value: Option<T>,
+
+    /// Controls the value, `None` while the input is empty
+    #[props(optional)]
+    value: Option<Signal<Option<T>>>,
+
+    #[props(optional)]
+    min: Option<T>,
+    #[props(optional)]
+    max: Option<T>,
+    /// Defaults to 1
+    #[props(optional)]
+    step: Option<T>,
+
+    #[props(default)]
+    locale: NumberLocale,
+
+    /// Shows the - and + buttons
+    #[props(default = true)]
+    steppers: bool,
+
+    /// Called with the value whenever the text parses
+    #[props(optional)]
+    onchange: EventHandler<Option<T>>,
+}
+
+/// A number input parsing its text with `locale`, and stepping by `step` with its buttons
+/// or the arrow keys. Text that does not parse is an error of the field instead of a value.
+#[component]
+pub fn NumberInput<T: Number>(mut props: NumberInputProps<T>) -> Element {
+    let default_classes = "input";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let locale = props.locale;
+    let format = move |value: &T| locale.format(value);
+    let input = use_typed_input(props.value, props.default_value, format);
+    input.field.setup_attributes(&mut props.attributes);
+
+    let (min, max) = (props.min, props.max);
+    let parse = move |text: &str| -> Result<Option<T>, String> {
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        let value = locale
+            .parse(text)
+            .ok_or_else(|| "Must be a number".to_string())?;
+        match range_error(&Some(value), &min, &max, format) {
+            Some(error) => Err(error),
+            None => Ok(Some(value)),
+        }
+    };
+
+    let oninput = {
+        let mut input = input.clone();
+        move |event: FormEvent| {
+            let text = event.data.value();
+            let parsed = parse(&text);
+            if input.set_text(text, parsed).is_none() {
+                props.onchange.call(*input.value.peek());
+            }
+        }
+    };
+
+    let step = props.step.unwrap_or(T::ONE);
+    let step_by = {
+        let mut input = input.clone();
+        move |up: bool| {
+            let current = *input.value.peek();
+            let mut next = match current {
+                Some(value) if up => value.step_up(step),
+                Some(value) => value.step_down(step),
+                None => min.unwrap_or(T::ZERO),
+            };
+            if let Some(min) = min
+                && next < min
+            {
+                next = min;
+            }
+            if let Some(max) = max
+                && next > max
+            {
+                next = max;
+            }
+            input.set_text(format(&next), Ok(Some(next)));
+            input.field.touch();
+            props.onchange.call(Some(next));
+        }
+    };
+
+    let onkeydown = {
+        let mut step_by = step_by.clone();
+        move |event: KeyboardEvent| match event.key() {
+            Key::ArrowUp => {
+                event.prevent_default();
+                step_by(true);
+            }
+            Key::ArrowDown => {
+                event.prevent_default();
+                step_by(false);
+            }
+            _ => {}
+        }
+    };
+
+    let onblur = {
+        let field = input.field.clone();
+        move |_| field.touch()
+    };
+
+    let text = input.text(format);
+    let invalid_text = parse(&text).is_err();
+    if invalid_text && input.field.name().is_none() {
+        props
+            .attributes
+            .push(Attribute::new("aria-invalid", "true", None, false));
+    }
+
+    let value = *input.value.read();
+    let at_min = matches!((value, min), (Some(value), Some(min)) if value <= min);
+    let at_max = matches!((value, max), (Some(value), Some(max)) if value >= max);
+
+    let mut decrement = step_by.clone();
+    let mut increment = step_by;
+
+    rsx! {
+        div { class: "numberinput",
+            if props.steppers {
+                button {
+                    class: "numberinput-stepper",
+                    r#type: "button",
+                    tabindex: "-1",
+                    aria_label: "Decrement",
+                    disabled: at_min,
+                    onclick: move |_| decrement(false),
+                    Icon { icon: Icons::Remove }
+                }
+            }
+            input {
+                r#type: "text",
+                inputmode: "decimal",
+                role: "spinbutton",
+                "aria-valuemin": min.map(|min| min.to_string()),
+                "aria-valuemax": max.map(|max| max.to_string()),
+                "aria-valuenow": value.map(|value| value.to_string()),
+                value: text,
+                oninput,
+                onkeydown,
+                onblur,
+                ..props.attributes,
+            }
+            if props.steppers {
+                button {
+                    class: "numberinput-stepper",
+                    r#type: "button",
+                    tabindex: "-1",
+                    aria_label: "Increment",
+                    disabled: at_max,
+                    onclick: move |_| increment(true),
+                    Icon { icon: Icons::Add }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct TemporalInputProps<T: Temporal> {
+    #[props(extends = input, extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    #[props(optional)]
+    default_value: Option<T>,
+
+    /// Controls the value, `None` while the input is empty
+    #[props(optional)]
+    value: Option<Signal<Option<T>>>,
+
+    #[props(optional)]
+    min: Option<T>,
+    #[props(optional)]
+    max: Option<T>,
+
+    /// Called with the value whenever the text parses
+    #[props(optional)]
+    onchange: EventHandler<Option<T>>,
+}
+
+pub type DateInputProps = TemporalInputProps<NaiveDate>;
+pub type TimeInputProps = TemporalInputProps<NaiveTime>;
+pub type DateTimeInputProps = TemporalInputProps<NaiveDateTime>;
+
+/// A native date input bound to a [`NaiveDate`]
+#[component]
+pub fn DateInput(props: DateInputProps) -> Element {
+    temporal_input(props)
+}
+
+/// A native time input bound to a [`NaiveTime`]
+#[component]
+pub fn TimeInput(props: TimeInputProps) -> Element {
+    temporal_input(props)
+}
+
+/// A native `datetime-local` input bound to a [`NaiveDateTime`]
+#[component]
+pub fn DateTimeInput(props: DateTimeInputProps) -> Element {
+    temporal_input(props)
+}
+
+fn temporal_input<T: Temporal>(mut props: TemporalInputProps<T>) -> Element {
+    let default_classes = "input";
+    crate::setup_class_attribute(&mut props.attributes, default_classes);
+
+    let mut input = use_typed_input(props.value, props.default_value, T::format_input);
+    input.field.setup_attributes(&mut props.attributes);
+
+    let (min, max) = (props.min.clone(), props.max.clone());
+    let parse = move |text: &str| -> Result<Option<T>, String> {
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        let value = T::parse_input(text).ok_or_else(|| T::PARSE_ERROR.to_string())?;
+        match range_error(&Some(value.clone()), &min, &max, T::format_input) {
+            Some(error) => Err(error),
+            None => Ok(Some(value)),
+        }
+    };
+
+    let text = input.text(T::format_input);
+    if parse(&text).is_err() && input.field.name().is_none() {
+        props
+            .attributes
+            .push(Attribute::new("aria-invalid", "true", None, false));
+    }
+
+    let onblur = {
+        let field = input.field.clone();
+        move |_| field.touch()
+    };
+
+    let oninput = move |event: FormEvent| {
+        let text = event.data.value();
+        let parsed = parse(&text);
+        if input.set_text(text, parsed).is_none() {
+            props.onchange.call(input.value.peek().clone());
+        }
+    };
+
+    rsx! {
+        input {
+            r#type: T::INPUT_TYPE,
+            min: props.min.as_ref().map(T::format_input),
+            max: props.max.as_ref().map(T::format_input),
+            value: text,
+            oninput,
+            onblur,
+            ..props.attributes,
+        }
+    }
+}
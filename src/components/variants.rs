@@ -0,0 +1,121 @@
+//! Typed values of the `data-*` attributes shared by several components.
+//!
+//! Components take them as optional props, leaving the attribute unset (and the base style)
+//! when they are not given.
+
+/// Color of a component, rendered as `data-style`.
+/// Components that do not style a color fall back to their base style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Primary,
+    Secondary,
+    Accent,
+    Muted,
+    Destructive,
+    Success,
+}
+
+impl Color {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Color::Primary => "primary",
+            Color::Secondary => "secondary",
+            Color::Accent => "accent",
+            Color::Muted => "muted",
+            Color::Destructive => "destructive",
+            Color::Success => "success",
+        }
+    }
+}
+
+/// Size of a component, rendered as `data-size`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Size {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Size {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Size::Xs => "xs",
+            Size::Sm => "sm",
+            Size::Md => "md",
+            Size::Lg => "lg",
+            Size::Xl => "xl",
+        }
+    }
+}
+
+/// Transitions of a component, rendered as `data-animation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Animation {
+    None,
+    Light,
+    Full,
+}
+
+impl Animation {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Animation::None => "none",
+            Animation::Light => "light",
+            Animation::Full => "full",
+        }
+    }
+}
+
+/// Rendered as `data-orientation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        }
+    }
+}
+
+/// What the counter of a text field counts, rendered as `data-counter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counter {
+    /// Counted like the browser does for `maxlength`, which the counter shows when set
+    Characters,
+    Words,
+}
+
+impl Counter {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Counter::Characters => "characters",
+            Counter::Words => "words",
+        }
+    }
+
+    pub fn count(&self, text: &str) -> usize {
+        match self {
+            // `maxlength` counts UTF-16 code units
+            Counter::Characters => text.encode_utf16().count(),
+            Counter::Words => text.split_whitespace().count(),
+        }
+    }
+
+    /// `12 / 100` characters, or `3 words`
+    pub fn format(&self, text: &str, max_length: Option<usize>) -> String {
+        let count = self.count(text);
+        match (self, max_length) {
+            (Counter::Characters, Some(max)) => format!("{count} / {max}"),
+            (Counter::Characters, None) => count.to_string(),
+            (Counter::Words, _) if count == 1 => "1 word".to_string(),
+            (Counter::Words, _) => format!("{count} words"),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Helpers shared by the components.
+// A build with only some of the components leaves the helpers of the others unused
+#![cfg_attr(not(feature = "all-components"), allow(dead_code))]
+
+use dioxus::dioxus_core::{Attribute, AttributeValue};
+use dioxus::prelude::{Signal, use_signal};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub(crate) fn setup_class_attribute(attributes: &mut Vec<Attribute>, default_classes: &str) {
+    // Find the class attribute in the vec and modify it
+    if let Some(class_attribute) = attributes.iter_mut().find(|attr| attr.name == "class") {
+        if let AttributeValue::Text(ref mut value) = class_attribute.value {
+            // User classes replace each other, but never the component's own classes,
+            // which may look like utilities: `table` is also a display
+            let merged = crate::class_merge::tw_merge([value.as_str()]);
+            let defaults: Vec<&str> = default_classes.split_whitespace().collect();
+            let user = merged
+                .split_whitespace()
+                .filter(|class| !defaults.contains(class));
+            *value = defaults
+                .iter()
+                .copied()
+                .chain(user)
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+    } else {
+        // Else push the class attribute in the vec
+        attributes.push(Attribute::new("class", default_classes, None, true));
+    }
+}
+
+/// Sets a `data-*` attribute from a typed prop, replacing the raw attribute of the same name.
+/// Leaves the attributes untouched when the prop is not given.
+pub(crate) fn setup_data_attribute(
+    attributes: &mut Vec<Attribute>,
+    name: &'static str,
+    value: Option<&'static str>,
+) {
+    if let Some(value) = value {
+        attributes.retain(|attr| attr.name != name);
+        attributes.push(Attribute::new(name, value, None, true));
+    }
+}
+
+/// Text of a raw attribute, like `type` or `maxlength`
+pub(crate) fn attribute_text(attributes: &[Attribute], name: &str) -> Option<String> {
+    attributes
+        .iter()
+        .find(|attr| attr.name == name)
+        .and_then(|attr| match &attr.value {
+            AttributeValue::Text(value) => Some(value.clone()),
+            AttributeValue::Int(value) => Some(value.to_string()),
+            AttributeValue::Float(value) => Some(value.to_string()),
+            _ => None,
+        })
+}
+
+/// Signal holding the value of an input: the `value` prop when controlled, or one starting
+/// from `default` when uncontrolled. Writing to a controlled signal re-renders the input.
+pub(crate) fn use_controlled<T: 'static>(
+    value: Option<Signal<T>>,
+    default: impl FnOnce() -> T,
+) -> Signal<T> {
+    let uncontrolled = use_signal(default);
+    value.unwrap_or(uncontrolled)
+}
+
+/// Stand-in for the form binding of the inputs when the `form` feature is disabled,
+/// which otherwise exports the real one
+#[cfg(not(feature = "form"))]
+#[derive(Clone, PartialEq)]
+pub(crate) struct FieldBinding;
+
+#[cfg(not(feature = "form"))]
+impl FieldBinding {
+    pub(crate) fn value_or(&self, _controlled: bool, default: String) -> String {
+        default
+    }
+
+    pub(crate) fn use_controlled_value(
+        &self,
+        _controlled: bool,
+        _value: impl Fn() -> String + 'static,
+    ) {
+    }
+
+    pub(crate) fn set_value(&self, _value: impl Into<String>) {}
+
+    pub(crate) fn set_parsed_value(&self, _value: impl Into<String>, _parse_error: Option<String>) {
+    }
+
+    pub(crate) fn touch(&self) {}
+
+    pub(crate) fn name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn is_invalid(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn setup_attributes(&self, _attributes: &mut Vec<Attribute>) {}
+}
+
+#[cfg(not(feature = "form"))]
+pub(crate) fn use_field_binding(_initial: impl FnOnce() -> String) -> FieldBinding {
+    FieldBinding
+}
+
+const ID_PREFIX: &str = "dx42-";
+static UNIQUE_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn use_unique_id() -> String {
+    format!(
+        "{}{}",
+        ID_PREFIX,
+        UNIQUE_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Cross-platform async sleep using dioxus-sdk-time.
+#[cfg(any(feature = "carousel", feature = "toast"))]
+pub(crate) async fn sleep_ms(ms: u64) {
+    dioxus_sdk_time::sleep(std::time::Duration::from_millis(ms)).await;
+}
@@ -1,71 +1,86 @@
 pub mod bootstrap;
 pub use bootstrap::*;
 
+pub mod class_merge;
+
 pub mod components;
+#[cfg(feature = "accordion")]
 pub use components::accordion::*;
+#[cfg(feature = "button")]
 pub use components::button::*;
+#[cfg(feature = "buttongroup")]
 pub use components::buttongroup::*;
+#[cfg(feature = "calendar")]
+pub use components::calendar::*;
+#[cfg(feature = "callout")]
 pub use components::callout::*;
+#[cfg(feature = "carousel")]
 pub use components::carousel::*;
+#[cfg(feature = "checkbox")]
 pub use components::checkbox::*;
+#[cfg(feature = "combobox")]
+pub use components::combobox::*;
+#[cfg(feature = "dropdown")]
 pub use components::dropdown::*;
+#[cfg(feature = "dropzone")]
+pub use components::dropzone::*;
+#[cfg(feature = "form")]
+pub use components::form::*;
+#[cfg(feature = "formlist")]
 pub use components::formlist::*;
+#[cfg(feature = "hovercard")]
 pub use components::hovercard::*;
+#[cfg(feature = "icon")]
 pub use components::icon::*;
+#[cfg(feature = "input")]
 pub use components::input::*;
+#[cfg(feature = "lightswitch")]
 pub use components::lightswitch::*;
+#[cfg(feature = "markdown")]
 pub use components::markdown::*;
+#[cfg(feature = "modal")]
 pub use components::modal::*;
+#[cfg(feature = "modelform")]
+pub use components::modelform::*;
+#[cfg(feature = "pagination")]
 pub use components::pagination::*;
+#[cfg(feature = "placeholder")]
 pub use components::placeholder::*;
+#[cfg(feature = "progressbar")]
 pub use components::progressbar::*;
+#[cfg(feature = "radio")]
 pub use components::radio::*;
+#[cfg(feature = "scrollable")]
 pub use components::scrollable::*;
+#[cfg(feature = "select")]
 pub use components::select::*;
+#[cfg(feature = "separator")]
 pub use components::separator::*;
+#[cfg(feature = "sidepanel")]
 pub use components::sidepanel::*;
+#[cfg(feature = "slider")]
 pub use components::slider::*;
+#[cfg(feature = "sorttable")]
 pub use components::sorttable::*;
+#[cfg(feature = "table")]
 pub use components::table::*;
+#[cfg(feature = "tabs")]
 pub use components::tabs::*;
+#[cfg(feature = "textarea")]
 pub use components::textarea::*;
+#[cfg(feature = "toast")]
 pub use components::toast::*;
+#[cfg(feature = "toggle")]
 pub use components::toggle::*;
+#[cfg(feature = "typedinput")]
+pub use components::typedinput::*;
+pub use components::variants::*;
 pub mod prelude;
 
 #[cfg(feature = "theme")]
 pub mod theme;
 
-use dioxus::dioxus_core::{Attribute, AttributeValue};
-use dioxus::prelude::*;
-use std::time::Duration;
-
-pub(crate) fn setup_class_attribute(attributes: &mut Vec<Attribute>, default_classes: &str) {
-    // Find the class attribute in the vec and modify it
-    if let Some(class_attribute) = attributes.iter_mut().find(|attr| attr.name == "class") {
-        if let AttributeValue::Text(ref mut value) = class_attribute.value {
-            *value = format!("{} {}", default_classes, value.clone());
-        }
-    } else {
-        // Else push the class attribute in the vec
-        attributes.push(Attribute::new("class", default_classes, None, true));
-    }
-}
-
-use std::sync::atomic::AtomicUsize;
-
-const ID_PREFIX: &str = "dx42-";
-static UNIQUE_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
-
-/// Cross-platform async sleep using dioxus-sdk-time.
-pub(crate) async fn sleep_ms(ms: u64) {
-    dioxus_sdk_time::sleep(Duration::from_millis(ms)).await;
-}
-
-pub(crate) fn use_unique_id() -> String {
-    format!(
-        "{}{}",
-        ID_PREFIX,
-        UNIQUE_ID_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-    )
-}
+mod helpers;
+// Unused when no component is enabled
+#[cfg_attr(not(feature = "all-components"), allow(unused_imports))]
+pub(crate) use helpers::*;
@@ -1,33 +1,75 @@
 pub use crate::bootstrap::*;
+#[cfg(feature = "accordion")]
 pub use crate::components::accordion::*;
+#[cfg(feature = "button")]
 pub use crate::components::button::*;
+#[cfg(feature = "buttongroup")]
 pub use crate::components::buttongroup::*;
+#[cfg(feature = "calendar")]
+pub use crate::components::calendar::*;
+#[cfg(feature = "callout")]
 pub use crate::components::callout::*;
+#[cfg(feature = "carousel")]
 pub use crate::components::carousel::*;
+#[cfg(feature = "checkbox")]
 pub use crate::components::checkbox::*;
+#[cfg(feature = "combobox")]
+pub use crate::components::combobox::*;
+#[cfg(feature = "dropdown")]
 pub use crate::components::dropdown::*;
+#[cfg(feature = "dropzone")]
+pub use crate::components::dropzone::*;
+#[cfg(feature = "form")]
+pub use crate::components::form::*;
+#[cfg(feature = "formlist")]
 pub use crate::components::formlist::*;
+#[cfg(feature = "hovercard")]
 pub use crate::components::hovercard::*;
+#[cfg(feature = "icon")]
 pub use crate::components::icon::*;
+#[cfg(feature = "input")]
 pub use crate::components::input::*;
+#[cfg(feature = "lightswitch")]
 pub use crate::components::lightswitch::*;
+#[cfg(feature = "markdown")]
 pub use crate::components::markdown::*;
+#[cfg(feature = "modal")]
 pub use crate::components::modal::*;
+#[cfg(feature = "modelform")]
+pub use crate::components::modelform::*;
+#[cfg(feature = "pagination")]
 pub use crate::components::pagination::*;
+#[cfg(feature = "placeholder")]
 pub use crate::components::placeholder::*;
+#[cfg(feature = "progressbar")]
 pub use crate::components::progressbar::*;
+#[cfg(feature = "radio")]
 pub use crate::components::radio::*;
+#[cfg(feature = "scrollable")]
 pub use crate::components::scrollable::*;
+#[cfg(feature = "select")]
 pub use crate::components::select::*;
+#[cfg(feature = "separator")]
 pub use crate::components::separator::*;
+#[cfg(feature = "sidepanel")]
 pub use crate::components::sidepanel::*;
+#[cfg(feature = "slider")]
 pub use crate::components::slider::*;
+#[cfg(feature = "sorttable")]
 pub use crate::components::sorttable::*;
+#[cfg(feature = "table")]
 pub use crate::components::table::*;
+#[cfg(feature = "tabs")]
 pub use crate::components::tabs::*;
+#[cfg(feature = "textarea")]
 pub use crate::components::textarea::*;
+#[cfg(feature = "toast")]
 pub use crate::components::toast::*;
+#[cfg(feature = "toggle")]
 pub use crate::components::toggle::*;
+#[cfg(feature = "typedinput")]
+pub use crate::components::typedinput::*;
+pub use crate::components::variants::*;
 
 #[cfg(feature = "theme")]
 pub use crate::theme::*;
@@ -3,6 +3,35 @@ use dioxus::prelude::*;
 
 #[component]
 pub fn ThemePicker() -> Element {
+    let mut theme_manager = use_context::<Signal<ThemeManager>>();
+
+    // Edits are made on a draft, and only reach the live theme once applied
+    let mut draft =
+        use_context_provider(|| Signal::new(ThemeDraft::new(theme_manager.peek().clone())));
+
+    // Edits the theme displayed, whichever ThemeSwitch changed it
+    let resolved = try_use_resolved_theme();
+    use_effect(use_reactive!(|resolved| {
+        let Some(resolved) = resolved else {
+            return;
+        };
+        let name = if resolved.is_dark() { "dark" } else { "root" };
+        let index = theme_manager
+            .peek()
+            .themes
+            .iter()
+            .position(|theme| theme.name == name);
+        if let Some(index) = index.filter(|index| *index != theme_manager.peek().current_theme) {
+            theme_manager.write().current_theme = index;
+        }
+    }));
+    use_effect(move || {
+        let current_theme = theme_manager.read().current_theme;
+        if draft.peek().present().current_theme != current_theme {
+            draft.write().set_current_theme(current_theme);
+        }
+    });
+
     rsx! {
         SidePanel {
             MiniPicker {}
@@ -10,7 +39,10 @@ pub fn ThemePicker() -> Element {
             SidePanelContent {
                 class: "theme-picker",
                 "data-side": "right",
-                ColorPicker {}
+                div { class: "theme-picker-body",
+                    ColorPicker {}
+                    ThemePreview {}
+                }
             }
         }
     }
@@ -20,10 +52,12 @@ pub fn ThemePicker() -> Element {
 fn ColorPicker() -> Element {
     let selected_color = use_signal(|| String::from("primary"));
 
-    let mut theme_manager = use_context::<Signal<ThemeManager>>();
+    let mut draft = use_context::<Signal<ThemeDraft>>();
 
-    let current_theme = theme_manager.read().current_theme;
-    let colors = theme_manager.read().themes[current_theme].colors.clone();
+    let current_theme = draft.read().present().current_theme;
+    let theme = draft.read().present().themes[current_theme].clone();
+    let draft_style = theme.to_style();
+    let audit = theme.audit();
 
     let onchange = move |event: FormEvent| {
         // TODO Very ugly but works
@@ -42,29 +76,35 @@ fn ColorPicker() -> Element {
             selected_color.read().to_string()
         };
 
-        // Get the current selected color in the theme manager (as mut ref)
-        if let Some(color_choice) = theme_manager.write().themes[current_theme]
-            .colors
-            .get_mut(&select_color)
-        {
+        let selected = selected_color.read().clone();
+
+        // Get the current selected color in the draft (as mut ref)
+        draft.write().edit(&selected, |theme_manager| {
+            let Some(color_choice) = theme_manager.themes[current_theme]
+                .colors
+                .get_mut(&select_color)
+            else {
+                return;
+            };
+
             match color_choice {
                 ColorChoice::Simple(color) => {
                     *color = hsl_color;
                 }
                 ColorChoice::Duo(color_bg, color_fg) => {
-                    if select_color == "background" && *selected_color.read() == "foreground" {
+                    if select_color == "background" && selected == "foreground" {
                         *color_fg = hsl_color;
                         return;
                     }
 
-                    if selected_color.read().contains("foreground") {
+                    if selected.contains("foreground") {
                         *color_fg = hsl_color;
                     } else {
                         *color_bg = hsl_color;
                     }
                 }
             }
-        }
+        });
     };
 
     rsx! {
@@ -77,11 +117,16 @@ fn ColorPicker() -> Element {
                 style: "visibility: hidden;",
                 onchange,
             }
-            for (str , color) in colors.into_iter() {
-                ColorSelector {
-                    color_str: str,
-                    color: color.clone(),
-                    selected_color,
+            HistoryControls {}
+            div { class: "theme-picker-colors", style: "{draft_style}",
+                for (str , color) in theme.colors.into_iter() {
+                    ColorSelector {
+                        issue: audit.get(&str).cloned(),
+                        modified: draft.read().present().is_color_modified(&str),
+                        color_str: str,
+                        color: color.clone(),
+                        selected_color,
+                    }
                 }
             }
             RadiusSelector {}
@@ -95,7 +140,11 @@ fn ColorSelector(
     color_str: ReadSignal<String>,
     color: ColorChoice,
     mut selected_color: Signal<String>,
+    issue: Option<ContrastIssue>,
+    modified: bool,
 ) -> Element {
+    let mut draft = use_context::<Signal<ThemeDraft>>();
+
     let content: Element = match color {
         ColorChoice::Simple(_) => {
             rsx! {
@@ -167,6 +216,9 @@ fn ColorSelector(
                     },
                     Icon { icon: Icons::FlipToBack }
                 }
+                if let Some(issue) = issue {
+                    ContrastWarning { issue }
+                }
             }
         }
     };
@@ -174,6 +226,60 @@ fn ColorSelector(
     rsx! {
         div { class: "theme-picker-color-selector",
             {content}
+            if modified {
+                button {
+                    r#type: "button",
+                    class: "theme-reset-button",
+                    title: "Reset to default",
+                    onclick: move |_| draft.write().reset_color(&color_str.read()),
+                    Icon { icon: Icons::Restore }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ContrastWarning(issue: ContrastIssue) -> Element {
+    let mut draft = use_context::<Signal<ThemeDraft>>();
+
+    let title = format!(
+        "Contrast {:.2}:1 fails WCAG {} ({}:1). Click to use {} as foreground.",
+        issue.ratio,
+        issue.level.as_str(),
+        issue.level.min_ratio(),
+        issue.suggestion.to_hex()
+    );
+
+    let icon = match issue.level {
+        WcagLevel::Aa => Icons::Warning,
+        WcagLevel::Aaa => Icons::Info,
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "theme-contrast-warning",
+            "data-level": issue.level.as_str(),
+            title,
+            onclick: move |_| {
+                draft
+                    .write()
+                    .edit(
+                        &format!("contrast-{}", issue.key),
+                        |theme_manager| {
+                            let current_theme = theme_manager.current_theme;
+                            if let Some(ColorChoice::Duo(_, foreground)) = theme_manager.themes[current_theme]
+                                .colors
+                                .get_mut(&issue.key)
+                            {
+                                *foreground = issue.suggestion.clone();
+                            }
+                        },
+                    );
+            },
+            Icon { icon }
+            span { "{issue.ratio:.1}" }
         }
     }
 }
@@ -199,51 +305,155 @@ fn ToggleDiv(is_selected: bool, onclick: EventHandler<MouseEvent>, children: Ele
 
 #[component]
 fn RadiusSelector() -> Element {
-    let mut theme_manager = use_context::<Signal<ThemeManager>>();
+    let mut draft = use_context::<Signal<ThemeDraft>>();
+
+    let current_theme = draft.read().present().current_theme;
 
-    let current_theme = theme_manager.read().current_theme;
+    let draft_radius = move || {
+        let draft = draft.read();
+        let present = draft.present();
+        present.themes[present.current_theme].radius.to_style()
+    };
+    // Follows undo, redo and resets of the draft
+    let mut radius = use_signal(draft_radius);
+    use_effect(move || radius.set(draft_radius()));
 
     rsx! {
         div { id: "radius-selector", class: "w-full",
             p { style: "font-size: 0.875rem; font-weight: 700;", "Radius" }
-            Input {
-                "data-size": "sm",
-                r#type: "text",
-                default_value: theme_manager.read().themes[current_theme].radius.to_style(),
-                onchange: move |event: FormEvent| {
-                    let value = event.data().value();
-                    theme_manager.write().themes[current_theme].radius = RadiusCss(value);
-                },
+            div { style: "display: flex; align-items: center;",
+                Input {
+                    size: Size::Sm,
+                    r#type: "text",
+                    value: radius,
+                    onchange: move |event: FormEvent| {
+                        let value = event.data().value();
+                        draft
+                            .write()
+                            .edit(
+                                "radius",
+                                |theme_manager| {
+                                    theme_manager.themes[current_theme].radius = RadiusCss(value);
+                                },
+                            );
+                    },
+                }
+                button {
+                    r#type: "button",
+                    class: "theme-reset-button",
+                    title: "Reset to default",
+                    onclick: move |_| draft.write().reset_radius(),
+                    Icon { icon: Icons::Restore }
+                }
             }
         }
     }
 }
 
 #[component]
-fn MiniPicker() -> Element {
+fn HistoryControls() -> Element {
+    let mut draft = use_context::<Signal<ThemeDraft>>();
     let mut theme_manager = use_context::<Signal<ThemeManager>>();
 
-    let current_theme = theme_manager.read().current_theme;
+    let is_dirty = draft.read().is_dirty(&theme_manager.read());
 
     rsx! {
-        div { style: "display: flex; flex-direction: row; padding: 0.5rem; align-items: center;",
-            SidePanelTrigger { class: "theme-minipicker-trigger",
-                Icon { icon: Icons::Palette }
+        div { class: "theme-history-controls",
+            button {
+                r#type: "button",
+                class: "theme-history-button",
+                title: "Undo",
+                disabled: !draft.read().can_undo(),
+                onclick: move |_| {
+                    draft.write().undo();
+                },
+                Icon { icon: Icons::Undo }
             }
-            LightSwitch {
-                class: "theme-minipicker-lightswitch",
+            button {
+                r#type: "button",
+                class: "theme-history-button",
+                title: "Redo",
+                disabled: !draft.read().can_redo(),
                 onclick: move |_| {
-                    if current_theme == 0 {
-                        theme_manager.write().current_theme = 1
-                    } else {
-                        theme_manager.write().current_theme = 0
-                    }
+                    draft.write().redo();
                 },
+                Icon { icon: Icons::Redo }
+            }
+            button {
+                r#type: "button",
+                class: "theme-history-button",
+                title: "Reset theme to default",
+                onclick: move |_| draft.write().reset_theme(),
+                Icon { icon: Icons::RestartAlt }
+            }
+            Button {
+                size: Size::Sm,
+                variant: ButtonVariant::Outline,
+                disabled: !is_dirty,
+                onclick: move |_| draft.write().discard(&theme_manager.read()),
+                "Discard"
+            }
+            Button {
+                size: Size::Sm,
+                color: Color::Primary,
+                disabled: !is_dirty,
+                onclick: move |_| draft.read().commit(&mut theme_manager.write()),
+                "Apply"
             }
         }
     }
 }
 
+#[component]
+fn ThemePreview() -> Element {
+    let draft = use_context::<Signal<ThemeDraft>>();
+
+    let current_theme = draft.read().present().current_theme;
+    let style = draft.read().present().themes[current_theme].to_style();
+
+    rsx! {
+        div { class: "theme-preview", style: "{style}",
+            p { style: "font-size: 0.875rem; font-weight: 700;", "Preview" }
+            div { class: "theme-preview-row",
+                for color in [Color::Primary, Color::Secondary, Color::Accent, Color::Destructive, Color::Success] {
+                    Button { size: Size::Sm, color, {color.as_str()} }
+                }
+            }
+            div { class: "theme-preview-row",
+                Button { size: Size::Sm, color: Color::Primary, variant: ButtonVariant::Outline, "Outline" }
+                Button { size: Size::Sm, color: Color::Primary, variant: ButtonVariant::Ghost, "Ghost" }
+                Button { size: Size::Sm, disabled: true, "Disabled" }
+            }
+            Input { placeholder: "Input" }
+            Callout {
+                title: "Callout",
+                variant: CalloutVariant::Note,
+                "Callouts use the muted text color."
+            }
+            div { class: "toast", "data-style": "default",
+                h6 { class: "h6", "Toast" }
+                "A default toast."
+            }
+            div { class: "toast", "data-style": "success",
+                h6 { class: "h6", "Success" }
+                "A success toast."
+            }
+        }
+    }
+}
+
+#[component]
+fn MiniPicker() -> Element {
+    rsx! {
+        div { style: "display: flex; flex-direction: row; padding: 0.5rem; align-items: center;",
+            SidePanelTrigger { class: "theme-minipicker-trigger",
+                Icon { icon: Icons::Palette }
+            }
+            LightSwitch { class: "theme-minipicker-lightswitch" }
+        }
+    }
+}
+
 #[component]
 fn ButtonExport() -> Element {
     rsx! {
@@ -253,24 +463,35 @@ fn ButtonExport() -> Element {
             ModalContent {
                 ModalClose {}
                 h6 { class: "h6", "Theme" }
-                p { style: "font-size: 0.875rem; font-widht: 700; color: color-mix(in oklab, var(--foreground) 50%, transparent); padding-bottom: 1rem;",
-                    "Copy and paste this in your project's CSS file."
+                Tabs { default_tab: ExportFormat::default().as_str(),
+                    TabsList {
+                        for format in ExportFormat::ALL {
+                            TabsTrigger { id: format.as_str(), "{format.label()}" }
+                        }
+                    }
+                    for format in ExportFormat::ALL {
+                        TabsContent { id: format.as_str(),
+                            p { style: "font-size: 0.875rem; font-widht: 700; color: color-mix(in oklab, var(--foreground) 50%, transparent); padding-bottom: 1rem;",
+                                "{format.hint()}"
+                            }
+                            ThemeExport { format }
+                        }
+                    }
                 }
-                ThemeExport {}
             }
         }
     }
 }
 
 #[component]
-fn ThemeExport() -> Element {
-    let theme_manager = use_context::<Signal<ThemeManager>>();
+fn ThemeExport(format: ExportFormat) -> Element {
+    let draft = use_context::<Signal<ThemeDraft>>();
 
     rsx! {
         Scrollable {
             style: "max-height: 20rem; border: none; background-color: var(--foreground);",
             pre { style: "background-color: var(--foreground); color: var(--background); padding-left: 1rem; padding-right: 3rem; padding-top: 0.5rem; padding-bottom: 0.5rem; border-radius: var(--radius)",
-                code { style: "font-size: 0.875rem;", "{theme_manager.read().export_to_css()}" }
+                code { style: "font-size: 0.875rem;", "{format.export(draft.read().present())}" }
             }
         }
     }
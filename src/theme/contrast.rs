@@ -0,0 +1,174 @@
+use super::{ColorChoice, HslColor, Theme};
+
+/// WCAG 2.x conformance level for text contrast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WcagLevel {
+    /// At least 4.5:1 for normal text.
+    Aa,
+    /// At least 7:1 for normal text.
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Minimum contrast ratio required by this level.
+    pub const fn min_ratio(&self) -> f64 {
+        match self {
+            WcagLevel::Aa => 4.5,
+            WcagLevel::Aaa => 7.0,
+        }
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            WcagLevel::Aa => "AA",
+            WcagLevel::Aaa => "AAA",
+        }
+    }
+}
+
+impl HslColor {
+    /// Relative luminance as defined by WCAG, from 0.0 (black) to 1.0 (white).
+    pub fn relative_luminance(&self) -> f64 {
+        let linearize = |c: f64| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        let (r, g, b) = self.to_rgb();
+
+        0.2126 * linearize(r) + 0.7152 * linearize(g) + 0.0722 * linearize(b)
+    }
+
+    /// Contrast ratio between two colors, from 1.0 (identical) to 21.0 (black on white).
+    pub fn contrast_ratio(&self, other: &HslColor) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns true if this color used as text on `background` meets `level`.
+    pub fn meets(&self, background: &HslColor, level: WcagLevel) -> bool {
+        self.contrast_ratio(background) >= level.min_ratio()
+    }
+
+    /// Returns the closest color (same hue and saturation, lightness changed as little
+    /// as possible) that reaches `level` on `background`.
+    /// Falls back to black or white when no lightness satisfies the level.
+    pub fn suggest_for(&self, background: &HslColor, level: WcagLevel) -> HslColor {
+        if self.meets(background, level) {
+            return self.clone();
+        }
+
+        const STEP: f64 = 0.5;
+
+        let with_lightness = |l: f64| HslColor {
+            h: self.h,
+            s: self.s,
+            l,
+        };
+
+        let mut offset = STEP;
+        while offset <= 100.0 {
+            for l in [self.l - offset, self.l + offset] {
+                if !(0.0..=100.0).contains(&l) {
+                    continue;
+                }
+                let candidate = with_lightness(l);
+                if candidate.meets(background, level) {
+                    return candidate;
+                }
+            }
+            offset += STEP;
+        }
+
+        let black = with_lightness(0.0);
+        let white = with_lightness(100.0);
+        if black.contrast_ratio(background) >= white.contrast_ratio(background) {
+            black
+        } else {
+            white
+        }
+    }
+}
+
+impl ColorChoice {
+    /// Contrast ratio between the foreground and the background of a `Duo`.
+    /// Returns None for `Simple` colors, which have no pair to compare.
+    pub fn contrast_ratio(&self) -> Option<f64> {
+        match self {
+            ColorChoice::Simple(_) => None,
+            ColorChoice::Duo(background, foreground) => Some(foreground.contrast_ratio(background)),
+        }
+    }
+}
+
+/// A foreground/background pair that does not meet a WCAG level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    /// Name of the color pair in the theme (e.g. "primary").
+    pub key: String,
+    pub ratio: f64,
+    /// Lowest level the pair fails to meet.
+    pub level: WcagLevel,
+    /// Foreground color that would meet `level` on the current background.
+    pub suggestion: HslColor,
+}
+
+/// Result of [`Theme::audit`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContrastAudit {
+    pub issues: Vec<ContrastIssue>,
+}
+
+impl ContrastAudit {
+    /// Returns true if every pair meets AAA.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Pairs that fail to meet `level`.
+    pub fn failing(&self, level: WcagLevel) -> impl Iterator<Item = &ContrastIssue> {
+        self.issues.iter().filter(move |issue| issue.level <= level)
+    }
+
+    /// Returns the issue reported for the color pair `key`, if any.
+    pub fn get(&self, key: &str) -> Option<&ContrastIssue> {
+        self.issues.iter().find(|issue| issue.key == key)
+    }
+}
+
+impl Theme {
+    /// Checks every `ColorChoice::Duo` of the theme against WCAG AA and AAA.
+    /// Issues are listed in theme order.
+    pub fn audit(&self) -> ContrastAudit {
+        let mut issues = Vec::new();
+
+        for (key, color_choice) in self.colors.iter() {
+            let ColorChoice::Duo(background, foreground) = color_choice else {
+                continue;
+            };
+
+            let ratio = foreground.contrast_ratio(background);
+            let level = if ratio < WcagLevel::Aa.min_ratio() {
+                WcagLevel::Aa
+            } else if ratio < WcagLevel::Aaa.min_ratio() {
+                WcagLevel::Aaa
+            } else {
+                continue;
+            };
+
+            issues.push(ContrastIssue {
+                key: key.clone(),
+                ratio,
+                level,
+                suggestion: foreground.suggest_for(background, level),
+            });
+        }
+
+        ContrastAudit { issues }
+    }
+}
@@ -0,0 +1,127 @@
+use super::ThemeManager;
+use std::collections::VecDeque;
+
+/// Maximum number of edits kept in the undo history.
+const HISTORY_DEPTH: usize = 100;
+
+/// A copy of a [`ThemeManager`] being edited, with undo/redo history.
+/// Edits only reach the live theme once the draft is committed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeDraft {
+    present: ThemeManager,
+    undo_stack: VecDeque<ThemeManager>,
+    redo_stack: Vec<ThemeManager>,
+    last_edit: Option<String>,
+}
+
+impl ThemeDraft {
+    pub fn new(theme_manager: ThemeManager) -> Self {
+        Self {
+            present: theme_manager,
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            last_edit: None,
+        }
+    }
+
+    /// The draft theme manager, with every edit applied.
+    pub fn present(&self) -> &ThemeManager {
+        &self.present
+    }
+
+    /// Applies `edit` to the draft and records it in the history.
+    /// Consecutive edits sharing the same `key` (e.g. dragging a color picker)
+    /// are merged into a single history entry.
+    pub fn edit(&mut self, key: &str, edit: impl FnOnce(&mut ThemeManager)) {
+        let mut next = self.present.clone();
+        edit(&mut next);
+
+        if next == self.present {
+            return;
+        }
+
+        if self.last_edit.as_deref() != Some(key) {
+            self.undo_stack.push_back(self.present.clone());
+            if self.undo_stack.len() > HISTORY_DEPTH {
+                self.undo_stack.pop_front();
+            }
+        }
+
+        self.present = next;
+        self.redo_stack.clear();
+        self.last_edit = Some(key.to_string());
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Reverts the last edit. Returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(mut previous) = self.undo_stack.pop_back() else {
+            return false;
+        };
+
+        // Switching between themes is not an edit, keep the one currently displayed
+        previous.current_theme = self.present.current_theme;
+        self.redo_stack
+            .push(std::mem::replace(&mut self.present, previous));
+        self.last_edit = None;
+        true
+    }
+
+    /// Re-applies the last undone edit. Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(mut next) = self.redo_stack.pop() else {
+            return false;
+        };
+
+        next.current_theme = self.present.current_theme;
+        self.undo_stack
+            .push_back(std::mem::replace(&mut self.present, next));
+        self.last_edit = None;
+        true
+    }
+
+    /// Selects the theme being edited, without recording it in the history.
+    pub fn set_current_theme(&mut self, current_theme: usize) {
+        self.present.current_theme = current_theme;
+        self.last_edit = None;
+    }
+
+    pub fn reset_color(&mut self, key: &str) {
+        self.edit(&format!("reset-{key}"), |theme_manager| {
+            theme_manager.reset_color(key)
+        });
+        self.last_edit = None;
+    }
+
+    pub fn reset_radius(&mut self) {
+        self.edit("reset-radius", ThemeManager::reset_radius);
+        self.last_edit = None;
+    }
+
+    pub fn reset_theme(&mut self) {
+        self.edit("reset-theme", ThemeManager::reset_theme);
+        self.last_edit = None;
+    }
+
+    /// Returns true if the draft differs from `live`.
+    pub fn is_dirty(&self, live: &ThemeManager) -> bool {
+        self.present.themes != live.themes
+    }
+
+    /// Copies the draft themes into `live`, keeping its selected theme.
+    pub fn commit(&self, live: &mut ThemeManager) {
+        live.themes.clone_from(&self.present.themes);
+    }
+
+    /// Drops every edit and restarts from `live`.
+    pub fn discard(&mut self, live: &ThemeManager) {
+        *self = Self::new(live.clone());
+    }
+}
@@ -0,0 +1,296 @@
+use serde_json::{Map, Value, json};
+
+use super::{ExportToCss, Theme, ThemeManager, ToStyle, TokenScale};
+
+/// Export to a Tailwind v4 `@theme` block, to be pasted in `css/input.css`.
+pub trait ExportToTailwind {
+    fn export_to_tailwind(&self) -> String;
+}
+
+/// Export to a `tailwind.config.js` extending the Tailwind theme.
+pub trait ExportToTailwindConfig {
+    fn export_to_tailwind_config(&self) -> String;
+}
+
+/// Export to a W3C Design Tokens Community Group (DTCG) JSON document.
+pub trait ExportToDesignTokens {
+    fn export_to_design_tokens(&self) -> String;
+}
+
+/// Formats a theme can be exported to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Css,
+    Tailwind,
+    TailwindConfig,
+    DesignTokens,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 4] = [
+        ExportFormat::Css,
+        ExportFormat::Tailwind,
+        ExportFormat::TailwindConfig,
+        ExportFormat::DesignTokens,
+    ];
+
+    pub const fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Css => "CSS",
+            ExportFormat::Tailwind => "Tailwind v4",
+            ExportFormat::TailwindConfig => "tailwind.config.js",
+            ExportFormat::DesignTokens => "Design Tokens",
+        }
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Css => "css",
+            ExportFormat::Tailwind => "tailwind",
+            ExportFormat::TailwindConfig => "tailwind-config",
+            ExportFormat::DesignTokens => "design-tokens",
+        }
+    }
+
+    /// Where the exported content is meant to be pasted.
+    pub const fn hint(&self) -> &'static str {
+        match self {
+            ExportFormat::Css => "Copy and paste this in your project's CSS file.",
+            ExportFormat::Tailwind => "Copy and paste this in your Tailwind input CSS file.",
+            ExportFormat::TailwindConfig => "Use this as your tailwind.config.js.",
+            ExportFormat::DesignTokens => "Save this as a .tokens.json file.",
+        }
+    }
+
+    pub fn export(&self, theme_manager: &ThemeManager) -> String {
+        match self {
+            ExportFormat::Css => theme_manager.export_to_css(),
+            ExportFormat::Tailwind => theme_manager.export_to_tailwind(),
+            ExportFormat::TailwindConfig => theme_manager.export_to_tailwind_config(),
+            ExportFormat::DesignTokens => theme_manager.export_to_design_tokens(),
+        }
+    }
+}
+
+/// Whether Tailwind v4 has a theme namespace for the scale, generating utilities from it.
+/// Durations have none, they are kept as plain variables.
+fn has_tailwind_namespace(scale: &TokenScale) -> bool {
+    scale.prefix() != "duration"
+}
+
+impl ExportToTailwind for Theme {
+    fn export_to_tailwind(&self) -> String {
+        let mut css = String::from("@theme {\n");
+
+        for (key, color) in self.color_vars() {
+            css.push_str(&format!("  --color-{}: {};\n", key, color.to_style()));
+        }
+
+        css.push_str(&format!("  --radius: {};\n", self.radius.to_style()));
+        for scale in self.tokens.scales() {
+            if has_tailwind_namespace(scale) {
+                css.push_str(&scale.export_to_css());
+            }
+        }
+        css.push_str("}\n");
+
+        let variables: String = self
+            .tokens
+            .scales()
+            .into_iter()
+            .filter(|scale| !has_tailwind_namespace(scale))
+            .map(|scale| scale.export_to_css())
+            .collect();
+        if !variables.is_empty() {
+            css.push_str(&format!("\n:root {{\n{variables}}}\n"));
+        }
+
+        css
+    }
+}
+
+impl ExportToTailwind for ThemeManager {
+    /// Colors are mapped inline onto the theme variables so that switching theme
+    /// at runtime is picked up by Tailwind utilities.
+    /// Tokens are declared once by `@theme`, the other themes only override the ones they change.
+    fn export_to_tailwind(&self) -> String {
+        let Some(root) = self.themes.first() else {
+            return String::new();
+        };
+
+        let mut css = String::from("@theme inline {\n");
+        for (key, _) in root.color_vars() {
+            css.push_str(&format!("  --color-{key}: var(--{key});\n"));
+        }
+        css.push_str("}\n\n");
+
+        css.push_str("@theme {\n");
+        for scale in root.tokens.scales() {
+            if has_tailwind_namespace(scale) {
+                css.push_str(&scale.export_to_css());
+            }
+        }
+        css.push_str("}\n\n");
+
+        css.push_str("@layer base {\n\n");
+        for (index, theme) in self.themes.iter().enumerate() {
+            css.push_str(&format!(" {} {{\n", theme.selector()));
+            for (key, color) in theme.color_vars() {
+                css.push_str(&format!("  --{}: {};\n", key, color.to_style()));
+            }
+            css.push_str(&format!("  --radius: {};\n", theme.radius.to_style()));
+
+            for (scale, root_scale) in theme.tokens.scales().into_iter().zip(root.tokens.scales()) {
+                for (name, value) in scale.iter() {
+                    let is_declared = match index {
+                        0 => has_tailwind_namespace(scale),
+                        _ => root_scale.get(name) == Some(value),
+                    };
+                    if !is_declared {
+                        css.push_str(&format!("  --{}: {};\n", scale.var_name(name), value));
+                    }
+                }
+            }
+            css.push_str(" }\n\n");
+        }
+        css.push('}');
+
+        css
+    }
+}
+
+/// `tailwind.config.js` theme key matching a token scale.
+fn tailwind_config_key(scale: &TokenScale) -> &'static str {
+    match scale.prefix() {
+        "font" => "fontFamily",
+        "text" => "fontSize",
+        "shadow" => "boxShadow",
+        "radius" => "borderRadius",
+        "duration" => "transitionDuration",
+        _ => "spacing",
+    }
+}
+
+impl ExportToTailwindConfig for Theme {
+    fn export_to_tailwind_config(&self) -> String {
+        let mut js = String::from(
+            "/** @type {import('tailwindcss').Config} */\nmodule.exports = {\n\ttheme: {\n\t\textend: {\n",
+        );
+
+        js.push_str("\t\t\tcolors: {\n");
+        for (key, _) in self.color_vars() {
+            js.push_str(&format!("\t\t\t\t\"{key}\": 'var(--{key})',\n"));
+        }
+        js.push_str("\t\t\t},\n");
+
+        for scale in self.tokens.scales() {
+            js.push_str(&format!("\t\t\t{}: {{\n", tailwind_config_key(scale)));
+            if scale.prefix() == "radius" {
+                js.push_str("\t\t\t\t\"DEFAULT\": 'var(--radius)',\n");
+            }
+            for (name, _) in scale.iter() {
+                js.push_str(&format!(
+                    "\t\t\t\t\"{}\": 'var(--{})',\n",
+                    name,
+                    scale.var_name(name)
+                ));
+            }
+            js.push_str("\t\t\t},\n");
+        }
+
+        js.push_str("\t\t},\n\t},\n}\n");
+        js
+    }
+}
+
+impl ExportToTailwindConfig for ThemeManager {
+    fn export_to_tailwind_config(&self) -> String {
+        self.themes
+            .first()
+            .map(|theme| theme.export_to_tailwind_config())
+            .unwrap_or_default()
+    }
+}
+
+/// DTCG `$type` of the tokens of a scale, if the format defines one.
+fn design_token_type(scale: &TokenScale) -> Option<&'static str> {
+    match scale.prefix() {
+        "font" => Some("fontFamily"),
+        "text" | "spacing" | "radius" => Some("dimension"),
+        "duration" => Some("duration"),
+        _ => None,
+    }
+}
+
+impl Theme {
+    fn design_tokens(&self) -> Value {
+        let mut group = Map::new();
+
+        let mut colors = Map::new();
+        colors.insert("$type".to_string(), json!("color"));
+        for (key, color) in self.color_vars() {
+            colors.insert(key, json!({ "$value": color.to_hex() }));
+        }
+        group.insert("color".to_string(), Value::Object(colors));
+
+        group.insert(
+            "radius".to_string(),
+            json!({ "$type": "dimension", "$value": self.radius.to_style() }),
+        );
+
+        for scale in self.tokens.scales() {
+            let mut tokens = Map::new();
+            if let Some(token_type) = design_token_type(scale) {
+                tokens.insert("$type".to_string(), json!(token_type));
+            }
+            for (name, value) in scale.iter() {
+                let value = if scale.prefix() == "font" {
+                    // DTCG font families are arrays of names
+                    let families: Vec<&str> = value
+                        .split(',')
+                        .map(|family| family.trim().trim_matches(['"', '\'']))
+                        .filter(|family| !family.is_empty())
+                        .collect();
+                    json!(families)
+                } else if value == "var(--radius)" {
+                    // Component radii referencing the theme radius become DTCG aliases
+                    json!(format!("{{{}.radius}}", self.name))
+                } else {
+                    json!(value)
+                };
+                tokens.insert(name.to_string(), json!({ "$value": value }));
+            }
+
+            // The radius scale shares its group with the theme radius token
+            let key = if scale.prefix() == "radius" {
+                "component-radius"
+            } else {
+                scale.prefix()
+            };
+            group.insert(key.to_string(), Value::Object(tokens));
+        }
+
+        Value::Object(group)
+    }
+}
+
+impl ExportToDesignTokens for Theme {
+    fn export_to_design_tokens(&self) -> String {
+        let document = json!({ self.name.clone(): self.design_tokens() });
+
+        serde_json::to_string_pretty(&document).unwrap_or_default()
+    }
+}
+
+impl ExportToDesignTokens for ThemeManager {
+    fn export_to_design_tokens(&self) -> String {
+        let document: Map<String, Value> = self
+            .themes
+            .iter()
+            .map(|theme| (theme.name.clone(), theme.design_tokens()))
+            .collect();
+
+        serde_json::to_string_pretty(&document).unwrap_or_default()
+    }
+}
@@ -1,29 +1,80 @@
-use std::{collections::HashMap, error::Error};
+use std::error::Error;
 
 pub mod component;
+pub mod contrast;
+pub mod draft;
+pub mod export;
+pub mod tokens;
 
 pub use component::ThemePicker;
+pub use contrast::*;
+pub use draft::*;
+pub use export::*;
+pub use tokens::*;
 
 // TODO
 // Theme importer
 // Add Color like Primary, Secondary,...
 // Support for RGB colors
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ThemeManager {
     themes: Vec<Theme>,
+    /// Themes as they were created, used to reset edits
+    defaults: Vec<Theme>,
     pub current_theme: usize,
 }
 
 impl std::default::Default for ThemeManager {
     fn default() -> Self {
+        let themes = vec![Theme::default(), Theme::dark()];
+
         Self {
-            themes: vec![Theme::default(), Theme::dark()],
+            defaults: themes.clone(),
+            themes,
             current_theme: 1,
         }
     }
 }
 
+impl ThemeManager {
+    /// Restores the color `key` of the current theme to its default value.
+    pub fn reset_color(&mut self, key: &str) {
+        let Some(default) = self.defaults[self.current_theme].colors.get(key) else {
+            return;
+        };
+
+        self.themes[self.current_theme]
+            .colors
+            .insert(key.to_string(), default.clone());
+    }
+
+    /// Restores the radius of the current theme to its default value.
+    pub fn reset_radius(&mut self) {
+        self.themes[self.current_theme].radius = self.defaults[self.current_theme].radius.clone();
+    }
+
+    /// Restores every token of the current theme to its default value.
+    pub fn reset_theme(&mut self) {
+        self.themes[self.current_theme] = self.defaults[self.current_theme].clone();
+    }
+
+    /// The theme currently displayed.
+    pub fn theme(&self) -> &Theme {
+        &self.themes[self.current_theme]
+    }
+
+    pub fn theme_mut(&mut self) -> &mut Theme {
+        &mut self.themes[self.current_theme]
+    }
+
+    /// Returns true if the color `key` of the current theme differs from its default.
+    pub fn is_color_modified(&self, key: &str) -> bool {
+        self.themes[self.current_theme].colors.get(key)
+            != self.defaults[self.current_theme].colors.get(key)
+    }
+}
+
 impl ToStyle for ThemeManager {
     fn to_style(&self) -> String {
         self.themes[self.current_theme].to_style()
@@ -44,38 +95,25 @@ impl ExportToCss for ThemeManager {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Theme {
     name: String,
-    colors: HashMap<String, ColorChoice>,
+    colors: ThemeColors,
     radius: RadiusCss,
+    tokens: DesignTokens,
 }
 
 impl ToStyle for Theme {
     fn to_style(&self) -> String {
         let mut style = String::new();
 
-        for (key, color_choice) in self.colors.iter() {
-            match color_choice {
-                ColorChoice::Simple(color) => {
-                    style.push_str(&format!(" --{}: {};", key, color.to_style()));
-                }
-                ColorChoice::Duo(background, foreground) => {
-                    style.push_str(&format!(" --{}: {};", key, background.to_style()));
-                    if key != "background" {
-                        style.push_str(&format!(
-                            " --{}-foreground: {};",
-                            key,
-                            foreground.to_style()
-                        ));
-                    } else {
-                        style.push_str(&format!(" --foreground: {};", foreground.to_style()));
-                    }
-                }
-            }
+        for (key, color) in self.color_vars() {
+            style.push_str(&format!(" --{}: {};", key, color.to_style()));
         }
 
-        style.push_str(&format!(" --radius: {};\n", self.radius.to_style()));
+        style.push_str(&format!(" --radius: {};", self.radius.to_style()));
+        style.push_str(&self.tokens.to_style());
+        style.push('\n');
 
         style
     }
@@ -83,31 +121,14 @@ impl ToStyle for Theme {
 
 impl ExportToCss for Theme {
     fn export_to_css(&self) -> String {
-        let first_char = if self.name == "root" { ':' } else { '.' };
-
-        let mut css = format!(" {}{} {{\n", first_char, self.name);
+        let mut css = format!(" {} {{\n", self.selector());
 
-        for (key, color_choice) in self.colors.iter() {
-            match color_choice {
-                ColorChoice::Simple(color) => {
-                    css.push_str(&format!("  --{}: {};\n", key, color.to_style()));
-                }
-                ColorChoice::Duo(background, foreground) => {
-                    css.push_str(&format!("  --{}: {};\n", key, background.to_style()));
-                    if key != "background" {
-                        css.push_str(&format!(
-                            "  --{}-foreground: {};\n",
-                            key,
-                            foreground.to_style()
-                        ));
-                    } else {
-                        css.push_str(&format!("  --foreground: {};\n", foreground.to_style()));
-                    }
-                }
-            }
+        for (key, color) in self.color_vars() {
+            css.push_str(&format!("  --{}: {};\n", key, color.to_style()));
         }
 
         css.push_str(&format!("  --radius: {};\n", self.radius.to_style()));
+        css.push_str(&self.tokens.export_to_css());
 
         css.push_str(" }\n");
 
@@ -115,9 +136,79 @@ impl ExportToCss for Theme {
     }
 }
 
+/// Colors of a [`Theme`], kept in insertion order so that generated CSS is stable.
+///
+/// The built-in themes use the following order: `background`, `primary`, `secondary`,
+/// `accent`, `muted`, `destructive`, `success`, `border`, `input`, `popover`.
+/// Custom colors are emitted after them, in the order they were inserted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThemeColors(Vec<(String, ColorChoice)>);
+
+impl ThemeColors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&ColorChoice> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, color)| color)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut ColorChoice> {
+        self.0
+            .iter_mut()
+            .find(|(k, _)| k == key)
+            .map(|(_, color)| color)
+    }
+
+    /// Replaces the color `key` in place, or appends it if it does not exist yet.
+    /// Returns the previous color, if any.
+    pub fn insert(&mut self, key: String, color: ColorChoice) -> Option<ColorChoice> {
+        match self.get_mut(&key) {
+            Some(existing) => Some(std::mem::replace(existing, color)),
+            None => {
+                self.0.push((key, color));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<ColorChoice> {
+        let index = self.0.iter().position(|(k, _)| k == key)?;
+        Some(self.0.remove(index).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ColorChoice)> {
+        self.0.iter().map(|(key, color)| (key, color))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.0.iter().map(|(key, _)| key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl IntoIterator for ThemeColors {
+    type Item = (String, ColorChoice);
+    type IntoIter = std::vec::IntoIter<(String, ColorChoice)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 impl std::default::Default for Theme {
     fn default() -> Self {
-        let mut colors = HashMap::new();
+        let mut colors = ThemeColors::new();
         colors.insert(
             "background".to_string(),
             ColorChoice::Duo(
@@ -261,13 +352,53 @@ impl std::default::Default for Theme {
             name: "root".to_string(),
             colors,
             radius: RadiusCss("5px".to_string()),
+            tokens: DesignTokens::default(),
         }
     }
 }
 
 impl Theme {
+    /// `:root` for the root theme, else the class of the theme, e.g. `.dark`
+    pub(super) fn selector(&self) -> String {
+        if self.name == "root" {
+            ":root".to_string()
+        } else {
+            format!(".{}", self.name)
+        }
+    }
+
+    pub fn colors(&self) -> &ThemeColors {
+        &self.colors
+    }
+
+    pub fn colors_mut(&mut self) -> &mut ThemeColors {
+        &mut self.colors
+    }
+
+    /// Every color of the theme as `(css variable name, color)`, in theme order.
+    /// `Duo` colors yield both the background and its `-foreground` variable.
+    pub fn color_vars(&self) -> Vec<(String, &HslColor)> {
+        let mut vars = Vec::new();
+
+        for (key, color_choice) in self.colors.iter() {
+            match color_choice {
+                ColorChoice::Simple(color) => vars.push((key.clone(), color)),
+                ColorChoice::Duo(background, foreground) => {
+                    vars.push((key.clone(), background));
+                    if key != "background" {
+                        vars.push((format!("{key}-foreground"), foreground));
+                    } else {
+                        vars.push(("foreground".to_string(), foreground));
+                    }
+                }
+            }
+        }
+
+        vars
+    }
+
     fn dark() -> Self {
-        let mut colors = HashMap::new();
+        let mut colors = ThemeColors::new();
         colors.insert(
             "background".to_string(),
             ColorChoice::Duo(
@@ -411,6 +542,7 @@ impl Theme {
             name: "dark".to_string(),
             colors,
             radius: RadiusCss("5px".to_string()),
+            tokens: DesignTokens::dark(),
         }
     }
 }
@@ -477,6 +609,36 @@ impl HslColor {
 
         Ok(Self { h: h as i64, s, l })
     }
+
+    /// Converts the color to its sRGB components, each in the `0.0..=1.0` range.
+    pub fn to_rgb(&self) -> (f64, f64, f64) {
+        let h = self.h.rem_euclid(360) as f64;
+        let s = (self.s / 100.0).clamp(0.0, 1.0);
+        let l = (self.l / 100.0).clamp(0.0, 1.0);
+
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r, g, b) = match h as u32 {
+            0..60 => (c, x, 0.0),
+            60..120 => (x, c, 0.0),
+            120..180 => (0.0, c, x),
+            180..240 => (0.0, x, c),
+            240..300 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        (r + m, g + m, b + m)
+    }
+
+    /// Returns the color as a `#rrggbb` hex string.
+    pub fn to_hex(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        let to_byte = |v: f64| (v * 255.0).round() as u8;
+
+        format!("#{:02x}{:02x}{:02x}", to_byte(r), to_byte(g), to_byte(b))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
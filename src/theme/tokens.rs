@@ -0,0 +1,220 @@
+use super::{ExportToCss, ToStyle};
+
+/// An ordered scale of named CSS values, emitted as `--{prefix}-{name}` variables.
+/// The `DEFAULT` entry is emitted as `--{prefix}`, following the Tailwind convention.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenScale {
+    prefix: String,
+    values: Vec<(String, String)>,
+}
+
+impl TokenScale {
+    pub fn new(prefix: &str, values: &[(&str, &str)]) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            values: values
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Sets the value of `name`, appending it to the scale if it does not exist yet.
+    pub fn set(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+
+        match self.values.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => self.values.push((name.to_string(), value)),
+        }
+    }
+
+    /// Iterates over the `(name, value)` pairs in scale order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+
+    /// Name of the CSS variable for the entry `name` (without the leading `--`).
+    pub fn var_name(&self, name: &str) -> String {
+        if name == "DEFAULT" {
+            self.prefix.clone()
+        } else {
+            format!("{}-{}", self.prefix, name)
+        }
+    }
+}
+
+impl ToStyle for TokenScale {
+    fn to_style(&self) -> String {
+        self.iter()
+            .map(|(name, value)| format!(" --{}: {};", self.var_name(name), value))
+            .collect()
+    }
+}
+
+impl ExportToCss for TokenScale {
+    fn export_to_css(&self) -> String {
+        self.iter()
+            .map(|(name, value)| format!("  --{}: {};\n", self.var_name(name), value))
+            .collect()
+    }
+}
+
+/// Non-color design tokens of a [`Theme`](super::Theme).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesignTokens {
+    /// `--font-sans`, `--font-serif`, `--font-mono`
+    pub font_family: TokenScale,
+    /// `--text-xs` to `--text-xl`
+    pub font_size: TokenScale,
+    /// `--spacing-xs` to `--spacing-xl`
+    pub spacing: TokenScale,
+    /// `--shadow-sm`, `--shadow`, `--shadow-lg`
+    pub shadow: TokenScale,
+    /// Per-component radii, e.g. `--radius-button`. They default to the theme `--radius`.
+    pub radius: TokenScale,
+    /// `--duration-fast`, `--duration-normal`, `--duration-slow`
+    pub duration: TokenScale,
+}
+
+impl DesignTokens {
+    pub fn scales(&self) -> [&TokenScale; 6] {
+        [
+            &self.font_family,
+            &self.font_size,
+            &self.spacing,
+            &self.shadow,
+            &self.radius,
+            &self.duration,
+        ]
+    }
+
+    pub fn scales_mut(&mut self) -> [&mut TokenScale; 6] {
+        [
+            &mut self.font_family,
+            &mut self.font_size,
+            &mut self.spacing,
+            &mut self.shadow,
+            &mut self.radius,
+            &mut self.duration,
+        ]
+    }
+
+    pub(super) fn dark() -> Self {
+        Self {
+            shadow: TokenScale::new(
+                "shadow",
+                &[
+                    ("sm", "0 1px 2px 0 rgb(0 0 0 / 0.3)"),
+                    (
+                        "DEFAULT",
+                        "0 1px 3px 0 rgb(0 0 0 / 0.4), 0 1px 2px -1px rgb(0 0 0 / 0.4)",
+                    ),
+                    (
+                        "lg",
+                        "0 10px 15px -3px rgb(0 0 0 / 0.5), 0 4px 6px -4px rgb(0 0 0 / 0.5)",
+                    ),
+                ],
+            ),
+            ..Self::default()
+        }
+    }
+}
+
+impl std::default::Default for DesignTokens {
+    fn default() -> Self {
+        Self {
+            font_family: TokenScale::new(
+                "font",
+                &[
+                    ("sans", "ui-sans-serif, system-ui, sans-serif"),
+                    ("serif", "ui-serif, Georgia, serif"),
+                    ("mono", "ui-monospace, SFMono-Regular, Menlo, monospace"),
+                ],
+            ),
+            font_size: TokenScale::new(
+                "text",
+                &[
+                    ("xs", "0.75rem"),
+                    ("sm", "0.875rem"),
+                    ("base", "1rem"),
+                    ("lg", "1.125rem"),
+                    ("xl", "1.25rem"),
+                ],
+            ),
+            spacing: TokenScale::new(
+                "spacing",
+                &[
+                    ("xs", "0.25rem"),
+                    ("sm", "0.5rem"),
+                    ("md", "1rem"),
+                    ("lg", "1.5rem"),
+                    ("xl", "2rem"),
+                ],
+            ),
+            shadow: TokenScale::new(
+                "shadow",
+                &[
+                    ("sm", "0 1px 2px 0 rgb(0 0 0 / 0.05)"),
+                    (
+                        "DEFAULT",
+                        "0 1px 3px 0 rgb(0 0 0 / 0.1), 0 1px 2px -1px rgb(0 0 0 / 0.1)",
+                    ),
+                    (
+                        "lg",
+                        "0 10px 15px -3px rgb(0 0 0 / 0.1), 0 4px 6px -4px rgb(0 0 0 / 0.1)",
+                    ),
+                ],
+            ),
+            radius: TokenScale::new(
+                "radius",
+                &[
+                    ("button", "var(--radius)"),
+                    ("input", "var(--radius)"),
+                    ("card", "var(--radius)"),
+                    ("popover", "var(--radius)"),
+                ],
+            ),
+            duration: TokenScale::new(
+                "duration",
+                &[("fast", "150ms"), ("normal", "300ms"), ("slow", "500ms")],
+            ),
+        }
+    }
+}
+
+impl ToStyle for DesignTokens {
+    fn to_style(&self) -> String {
+        self.scales().iter().map(|scale| scale.to_style()).collect()
+    }
+}
+
+impl ExportToCss for DesignTokens {
+    fn export_to_css(&self) -> String {
+        self.scales()
+            .iter()
+            .map(|scale| scale.export_to_css())
+            .collect()
+    }
+}
+
+impl super::Theme {
+    pub fn tokens(&self) -> &DesignTokens {
+        &self.tokens
+    }
+
+    pub fn tokens_mut(&mut self) -> &mut DesignTokens {
+        &mut self.tokens
+    }
+}
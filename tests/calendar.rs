@@ -0,0 +1,211 @@
+#![cfg(feature = "calendar")]
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+
+fn date(text: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+}
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dioxus::ssr::render(&dom)
+}
+
+#[test]
+fn ranges_are_ordered() {
+    let mut range = DateSelection::Range(None, None);
+    range.select(date("2024-03-10"));
+    assert!(!range.is_complete());
+    range.select(date("2024-03-04"));
+
+    assert_eq!(
+        range,
+        DateSelection::Range(Some(date("2024-03-04")), Some(date("2024-03-10")))
+    );
+    assert!(range.contains(date("2024-03-07")));
+    assert_eq!(range.format("%d/%m"), "04/03 – 10/03");
+
+    range.select(date("2024-04-01"));
+    assert_eq!(range, DateSelection::Range(Some(date("2024-04-01")), None));
+}
+
+#[test]
+fn ranges_do_not_span_disabled_dates() {
+    let weekend = |day: NaiveDate| day.weekday().number_from_monday() > 5;
+    let mut range = DateSelection::Range(None, None);
+    range.select_enabled(date("2024-03-04"), weekend);
+    range.select_enabled(date("2024-03-12"), weekend);
+    assert_eq!(range, DateSelection::Range(Some(date("2024-03-12")), None));
+
+    range.select_enabled(date("2024-03-15"), weekend);
+    assert_eq!(
+        range,
+        DateSelection::Range(Some(date("2024-03-12")), Some(date("2024-03-15")))
+    );
+}
+
+#[test]
+fn multiple_dates_toggle() {
+    let mut dates = DateSelection::Multiple(Vec::new());
+    dates.select(date("2024-03-10"));
+    dates.select(date("2024-03-02"));
+    dates.select(date("2024-03-05"));
+    dates.select(date("2024-03-10"));
+
+    assert_eq!(
+        dates,
+        DateSelection::Multiple(vec![date("2024-03-02"), date("2024-03-05")])
+    );
+    assert_eq!(dates.first(), Some(date("2024-03-02")));
+}
+
+#[test]
+fn renders_month_grid() {
+    #[component]
+    fn App() -> Element {
+        rsx! {
+            Calendar {
+                default_value: DateSelection::Single(Some(date("2024-03-15"))),
+                min: date("2024-03-05"),
+                week_start: Weekday::Sun,
+                is_disabled: |day: NaiveDate| day.weekday() == Weekday::Sat,
+            }
+        }
+    }
+    let html = render(App);
+
+    assert!(html.contains("March 2024"), "{html}");
+    assert!(html.contains(r#"role="grid""#), "{html}");
+    // March 2024 starts on a Friday, the grid on the Sunday before
+    let weekdays = html.find(">Sun<").zip(html.find(">Mon<"));
+    assert!(weekdays.is_some_and(|(sun, mon)| sun < mon), "{html}");
+    assert!(
+        html.contains(r#"-2024-02-25" class="calendar-day""#),
+        "{html}"
+    );
+    assert!(html.contains(r#"aria-activedescendant="dx42-"#), "{html}");
+    assert_eq!(html.matches(r#"aria-selected=true"#).count(), 1, "{html}");
+    assert!(
+        html.contains(
+            r#"-2024-03-04" class="calendar-day" aria-selected=false aria-disabled=true"#
+        ),
+        "{html}"
+    );
+    assert!(
+        html.contains(
+            r#"-2024-03-09" class="calendar-day" aria-selected=false aria-disabled=true"#
+        ),
+        "{html}"
+    );
+    assert!(
+        html.contains(
+            r#"-2024-03-11" class="calendar-day" aria-selected=false aria-disabled=false"#
+        ),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"aria-label="Previous month" disabled=true"#),
+        "{html}"
+    );
+}
+
+#[test]
+fn date_picker_shows_selection() {
+    #[component]
+    fn App() -> Element {
+        rsx! {
+            DatePicker {}
+            DatePicker {
+                default_value: DateSelection::Range(Some(date("2024-03-04")), Some(date("2024-03-10"))),
+                format: "%d/%m/%Y",
+            }
+        }
+    }
+    let html = render(App);
+
+    assert!(html.contains("Pick a date"), "{html}");
+    assert!(html.contains("04/03/2024 – 10/03/2024"), "{html}");
+    assert!(html.contains(r#"aria-expanded=false"#), "{html}");
+    assert!(!html.contains("calendar-grid"), "{html}");
+}
+
+#[test]
+fn swapped_bounds_are_ordered() {
+    #[component]
+    fn App() -> Element {
+        rsx! {
+            Calendar {
+                default_month: date("2024-03-01"),
+                min: date("2024-03-20"),
+                max: date("2024-03-10"),
+            }
+        }
+    }
+
+    let html = render(App);
+    let day = |date: &str| {
+        let start = html.find(&format!("-{date}\"")).unwrap();
+        html[start..start + html[start..].find('>').unwrap()].to_string()
+    };
+    assert!(day("2024-03-15").contains("aria-disabled=false"), "{html}");
+    assert!(day("2024-03-05").contains("aria-disabled=true"), "{html}");
+    assert!(day("2024-03-25").contains("aria-disabled=true"), "{html}");
+}
+
+#[cfg(feature = "form")]
+#[test]
+fn form_reset_restores_the_date_picker() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static FORM: Cell<Option<FormState>> = const { Cell::new(None) };
+    }
+
+    #[component]
+    fn App() -> Element {
+        let form = use_form();
+        FORM.set(Some(form));
+
+        rsx! {
+            Form { form,
+                FormField { name: "day",
+                    DatePicker { format: "%d/%m/%Y" }
+                }
+                FormField { name: "stay",
+                    DatePicker {
+                        default_value: DateSelection::Range(Some(date("2024-03-04")), Some(date("2024-03-10"))),
+                        format: "%d/%m/%Y",
+                    }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut form = FORM.get().unwrap();
+    let html = |dom: &mut VirtualDom| {
+        for _ in 0..3 {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+        dioxus::ssr::render(dom)
+    };
+
+    dom.in_runtime(|| {
+        form.set_value("day", "2024-05-01");
+        form.set_value("stay", "2024-06-01/2024-06-02");
+    });
+    let picked = html(&mut dom);
+    assert!(picked.contains("01/05/2024"), "{picked}");
+    assert!(picked.contains("01/06/2024 – 02/06/2024"), "{picked}");
+
+    dom.in_runtime(|| form.reset());
+    let reset = html(&mut dom);
+    assert!(reset.contains("Pick a date"), "{reset}");
+    assert!(!reset.contains("01/05/2024"), "{reset}");
+    assert!(reset.contains("04/03/2024 – 10/03/2024"), "{reset}");
+}
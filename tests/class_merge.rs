@@ -0,0 +1,99 @@
+use dioxus_tw_components::class_merge::tw_merge;
+
+/// (defaults, user classes, expected)
+#[rustfmt::skip]
+const CONFLICTS: &[(&str, &str, &str)] = &[
+    // Unknown classes are kept, duplicates removed
+    ("button", "my-button", "button my-button"),
+    ("button", "button", "button"),
+    ("button p-4", "button", "p-4 button"),
+    // Padding and margin
+    ("p-4", "p-2", "p-2"),
+    ("px-4", "p-2", "p-2"),
+    ("p-4", "px-2", "p-4 px-2"),
+    ("pt-1 pb-1", "py-2", "py-2"),
+    ("m-4", "-m-2", "-m-2"),
+    ("mx-auto", "ml-2", "mx-auto ml-2"),
+    // Sizes
+    ("w-4 h-4", "size-8", "size-8"),
+    ("size-8", "w-4", "size-8 w-4"),
+    ("w-full", "w-1/2", "w-1/2"),
+    ("max-w-sm", "w-full", "max-w-sm w-full"),
+    // Colors and font sizes share the text- prefix
+    ("text-sm text-foreground", "text-primary", "text-sm text-primary"),
+    ("text-sm text-foreground", "text-lg", "text-foreground text-lg"),
+    ("text-sm", "text-[14px]", "text-[14px]"),
+    ("text-foreground", "text-[var(--primary)]", "text-[var(--primary)]"),
+    ("text-left", "text-center text-red-500", "text-center text-red-500"),
+    ("leading-6", "text-2xl", "text-2xl"),
+    // Backgrounds
+    ("bg-primary", "bg-secondary/50", "bg-secondary/50"),
+    ("bg-primary bg-cover", "bg-contain", "bg-primary bg-contain"),
+    // Borders
+    ("border border-border", "border-2", "border-border border-2"),
+    ("border-t-2", "border-0", "border-0"),
+    ("border-border", "border-destructive", "border-destructive"),
+    ("border-border", "border-t-red-500", "border-border border-t-red-500"),
+    ("border-solid", "border-dashed", "border-dashed"),
+    ("border-collapse border-border", "border-separate", "border-border border-separate"),
+    ("border-spacing-2 border-border", "border-spacing-x-4", "border-spacing-2 border-border border-spacing-x-4"),
+    ("border-spacing-x-2 border-2", "border-spacing-4", "border-2 border-spacing-4"),
+    ("ring-2 ring-primary ring-offset-2", "ring-offset-4", "ring-2 ring-primary ring-offset-4"),
+    ("ring-primary ring-offset-background", "ring-offset-white ring-destructive", "ring-offset-white ring-destructive"),
+    ("rounded-t-lg", "rounded-none", "rounded-none"),
+    ("rounded", "rounded-md", "rounded-md"),
+    // Layout
+    ("flex", "hidden", "hidden"),
+    ("flex flex-row", "flex-col", "flex flex-col"),
+    ("flex-1", "flex-none", "flex-none"),
+    ("relative", "absolute", "absolute"),
+    ("items-center justify-between", "justify-end", "items-center justify-end"),
+    ("overflow-x-auto", "overflow-hidden", "overflow-hidden"),
+    ("inset-x-0", "inset-0", "inset-0"),
+    ("font-medium font-sans", "font-bold", "font-sans font-bold"),
+    ("shadow-sm", "shadow-lg shadow-primary", "shadow-lg shadow-primary"),
+    // Variants and important only conflict with the same modifiers
+    ("hover:bg-primary", "bg-secondary", "hover:bg-primary bg-secondary"),
+    ("hover:bg-primary", "hover:bg-secondary", "hover:bg-secondary"),
+    ("dark:hover:p-2", "hover:dark:p-4", "hover:dark:p-4"),
+    ("p-4", "!p-2", "p-4 !p-2"),
+    ("!p-4", "p-2!", "p-2!"),
+    // Arbitrary values and properties
+    ("[mask-type:luminance]", "[mask-type:alpha]", "[mask-type:alpha]"),
+    ("grid-cols-[1fr_auto]", "grid-cols-2", "grid-cols-2"),
+    // Classes sharing the prefix of a utility, with a value it does not take
+    ("select-item", "select-none", "select-item select-none"),
+    ("select-none", "select-text", "select-text"),
+    ("order-summary", "order-2", "order-summary order-2"),
+    ("top-bar", "top-0", "top-bar top-0"),
+    ("content-box", "content-center", "content-box content-center"),
+    ("cursor-pointer", "cursor-[url(hand.cur),_pointer]", "cursor-[url(hand.cur),_pointer]"),
+    ("w-72", "w-md", "w-md"),
+    ("rounded-t-lg", "rounded-t-card", "rounded-t-lg rounded-t-card"),
+];
+
+#[test]
+fn user_classes_replace_conflicting_defaults() {
+    for (defaults, classes, expected) in CONFLICTS {
+        assert_eq!(
+            tw_merge([*defaults, *classes]),
+            *expected,
+            "merging {defaults:?} with {classes:?}"
+        );
+    }
+}
+
+#[test]
+fn merge_is_idempotent() {
+    for (defaults, classes, _) in CONFLICTS {
+        let merged = tw_merge([*defaults, *classes]);
+
+        assert_eq!(tw_merge([merged.as_str()]), merged);
+    }
+}
+
+#[test]
+fn whitespace_is_normalized() {
+    assert_eq!(tw_merge(["  button\tp-4 ", "\np-2  "]), "button p-2");
+    assert_eq!(tw_merge(["", ""]), "");
+}
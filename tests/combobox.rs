@@ -0,0 +1,344 @@
+#![cfg(feature = "combobox")]
+
+use dioxus::dioxus_core::{ElementId, Event, Mutation, Mutations};
+use dioxus::prelude::*;
+use dioxus_html::{
+    Code, Location, PlatformEventData, SerializedFormData, SerializedHtmlEventConverter,
+    SerializedKeyboardData,
+};
+use dioxus_tw_components::prelude::*;
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    future::poll_fn,
+    rc::Rc,
+    task::{Poll, Waker},
+};
+
+thread_local! {
+    static VALUE: Cell<Option<Signal<Vec<String>>>> = const { Cell::new(None) };
+}
+
+fn fruits() -> Vec<ComboboxOption> {
+    vec![
+        ComboboxOption::new("apple", "Apple"),
+        ComboboxOption::new("banana", "Banana"),
+        ComboboxOption::new("cherry", "Cherry").disabled(),
+    ]
+}
+
+#[component]
+fn App() -> Element {
+    let value = use_signal(|| vec!["apple".to_string(), "banana".to_string()]);
+    VALUE.set(Some(value));
+
+    rsx! {
+        Combobox { id: "single", options: fruits(), default_value: vec!["banana".to_string()] }
+        Combobox { id: "multiple", options: fruits(), value, multiple: true }
+    }
+}
+
+fn html(dom: &mut VirtualDom) -> String {
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    dioxus::ssr::render(dom)
+}
+
+/// A rendered app receiving events on the elements listening to them
+struct Harness {
+    dom: VirtualDom,
+    listeners: Vec<(String, ElementId)>,
+}
+
+impl Harness {
+    fn new(app: fn() -> Element) -> Self {
+        dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+        let mut dom = VirtualDom::new(app);
+        let mut mutations = Mutations::default();
+        dom.rebuild(&mut mutations);
+        let mut harness = Harness {
+            dom,
+            listeners: Vec::new(),
+        };
+        harness.record(mutations);
+        harness
+    }
+
+    fn record(&mut self, mutations: Mutations) {
+        for edit in mutations.edits {
+            if let Mutation::NewEventListener { name, id } = edit {
+                self.listeners.push((name, id));
+            }
+        }
+    }
+
+    /// Sends an event to the last element listening to `name`, then runs the effects and tasks
+    fn send(&mut self, name: &str, data: impl Any) {
+        let (_, id) = self
+            .listeners
+            .iter()
+            .rev()
+            .find(|(listener, _)| listener == name)
+            .unwrap_or_else(|| panic!("no {name} listener"));
+        let event = Event::new(
+            Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>,
+            true,
+        );
+        self.dom.runtime().handle_event(name, event, *id);
+        self.settle();
+    }
+
+    fn settle(&mut self) {
+        for _ in 0..3 {
+            self.dom.process_events();
+            let mut mutations = Mutations::default();
+            self.dom.render_immediate(&mut mutations);
+            self.record(mutations);
+        }
+    }
+
+    fn input(&mut self, text: &str) {
+        self.send(
+            "input",
+            SerializedFormData::new(text.to_string(), Vec::new()),
+        );
+    }
+
+    fn key(&mut self, key: Key) {
+        let data = SerializedKeyboardData::new(
+            key,
+            Code::Unidentified,
+            Location::Standard,
+            false,
+            Modifiers::empty(),
+            false,
+        );
+        self.send("keydown", data);
+    }
+
+    fn html(&self) -> String {
+        dioxus::ssr::render(&self.dom)
+    }
+}
+
+#[test]
+fn items_filter_by_label() {
+    let labels = |items: Vec<ComboboxItem>| -> Vec<String> {
+        items
+            .into_iter()
+            .map(|item| match item {
+                ComboboxItem::Option(option) => option.label,
+                ComboboxItem::Create(label) => format!("+{label}"),
+            })
+            .collect()
+    };
+
+    assert_eq!(
+        labels(ComboboxItem::list(&fruits(), " AN ", true, false)),
+        ["Banana"]
+    );
+    assert_eq!(
+        labels(ComboboxItem::list(&fruits(), "e", true, false)),
+        ["Apple", "Cherry"]
+    );
+    assert_eq!(
+        labels(ComboboxItem::list(&fruits(), "kiwi", false, false)),
+        ["Apple", "Banana", "Cherry"]
+    );
+    assert!(ComboboxItem::list(&fruits(), "kiwi", true, false).is_empty());
+}
+
+#[test]
+fn creatable_items_offer_new_labels() {
+    assert_eq!(
+        ComboboxItem::list(&fruits(), " Kiwi ", true, true),
+        [ComboboxItem::Create("Kiwi".to_string())]
+    );
+    assert_eq!(
+        ComboboxItem::list(&fruits(), "app", true, true).last(),
+        Some(&ComboboxItem::Create("app".to_string()))
+    );
+    assert!(
+        !ComboboxItem::list(&fruits(), "apple", true, true)
+            .iter()
+            .any(|item| matches!(item, ComboboxItem::Create(_)))
+    );
+    assert_eq!(ComboboxItem::list(&fruits(), " ", true, true).len(), 3);
+}
+
+#[test]
+fn steps_skip_disabled_items() {
+    let mut options = fruits();
+    options.rotate_right(1);
+    // Cherry (disabled), Apple, Banana
+    let items = ComboboxItem::list(&options, "", true, false);
+
+    assert_eq!(ComboboxItem::first(&items), 1);
+    assert_eq!(ComboboxItem::step(&items, 1, true), 2);
+    assert_eq!(ComboboxItem::step(&items, 2, true), 1);
+    assert_eq!(ComboboxItem::step(&items, 1, false), 2);
+
+    let disabled = [ComboboxItem::Option(ComboboxOption::from("x").disabled())];
+    assert_eq!(ComboboxItem::step(&disabled, 0, true), 0);
+    assert_eq!(ComboboxItem::first(&[]), 0);
+}
+
+#[test]
+fn typing_activates_the_first_enabled_option() {
+    fn app() -> Element {
+        let mut options = fruits();
+        options.rotate_right(1);
+        rsx! {
+            Combobox { id: "fruits", options }
+        }
+    }
+
+    let mut harness = Harness::new(app);
+    harness.input("e");
+    let html = harness.html();
+
+    let start = html.find(r#"aria-activedescendant=""#).expect(&html) + 23;
+    let active = &html[start..start + html[start..].find('"').unwrap()];
+    assert!(active.ends_with("-option-1"), "{html}");
+    assert!(
+        html.contains("data-active=true data-create=false>Apple"),
+        "{html}"
+    );
+}
+
+#[test]
+fn backspace_removes_tags_and_clears_single_values() {
+    thread_local! {
+        static TAGS: Cell<Option<Signal<Vec<String>>>> = const { Cell::new(None) };
+    }
+
+    fn multiple() -> Element {
+        let value = use_signal(|| vec!["apple".to_string(), "banana".to_string()]);
+        TAGS.set(Some(value));
+        rsx! {
+            Combobox { options: fruits(), value, multiple: true }
+        }
+    }
+
+    fn single() -> Element {
+        let value = use_signal(|| vec!["apple".to_string()]);
+        TAGS.set(Some(value));
+        rsx! {
+            Combobox { options: fruits(), value }
+        }
+    }
+
+    let mut harness = Harness::new(multiple);
+    let tags = TAGS.get().unwrap();
+    harness.key(Key::Backspace);
+    assert_eq!(harness.dom.in_runtime(|| tags.cloned()), ["apple"]);
+    harness.input("b");
+    harness.key(Key::Backspace);
+    assert_eq!(harness.dom.in_runtime(|| tags.cloned()), ["apple"]);
+
+    let mut harness = Harness::new(single);
+    let value = TAGS.get().unwrap();
+    harness.key(Key::Backspace);
+    assert!(harness.dom.in_runtime(|| value.cloned()).is_empty());
+}
+
+#[test]
+fn stale_loader_results_are_dropped() {
+    /// A query being loaded, with its results once given
+    struct Pending {
+        query: String,
+        results: Option<Vec<ComboboxOption>>,
+        waker: Option<Waker>,
+    }
+
+    thread_local! {
+        static PENDING: RefCell<Vec<Pending>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        let loader = use_hook(|| {
+            OptionLoader::new(|query: String| {
+                PENDING.with_borrow_mut(|pending| {
+                    pending.push(Pending {
+                        query: query.clone(),
+                        results: None,
+                        waker: None,
+                    })
+                });
+                poll_fn(move |context| {
+                    PENDING.with_borrow_mut(|pending| {
+                        let pending = pending.iter_mut().find(|p| p.query == query).unwrap();
+                        match pending.results.take() {
+                            Some(results) => Poll::Ready(results),
+                            None => {
+                                pending.waker = Some(context.waker().clone());
+                                Poll::Pending
+                            }
+                        }
+                    })
+                })
+            })
+        });
+        rsx! {
+            Combobox { loader }
+        }
+    }
+
+    let resolve = |query: &str, label: &str| {
+        PENDING.with_borrow_mut(|pending| {
+            let pending = pending.iter_mut().find(|p| p.query == query).unwrap();
+            pending.results = Some(vec![ComboboxOption::from(label)]);
+            pending.waker.take().unwrap().wake();
+        })
+    };
+
+    let mut harness = Harness::new(app);
+    harness.input("a");
+    harness.input("ap");
+    resolve("ap", "Apple");
+    harness.settle();
+    resolve("a", "Banana");
+    harness.settle();
+
+    let html = harness.html();
+    assert!(html.contains(">Apple</li>"), "{html}");
+    assert!(!html.contains("Banana"), "{html}");
+}
+
+#[test]
+fn renders_selected_values() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = html(&mut dom);
+
+    assert!(html.contains(r#"role="combobox""#), "{html}");
+    assert!(html.contains(r#"aria-expanded=false"#), "{html}");
+    assert!(html.contains(r#"value="Banana""#), "{html}");
+    assert_eq!(html.matches(r#"class="combobox-tag""#).count(), 2, "{html}");
+    assert!(html.contains(r#"aria-label="Remove Apple""#), "{html}");
+    assert!(!html.contains("combobox-listbox"), "{html}");
+}
+
+#[test]
+fn external_writes_rerender() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut value = VALUE.get().unwrap();
+
+    dom.in_runtime(|| value.set(vec!["cherry".to_string()]));
+    let html = html(&mut dom);
+
+    assert_eq!(html.matches(r#"class="combobox-tag""#).count(), 1, "{html}");
+    assert!(html.contains(r#"aria-label="Remove Cherry""#), "{html}");
+    assert!(!html.contains(r#"aria-label="Remove Apple""#), "{html}");
+}
+
+#[test]
+fn loaders_compare_by_function() {
+    let loader =
+        OptionLoader::new(
+            |query: String| async move { vec![ComboboxOption::from(query.as_str())] },
+        );
+
+    assert_eq!(loader, loader.clone());
+    assert_ne!(loader, OptionLoader::new(|_| async { Vec::new() }));
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "theme")]
+
+use dioxus_tw_components::theme::*;
+
+fn hex(hex: &str) -> HslColor {
+    HslColor::try_new_from_hex(hex).unwrap()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 0.01,
+        "{actual} is not {expected}"
+    );
+}
+
+#[test]
+fn luminance_spans_black_to_white() {
+    assert_close(hex("#000000").relative_luminance(), 0.0);
+    assert_close(hex("#ffffff").relative_luminance(), 1.0);
+    // Pure green weighs the most, pure blue the least
+    assert_close(hex("#00ff00").relative_luminance(), 0.7152);
+    assert_close(hex("#0000ff").relative_luminance(), 0.0722);
+}
+
+#[test]
+fn contrast_ratios_match_wcag_values() {
+    let black = hex("#000000");
+    let white = hex("#ffffff");
+
+    assert_close(black.contrast_ratio(&white), 21.0);
+    assert_close(white.contrast_ratio(&black), 21.0);
+    assert_close(white.contrast_ratio(&white), 1.0);
+    // The classic #777 on white sits just under the AA threshold
+    assert_close(hex("#777777").contrast_ratio(&white), 4.48);
+    assert_close(hex("#767676").contrast_ratio(&white), 4.54);
+}
+
+#[test]
+fn levels_use_their_thresholds() {
+    let white = hex("#ffffff");
+
+    assert!(!hex("#777777").meets(&white, WcagLevel::Aa));
+    assert!(hex("#767676").meets(&white, WcagLevel::Aa));
+    assert!(!hex("#767676").meets(&white, WcagLevel::Aaa));
+    assert!(hex("#595959").meets(&white, WcagLevel::Aaa));
+}
+
+#[test]
+fn suggestions_reach_the_level() {
+    let white = hex("#ffffff");
+    let grey = hex("#aaaaaa");
+
+    for level in [WcagLevel::Aa, WcagLevel::Aaa] {
+        let suggestion = grey.suggest_for(&white, level);
+        assert!(suggestion.meets(&white, level), "{suggestion:?}");
+        // Only just: the lightness is changed as little as possible
+        assert!(
+            suggestion.contrast_ratio(&white) < level.min_ratio() + 0.2,
+            "{suggestion:?}"
+        );
+    }
+
+    // Colors meeting the level are kept
+    let black = hex("#000000");
+    assert_eq!(black.suggest_for(&white, WcagLevel::Aaa), black);
+}
+
+#[test]
+fn audit_reports_the_failing_pairs() {
+    let mut theme = Theme::default();
+    theme.colors_mut().insert(
+        "primary".to_string(),
+        ColorChoice::Duo(hex("#ffffff"), hex("#777777")),
+    );
+    theme.colors_mut().insert(
+        "secondary".to_string(),
+        ColorChoice::Duo(hex("#ffffff"), hex("#000000")),
+    );
+    theme.colors_mut().insert(
+        "accent".to_string(),
+        ColorChoice::Duo(hex("#ffffff"), hex("#767676")),
+    );
+
+    let audit = theme.audit();
+
+    let primary = audit.get("primary").unwrap();
+    assert_eq!(primary.level, WcagLevel::Aa);
+    assert_close(primary.ratio, 4.48);
+    assert!(primary.suggestion.meets(&hex("#ffffff"), WcagLevel::Aa));
+
+    assert_eq!(audit.get("accent").unwrap().level, WcagLevel::Aaa);
+    assert!(audit.get("secondary").is_none());
+
+    let failing_aa: Vec<&str> = audit
+        .failing(WcagLevel::Aa)
+        .map(|issue| issue.key.as_str())
+        .collect();
+    assert!(failing_aa.contains(&"primary"), "{failing_aa:?}");
+    assert!(!failing_aa.contains(&"accent"), "{failing_aa:?}");
+}
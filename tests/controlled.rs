@@ -0,0 +1,90 @@
+#![cfg(all(
+    feature = "input",
+    feature = "textarea",
+    feature = "select",
+    feature = "slider",
+    feature = "checkbox",
+    feature = "toggle"
+))]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+use std::cell::Cell;
+
+#[derive(Clone, Copy)]
+struct Signals {
+    text: Signal<String>,
+    level: Signal<i64>,
+    checked: Signal<bool>,
+}
+
+thread_local! {
+    static SIGNALS: Cell<Option<Signals>> = const { Cell::new(None) };
+}
+
+#[component]
+fn App() -> Element {
+    let text = use_signal(|| "first".to_string());
+    let level = use_signal(|| 20);
+    let checked = use_signal(|| false);
+    SIGNALS.set(Some(Signals {
+        text,
+        level,
+        checked,
+    }));
+
+    rsx! {
+        Input { id: "controlled-input", value: text }
+        TextArea { value: text }
+        SelectGroup { value: text,
+            SelectItem { value: "first", "First" }
+            SelectItem { value: "second", "Second" }
+        }
+        Slider { value: level }
+        Checkbox { checked }
+        Toggle { checked }
+        Input { id: "uncontrolled-input", default_value: "kept" }
+    }
+}
+
+fn html(dom: &mut VirtualDom) -> String {
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    dioxus::ssr::render(dom)
+}
+
+#[test]
+fn renders_controlled_values() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = html(&mut dom);
+
+    assert!(html.contains(r#"<input value="first""#), "{html}");
+    assert!(html.contains(r#"<textarea value="first""#), "{html}");
+    assert!(html.contains(r#"<select value="first""#), "{html}");
+    assert!(html.contains(r#"value="20""#), "{html}");
+    assert!(html.contains(r#"data-checked="unchecked""#), "{html}");
+    assert!(html.contains(r#"data-state="unchecked""#), "{html}");
+    assert!(html.contains(r#"value="kept""#), "{html}");
+}
+
+#[test]
+fn external_writes_rerender() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut signals = SIGNALS.get().unwrap();
+
+    dom.in_runtime(|| {
+        signals.text.set("second".to_string());
+        signals.level.set(70);
+        signals.checked.set(true);
+    });
+    let html = html(&mut dom);
+
+    assert!(html.contains(r#"<input value="second""#), "{html}");
+    assert!(html.contains(r#"<textarea value="second""#), "{html}");
+    assert!(html.contains(r#"<select value="second""#), "{html}");
+    assert!(html.contains(r#"value="70""#), "{html}");
+    assert!(html.contains(r#"data-checked="checked""#), "{html}");
+    assert!(html.contains(r#"data-state="checked""#), "{html}");
+    assert!(html.contains(r#"value="kept""#), "{html}");
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "theme")]
+
+use dioxus_tw_components::theme::*;
+
+fn set_primary(hex: &str) -> impl FnOnce(&mut ThemeManager) {
+    let color = HslColor::try_new_from_hex(hex).unwrap();
+    move |theme_manager| {
+        theme_manager
+            .theme_mut()
+            .colors_mut()
+            .insert("primary".to_string(), ColorChoice::Simple(color));
+    }
+}
+
+fn primary(draft: &ThemeDraft) -> Option<String> {
+    match draft.present().theme().colors().get("primary")? {
+        ColorChoice::Simple(color) => Some(color.to_hex()),
+        ColorChoice::Duo(background, _) => Some(background.to_hex()),
+    }
+}
+
+#[test]
+fn undo_and_redo_walk_the_history() {
+    let live = ThemeManager::default();
+    let mut draft = ThemeDraft::new(live.clone());
+    assert!(!draft.can_undo() && !draft.can_redo());
+
+    draft.edit("primary", set_primary("#ff0000"));
+    draft.edit("secondary", |theme_manager| {
+        theme_manager.theme_mut().colors_mut().remove("secondary");
+    });
+    assert!(draft.is_dirty(&live));
+
+    assert!(draft.undo());
+    assert!(draft.present().theme().colors().get("secondary").is_some());
+    assert_eq!(primary(&draft).as_deref(), Some("#ff0000"));
+
+    assert!(draft.undo());
+    assert!(!draft.can_undo());
+    assert!(!draft.undo());
+    assert!(!draft.is_dirty(&live));
+
+    assert!(draft.redo());
+    assert_eq!(primary(&draft).as_deref(), Some("#ff0000"));
+    assert!(draft.can_redo());
+
+    // A new edit drops the undone ones
+    draft.edit("radius", |theme_manager| theme_manager.reset_theme());
+    draft.edit("primary", set_primary("#00ff00"));
+    assert!(!draft.can_redo());
+}
+
+#[test]
+fn consecutive_edits_of_a_key_are_merged() {
+    let mut draft = ThemeDraft::new(ThemeManager::default());
+
+    draft.edit("primary", set_primary("#ff0000"));
+    draft.edit("primary", set_primary("#00ff00"));
+    draft.edit("primary", set_primary("#0000ff"));
+    assert_eq!(primary(&draft).as_deref(), Some("#0000ff"));
+
+    assert!(draft.undo());
+    assert!(!draft.can_undo());
+    assert_ne!(primary(&draft).as_deref(), Some("#ff0000"));
+
+    // Edits that change nothing are not recorded
+    let mut draft = ThemeDraft::new(ThemeManager::default());
+    draft.edit("noop", |_| {});
+    assert!(!draft.can_undo());
+}
+
+#[test]
+fn history_is_bounded() {
+    let mut draft = ThemeDraft::new(ThemeManager::default());
+
+    for index in 0..150 {
+        draft.edit(
+            &format!("edit-{index}"),
+            set_primary(&format!("#{index:06x}")),
+        );
+    }
+
+    let mut undone = 0;
+    while draft.undo() {
+        undone += 1;
+    }
+    assert_eq!(undone, 100);
+    // The oldest edits were dropped
+    assert_eq!(primary(&draft).as_deref(), Some("#000031"));
+}
+
+#[test]
+fn switching_theme_is_not_undone() {
+    let mut draft = ThemeDraft::new(ThemeManager::default());
+
+    draft.set_current_theme(0);
+    draft.edit("primary", set_primary("#ff0000"));
+    draft.set_current_theme(1);
+    assert!(draft.undo());
+    assert_eq!(draft.present().current_theme, 1);
+}
+
+#[test]
+fn commit_and_discard_follow_the_live_theme() {
+    let mut live = ThemeManager::default();
+    let mut draft = ThemeDraft::new(live.clone());
+
+    draft.edit("primary", set_primary("#ff0000"));
+    live.current_theme = 0;
+    draft.commit(&mut live);
+    assert!(!draft.is_dirty(&live));
+    assert_eq!(live.current_theme, 0);
+
+    draft.edit("primary", set_primary("#00ff00"));
+    draft.discard(&live);
+    assert!(!draft.can_undo());
+    assert!(!draft.is_dirty(&live));
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "dropzone")]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+
+#[test]
+fn accept_matches_extensions_and_types() {
+    assert!(accepts_file("", "notes.txt", None));
+    assert!(accepts_file("image/*,.pdf", "photo.PNG", Some("image/png")));
+    assert!(accepts_file("image/*, .pdf", "report.pdf", None));
+    assert!(accepts_file(
+        "application/json",
+        "data",
+        Some("application/json")
+    ));
+    assert!(!accepts_file(
+        "image/*,.pdf",
+        "notes.txt",
+        Some("text/plain")
+    ));
+    assert!(!accepts_file(".pdf", "pdf", None));
+}
+
+#[test]
+fn renders_limits() {
+    #[component]
+    fn App() -> Element {
+        rsx! {
+            FileDropzone { accept: "image/*,.pdf", max_size: 2 * 1024 * 1024, max_files: 3 }
+            FileDropzone { max_files: 1, prompt: "Drop your avatar" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = dioxus::ssr::render(&dom);
+
+    assert!(
+        html.contains(r#"type="file" accept="image/*,.pdf" multiple=true"#),
+        "{html}"
+    );
+    assert!(
+        html.contains("image/*, .pdf · up to 2.0 MB · 3 files max"),
+        "{html}"
+    );
+    assert!(html.contains(r#"type="file"/>"#), "{html}");
+    assert!(html.contains("Drop your avatar"), "{html}");
+    assert!(!html.contains("filedropzone-files"), "{html}");
+}
+
+#[test]
+fn limits_reject_type_size_and_count() {
+    let limits = FileLimits {
+        accept: Some("image/*".to_string()),
+        max_size: Some(1024),
+        max_files: Some(2),
+    };
+    let reject = |name: &str, size: u64, accepted: usize| {
+        limits.rejection(
+            name,
+            Some("image/png").filter(|_| name.ends_with(".png")),
+            size,
+            accepted,
+        )
+    };
+
+    assert_eq!(reject("photo.png", 512, 0), None);
+    assert_eq!(reject("photo.png", 1024, 1), None);
+    assert_eq!(
+        reject("notes.txt", 10, 0).as_deref(),
+        Some("File type not accepted")
+    );
+    assert_eq!(
+        reject("photo.png", 2048, 0).as_deref(),
+        Some("Larger than 1.0 KB")
+    );
+    assert_eq!(
+        reject("photo.png", 512, 2).as_deref(),
+        Some("Too many files")
+    );
+    // The type is checked first, then the size
+    assert_eq!(
+        reject("notes.txt", 2048, 2).as_deref(),
+        Some("File type not accepted")
+    );
+    assert_eq!(
+        reject("photo.png", 2048, 2).as_deref(),
+        Some("Larger than 1.0 KB")
+    );
+
+    assert_eq!(
+        FileLimits::default().rejection("any", None, u64::MAX, 100),
+        None
+    );
+}
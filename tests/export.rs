@@ -0,0 +1,91 @@
+#![cfg(feature = "theme")]
+
+use dioxus_tw_components::theme::*;
+use serde_json::Value;
+
+#[test]
+fn tailwind_theme_declares_colors_and_tokens() {
+    let css = Theme::default().export_to_tailwind();
+    let (theme, rest) = css.split_once("}\n").unwrap();
+
+    assert!(theme.starts_with("@theme {\n"), "{css}");
+    assert!(
+        theme.contains("  --color-primary: hsl(216deg 83.61% 52.16%);\n"),
+        "{css}"
+    );
+    assert!(theme.contains("  --color-primary-foreground: "), "{css}");
+    assert!(theme.contains("  --text-sm: 0.875rem;\n"), "{css}");
+    // Tailwind v4 has no namespace for durations
+    assert!(!theme.contains("--duration"), "{css}");
+    assert!(
+        rest.contains(":root {\n  --duration-fast: 150ms;\n"),
+        "{css}"
+    );
+}
+
+#[test]
+fn tailwind_theme_manager_declares_tokens_once() {
+    let css = ThemeManager::default().export_to_tailwind();
+
+    assert!(css.starts_with("@theme inline {\n"), "{css}");
+    assert!(
+        css.contains("  --color-primary: var(--primary);\n"),
+        "{css}"
+    );
+    assert_eq!(css.matches("  --text-sm: ").count(), 1, "{css}");
+    assert_eq!(css.matches("  --duration-fast: ").count(), 1, "{css}");
+    assert_eq!(css.matches("  --primary: ").count(), 2, "{css}");
+
+    // The dark theme only overrides the tokens it changes
+    let dark = &css[css.find(" .dark {").unwrap()..];
+    assert!(
+        dark.contains("  --shadow-sm: 0 1px 2px 0 rgb(0 0 0 / 0.3);\n"),
+        "{css}"
+    );
+    assert!(!dark.contains("--text-sm"), "{css}");
+}
+
+#[test]
+fn tailwind_config_extends_the_theme() {
+    let js = Theme::default().export_to_tailwind_config();
+
+    assert!(
+        js.starts_with("/** @type {import('tailwindcss').Config} */\n"),
+        "{js}"
+    );
+    assert!(
+        js.contains("\t\t\t\t\"primary\": 'var(--primary)',\n"),
+        "{js}"
+    );
+    assert!(js.contains("\t\t\tboxShadow: {\n"), "{js}");
+    assert!(
+        js.contains("\t\t\t\t\"DEFAULT\": 'var(--shadow)',\n"),
+        "{js}"
+    );
+    assert!(
+        js.contains("\t\t\t\t\"DEFAULT\": 'var(--radius)',\n"),
+        "{js}"
+    );
+    assert!(js.contains("\t\t\ttransitionDuration: {\n"), "{js}");
+}
+
+#[test]
+fn design_tokens_follow_the_dtcg_format() {
+    let json: Value =
+        serde_json::from_str(&ThemeManager::default().export_to_design_tokens()).unwrap();
+    let root = &json["root"];
+
+    assert_eq!(root["color"]["$type"], "color");
+    assert_eq!(root["color"]["primary"]["$value"], "#1f71eb");
+    assert_eq!(root["font"]["$type"], "fontFamily");
+    assert_eq!(
+        root["font"]["sans"]["$value"],
+        serde_json::json!(["ui-sans-serif", "system-ui", "sans-serif"])
+    );
+    assert_eq!(
+        root["component-radius"]["button"]["$value"],
+        "{root.radius}"
+    );
+    assert_eq!(root["duration"]["fast"]["$value"], "150ms");
+    assert!(json["dark"].is_object());
+}
@@ -0,0 +1,373 @@
+#![cfg(all(feature = "form", feature = "input", feature = "checkbox"))]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+use regex::Regex;
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+thread_local! {
+    static FORM: Cell<Option<FormState>> = const { Cell::new(None) };
+}
+
+#[component]
+fn App() -> Element {
+    let form = use_form();
+    FORM.set(Some(form));
+
+    rsx! {
+        Form { form,
+            FormField {
+                name: "username",
+                validators: vec![Validator::required(), Validator::min_length(3)],
+                Input { default_value: "ab" }
+            }
+            FormField {
+                name: "code",
+                validators: vec![Validator::pattern(Regex::new(r"^\d{4}$").unwrap()).message("Four digits")],
+                Input {}
+            }
+            FormField {
+                name: "terms",
+                validators: vec![Validator::required().message("Accept the terms")],
+                Checkbox {}
+            }
+        }
+    }
+}
+
+fn render() -> (VirtualDom, FormState) {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let form = FORM.get().unwrap();
+    (dom, form)
+}
+
+fn html(dom: &mut VirtualDom) -> String {
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    dioxus::ssr::render(dom)
+}
+
+/// Polls a future that does not wait on anything
+fn ready<T>(future: impl Future<Output = T>) -> T {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(value) => value,
+        Poll::Pending => panic!("the future is pending"),
+    }
+}
+
+#[test]
+fn untouched_fields_hide_errors() {
+    let (mut dom, form) = render();
+    let html = html(&mut dom);
+
+    assert!(html.contains(r#"name="username""#), "{html}");
+    assert!(html.contains(r#"value="ab""#), "{html}");
+    assert!(!html.contains("form-error"), "{html}");
+    dom.in_runtime(|| {
+        assert_eq!(form.errors("username"), ["Must be at least 3 characters"]);
+        assert!(form.visible_errors("username").is_empty());
+        assert!(!form.is_valid());
+    });
+}
+
+#[test]
+fn touched_fields_show_errors() {
+    let (mut dom, mut form) = render();
+    dom.in_runtime(|| {
+        form.set_value("code", "12a");
+        form.touch("code");
+    });
+    let html = html(&mut dom);
+
+    assert!(html.contains("Four digits"), "{html}");
+    assert!(html.contains(r#"aria-invalid="true""#), "{html}");
+    assert!(!html.contains("Accept the terms"), "{html}");
+}
+
+#[test]
+fn tracks_dirty_fields() {
+    let (dom, mut form) = render();
+    dom.in_runtime(|| {
+        assert!(!form.is_form_dirty());
+        form.set_value("username", "abc");
+        assert!(form.is_dirty("username"));
+        assert!(!form.is_dirty("code"));
+        assert!(form.errors("username").is_empty());
+
+        form.reset();
+        assert!(!form.is_form_dirty());
+        assert_eq!(form.value("username").as_deref(), Some("ab"));
+    });
+}
+
+#[test]
+fn validate_shows_every_error() {
+    let (mut dom, form) = render();
+    let valid = dom.in_runtime(|| ready(form.validate()));
+    let html = html(&mut dom);
+
+    assert!(!valid);
+    assert!(html.contains("Must be at least 3 characters"), "{html}");
+    assert!(html.contains("Accept the terms"), "{html}");
+    // Empty optional fields only validate once filled
+    assert!(!html.contains("Four digits"), "{html}");
+}
+
+#[test]
+fn validate_passes_once_fixed() {
+    let (dom, mut form) = render();
+    let valid = dom.in_runtime(|| {
+        form.set_value("username", "alice");
+        form.set_value("terms", "on");
+        ready(form.validate())
+    });
+
+    assert!(valid);
+    dom.in_runtime(|| {
+        let values = form.values();
+        assert_eq!(values.get("username"), Some("alice"));
+        assert_eq!(values.get("code"), Some(""));
+        assert_eq!(values.get("terms"), Some("on"));
+    });
+}
+
+#[test]
+fn async_validators_run_after_sync_ones() {
+    #[component]
+    fn AsyncApp() -> Element {
+        let form = use_form();
+        FORM.set(Some(form));
+
+        rsx! {
+            Form { form,
+                FormField {
+                    name: "email",
+                    validators: vec![
+                        Validator::required(),
+                        Validator::custom_async(|value: String| async move {
+                            if value == "taken@example.com" {
+                                Err("Already registered".to_string())
+                            } else {
+                                Ok(())
+                            }
+                        }),
+                    ],
+                    Input {}
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(AsyncApp);
+    dom.rebuild_in_place();
+    let mut form = FORM.get().unwrap();
+
+    // Async validations of `set_value` are spawned in the current scope
+    dom.in_scope(ScopeId::ROOT, || {
+        assert!(!ready(form.validate()));
+        assert_eq!(form.errors("email"), ["This field is required"]);
+
+        form.set_value("email", "taken@example.com");
+        assert!(!ready(form.validate()));
+        assert_eq!(form.errors("email"), ["Already registered"]);
+
+        form.set_value("email", "new@example.com");
+        assert!(ready(form.validate()));
+    });
+}
+
+#[test]
+fn validators_compare_by_rule() {
+    let even = Validator::custom(|value| {
+        if value.len() % 2 == 0 {
+            Ok(())
+        } else {
+            Err("Even length only".to_string())
+        }
+    });
+
+    assert_eq!(even, even.clone());
+    assert_ne!(Validator::min_length(2), Validator::min_length(3));
+    assert_ne!(
+        Validator::required(),
+        Validator::required().message("Needed")
+    );
+}
+
+#[test]
+fn changed_validators_are_registered_again() {
+    thread_local! {
+        static MIN_LENGTH: Cell<Option<Signal<usize>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let form = use_form();
+        FORM.set(Some(form));
+        let min_length = use_signal(|| 3);
+        MIN_LENGTH.set(Some(min_length));
+
+        rsx! {
+            Form { form,
+                FormField {
+                    name: "username",
+                    validators: vec![Validator::min_length(min_length())],
+                    Input { default_value: "ab" }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let form = FORM.get().unwrap();
+    let mut min_length = MIN_LENGTH.get().unwrap();
+    dom.in_runtime(|| assert!(!form.is_valid()));
+
+    dom.in_runtime(|| min_length.set(2));
+    // Registered again by an effect of the render
+    for _ in 0..2 {
+        dom.process_events();
+        html(&mut dom);
+    }
+    dom.in_runtime(|| {
+        assert!(form.is_valid(), "{:?}", form.errors("username"));
+        assert_eq!(form.value("username").as_deref(), Some("ab"));
+    });
+}
+
+#[test]
+fn controlled_values_win_over_the_field() {
+    thread_local! {
+        static VALUE: Cell<Option<Signal<String>>> = const { Cell::new(None) };
+        static CHECKED: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let form = use_form();
+        FORM.set(Some(form));
+        let value = use_signal(|| "ab".to_string());
+        VALUE.set(Some(value));
+        let checked = use_signal(|| false);
+        CHECKED.set(Some(checked));
+
+        rsx! {
+            Form { form,
+                FormField { name: "username", Input { value } }
+                FormField { name: "terms", Checkbox { checked } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let form = FORM.get().unwrap();
+    let (mut value, mut checked) = (VALUE.get().unwrap(), CHECKED.get().unwrap());
+
+    dom.in_runtime(|| {
+        value.set("abcd".to_string());
+        checked.set(true);
+    });
+    for _ in 0..3 {
+        dom.process_events();
+        html(&mut dom);
+    }
+
+    let html = html(&mut dom);
+    assert!(html.contains(r#"value="abcd""#), "{html}");
+    assert!(html.contains("checked=true"), "{html}");
+    dom.in_runtime(|| {
+        assert_eq!(form.value("username").as_deref(), Some("abcd"));
+        assert_eq!(form.value("terms").as_deref(), Some("on"));
+    });
+}
+
+#[test]
+fn inline_custom_validators_do_not_loop() {
+    thread_local! {
+        static RENDERS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        let form = use_form();
+        FORM.set(Some(form));
+        RENDERS.set(RENDERS.get() + 1);
+
+        rsx! {
+            Form { form,
+                FormField {
+                    name: "code",
+                    validators: vec![Validator::custom(|value| match value.len() {
+                        4 => Ok(()),
+                        _ => Err("Four characters".to_string()),
+                    })],
+                    Input { default_value: "ab" }
+                }
+                button { disabled: !form.is_valid(), "Send" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    for _ in 0..10 {
+        dom.process_events();
+        html(&mut dom);
+    }
+    assert!(RENDERS.get() < 5, "rendered {} times", RENDERS.get());
+
+    let form = FORM.get().unwrap();
+    dom.in_runtime(|| assert!(!form.is_valid()));
+    dom.in_runtime(|| {
+        let mut form = form;
+        form.set_value("code", "abcd");
+    });
+    html(&mut dom);
+    dom.in_runtime(|| assert!(form.is_valid()));
+}
+
+#[test]
+fn custom_validators_change_with_their_key() {
+    thread_local! {
+        static STRICT: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let form = use_form();
+        FORM.set(Some(form));
+        let strict = use_signal(|| false);
+        STRICT.set(Some(strict));
+        let max = if strict() { 2 } else { 10 };
+
+        rsx! {
+            Form { form,
+                FormField {
+                    name: "code",
+                    validators: vec![Validator::custom(move |value| match value.len() <= max {
+                        true => Ok(()),
+                        false => Err("Too long".to_string()),
+                    })],
+                    validators_key: "{max}",
+                    Input { default_value: "abc" }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let form = FORM.get().unwrap();
+    let mut strict = STRICT.get().unwrap();
+    dom.in_runtime(|| assert!(form.is_valid()));
+
+    dom.in_runtime(|| strict.set(true));
+    for _ in 0..2 {
+        dom.process_events();
+        html(&mut dom);
+    }
+    dom.in_runtime(|| assert_eq!(form.errors("code"), ["Too long"]));
+}
@@ -0,0 +1,161 @@
+#![cfg(all(feature = "formlist", feature = "input"))]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+use std::cell::Cell;
+
+thread_local! {
+    static ITEMS: Cell<Option<Signal<Vec<String>>>> = const { Cell::new(None) };
+}
+
+#[component]
+fn App() -> Element {
+    let items = use_signal(|| ["a", "b", "c", "d"].map(String::from).to_vec());
+    ITEMS.set(Some(items));
+
+    rsx! {
+        FormList {
+            value: items,
+            new_item: |_| String::new(),
+            min: 1,
+            max: 4,
+            render: move |entry: FormListEntry<String>| rsx! {
+                FormListHandle {}
+                // Keeps its own value, which must follow its item
+                Input { id: "item-{entry.key}", default_value: entry.value }
+                FormListTriggerMinus { "Remove" }
+            },
+            FormListTriggerPlus { "Add" }
+            FormListCurrentSize {}
+            " / "
+            FormListMaxSize {}
+        }
+    }
+}
+
+fn render(dom: &mut VirtualDom) -> String {
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    dioxus::ssr::render(dom)
+}
+
+fn input_values(html: &str) -> Vec<&str> {
+    html.split(r#"<input value=""#)
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .collect()
+}
+
+#[test]
+fn renders_each_item() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = render(&mut dom);
+
+    assert_eq!(input_values(&html), ["a", "b", "c", "d"], "{html}");
+    assert_eq!(html.matches(r#"class="formlist-row""#).count(), 4, "{html}");
+    assert_eq!(html.matches(r#"draggable="true""#).count(), 4, "{html}");
+    assert!(html.contains("4 / 4"), "{html}");
+    assert!(
+        html.contains(r#"type="button" disabled=true class="formlist-trigger-plus""#),
+        "{html}"
+    );
+}
+
+#[test]
+fn removed_items_take_their_state_along() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut items = ITEMS.get().unwrap();
+
+    dom.in_runtime(|| {
+        items.write().remove(1);
+    });
+    let html = render(&mut dom);
+
+    assert_eq!(input_values(&html), ["a", "c", "d"], "{html}");
+    assert!(html.contains(r#"id="item-2""#), "{html}");
+    assert!(!html.contains(r#"id="item-1""#), "{html}");
+    assert!(html.contains("3 / 4"), "{html}");
+
+    dom.in_runtime(|| {
+        items.write().truncate(1);
+    });
+    let html = render(&mut dom);
+
+    assert_eq!(input_values(&html), ["a"], "{html}");
+    assert_eq!(
+        html.matches(r#"type="button" disabled=true class="formlist-trigger-minus""#)
+            .count(),
+        1,
+        "{html}"
+    );
+}
+
+fn item_ids(html: &str) -> Vec<&str> {
+    html.split(r#"id="item-"#)
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .collect()
+}
+
+#[test]
+fn reordered_items_keep_their_keys() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut items = ITEMS.get().unwrap();
+
+    dom.in_runtime(|| items.write().reverse());
+    let html = render(&mut dom);
+    assert_eq!(input_values(&html), ["d", "c", "b", "a"], "{html}");
+    assert_eq!(item_ids(&html), ["3", "2", "1", "0"], "{html}");
+
+    // Edited in place, the item keeps the key of its position
+    dom.in_runtime(|| {
+        let mut items = items.write();
+        items.swap(0, 3);
+        items[1] = "x".to_string();
+    });
+    let html = render(&mut dom);
+    assert_eq!(item_ids(&html), ["0", "2", "1", "3"], "{html}");
+}
+
+#[test]
+fn bounds_follow_their_props() {
+    thread_local! {
+        static LIMIT: Cell<Option<Signal<usize>>> = const { Cell::new(None) };
+    }
+
+    #[component]
+    fn Limited() -> Element {
+        let items = use_signal(|| vec!["a".to_string(), "b".to_string()]);
+        let limit = use_signal(|| 2);
+        LIMIT.set(Some(limit));
+
+        rsx! {
+            FormList {
+                value: items,
+                new_item: |_| String::new(),
+                max: limit(),
+                render: move |_: FormListEntry<String>| rsx! {},
+                FormListTriggerPlus { "Add" }
+                FormListCurrentSize {}
+                FormListMaxSize {}
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(Limited);
+    dom.rebuild_in_place();
+    let html = render(&mut dom);
+    assert!(html.contains("disabled=true"), "{html}");
+
+    let mut limit = LIMIT.get().unwrap();
+    dom.in_runtime(|| limit.set(3));
+    for _ in 0..3 {
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    }
+    let html = render(&mut dom);
+    assert!(!html.contains("disabled"), "{html}");
+    assert!(html.ends_with(">23</div>"), "{html}");
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "icon")]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dioxus::ssr::render(&dom)
+}
+
+#[test]
+fn font_backend_sets_variation_settings() {
+    let html = render(|| {
+        rsx! {
+            Icon { icon: Icons::Home, backend: IconBackend::Font, filled: false, weight: 600 }
+        }
+    });
+
+    assert!(html.starts_with("<p"), "{html}");
+    assert!(html.contains(">home</p>"), "{html}");
+    assert!(
+        html.contains("&#39;FILL&#39; 0, &#39;wght&#39; 600, &#39;GRAD&#39; 0"),
+        "{html}"
+    );
+}
+
+#[cfg(feature = "svg-icons")]
+#[test]
+fn svg_backend_renders_path() {
+    let filled = render(|| rsx! { Icon { icon: Icons::Home, backend: IconBackend::Svg } });
+    let outlined = render(|| {
+        rsx! {
+            Icon { icon: Icons::Home, backend: IconBackend::Svg, filled: false }
+        }
+    });
+
+    for html in [&filled, &outlined] {
+        assert!(html.starts_with("<svg"), "{html}");
+        assert!(html.contains(r#"viewBox="0 -960 960 960""#), "{html}");
+        assert!(html.contains(r#"class="icon""#), "{html}");
+        assert!(html.contains("<path d=\"m"), "{html}");
+    }
+    assert_ne!(filled, outlined);
+}
+
+#[test]
+fn custom_icons_render() {
+    const LOGO: CustomIcon = CustomIcon::svg("logo", "0 0 24 24", "M12 2 2 22h20z");
+
+    let svg = render(|| rsx! { Icon { icon: Icons::Custom(LOGO) } });
+    assert!(svg.contains(r#"viewBox="0 0 24 24""#), "{svg}");
+    assert!(svg.contains(r#"d="M12 2 2 22h20z""#), "{svg}");
+
+    let ligature = render(|| {
+        rsx! {
+            Icon { icon: Icons::Custom(CustomIcon::ligature("owl")) }
+        }
+    });
+    assert!(ligature.contains(">owl</p>"), "{ligature}");
+    assert_eq!(Icons::Custom(LOGO).to_string(), "logo");
+}
+
+#[cfg(feature = "embedded-icon-font")]
+#[test]
+fn embedded_font_is_subset() {
+    let html = render(|| rsx! { Bootstrap {} });
+
+    let start = html.find("base64,").expect(&html) + "base64,".len();
+    let len = html[start..].find(')').expect(&html);
+    let full = std::fs::metadata(
+        "dioxus-tw-components-icon-fonts/fonts/MaterialSymbolsRounded-Filled.ttf",
+    )
+    .unwrap()
+    .len() as usize;
+    // Even with every icon compiled, a third of the font is left out
+    assert!(len / 4 * 3 < full * 2 / 3, "{len} base64 bytes for {full}");
+}
+
+#[cfg(feature = "svg-icons")]
+#[test]
+fn svg_backend_emulates_weight_and_grade() {
+    let regular = render(|| rsx! { Icon { icon: Icons::Home, backend: IconBackend::Svg } });
+    assert!(regular.contains(r#"stroke="none""#), "{regular}");
+    assert!(!regular.contains("filter"), "{regular}");
+
+    let bold = render(|| {
+        rsx! {
+            Icon { icon: Icons::Home, backend: IconBackend::Svg, weight: 700 }
+        }
+    });
+    assert!(bold.contains(r#"stroke-width="60""#), "{bold}");
+
+    // Lighter weights and negative grades thin the shape instead of being ignored
+    let light = render(|| {
+        rsx! {
+            Icon { icon: Icons::Home, backend: IconBackend::Svg, weight: 200, grade: -50 }
+        }
+    });
+    assert!(light.contains(r#"stroke="none""#), "{light}");
+    assert!(light.contains(r#"<filter id="icon-erode-23">"#), "{light}");
+    assert!(
+        light.contains(r#"<feMorphology operator="erode" radius="23">"#),
+        "{light}"
+    );
+    assert!(light.contains(r#"filter="url(#icon-erode-23)""#), "{light}");
+}
@@ -0,0 +1,175 @@
+#![cfg(all(feature = "input", feature = "textarea"))]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dioxus::ssr::render(&dom)
+}
+
+#[test]
+fn plain_fields_render_bare() {
+    let html = render(|| {
+        rsx! {
+            Input { id: "name" }
+            TextArea { id: "bio" }
+        }
+    });
+
+    assert!(!html.contains("input-group"), "{html}");
+    assert!(!html.contains("textarea-group"), "{html}");
+}
+
+#[test]
+fn adornments_surround_the_input() {
+    let html = render(|| {
+        rsx! {
+            Input {
+                default_value: "12",
+                prefix: rsx! { "$" },
+                suffix: rsx! { "USD" },
+                clearable: true,
+            }
+            Input { id: "empty", clearable: true }
+        }
+    });
+
+    let prefix = html
+        .find(r#"<span class="input-prefix">$</span>"#)
+        .expect(&html);
+    let input = html.find(r#"<input value="12""#).expect(&html);
+    let clear = html.find(r#"aria-label="Clear""#).expect(&html);
+    let suffix = html
+        .find(r#"<span class="input-suffix">USD</span>"#)
+        .expect(&html);
+    assert!(prefix < input && input < clear && clear < suffix, "{html}");
+    // Nothing to clear in an empty field
+    assert_eq!(html.matches(r#"aria-label="Clear""#).count(), 1, "{html}");
+}
+
+#[test]
+fn password_toggle_only_applies_to_passwords() {
+    let html = render(|| {
+        rsx! {
+            Input { r#type: "password", password_toggle: true }
+            Input { r#type: "text", password_toggle: true }
+        }
+    });
+
+    assert_eq!(
+        html.matches(r#"aria-label="Show password""#).count(),
+        1,
+        "{html}"
+    );
+    assert!(html.contains(r#"aria-pressed=false"#), "{html}");
+    assert!(html.contains("visibility"), "{html}");
+}
+
+#[test]
+fn counters_count_against_maxlength() {
+    let html = render(|| {
+        rsx! {
+            Input { default_value: "héllo", maxlength: "5", counter: Counter::Characters }
+            Input { default_value: "one two  three", counter: Counter::Words }
+            TextArea { default_value: "abc", maxlength: 10, counter: Counter::Characters }
+        }
+    });
+
+    assert!(
+        html.contains(
+            r#"data-counter="characters" data-full=true aria-live="polite">5 / 5</span>"#
+        ),
+        "{html}"
+    );
+    assert!(html.contains(">3 words</span>"), "{html}");
+    assert!(html.contains(r#"class="textarea-counter""#), "{html}");
+    assert!(html.contains(">3 / 10</span>"), "{html}");
+}
+
+#[test]
+fn auto_grow_rows_follow_the_lines() {
+    let html = render(|| {
+        rsx! {
+            TextArea { id: "short", auto_grow: true, default_value: "one" }
+            TextArea { id: "long", auto_grow: true, rows: 1, max_rows: 3, default_value: "1\n2\n3\n4\n5" }
+        }
+    });
+
+    assert!(html.contains(r#"rows="2""#), "{html}");
+    assert!(html.contains(r#"rows="3""#), "{html}");
+    assert!(html.contains("--max-rows: 3"), "{html}");
+    assert_eq!(html.matches(r#"data-autogrow="true""#).count(), 2, "{html}");
+}
+
+#[test]
+fn auto_grow_keeps_the_given_style() {
+    let html = render(|| {
+        rsx! {
+            TextArea { auto_grow: true, max_rows: 4, style: "color: red;" }
+        }
+    });
+
+    assert!(
+        html.contains(r#"style="color: red; --max-rows: 4""#),
+        "{html}"
+    );
+    assert_eq!(html.matches("style=").count(), 1, "{html}");
+}
+
+#[test]
+fn counter_formats() {
+    assert_eq!(Counter::Characters.format("日本", Some(10)), "2 / 10");
+    assert_eq!(Counter::Characters.format("🦀", None), "2");
+    assert_eq!(Counter::Words.format("  crab ", None), "1 word");
+    assert_eq!(Counter::Words.format("", Some(10)), "0 words");
+}
+
+#[test]
+fn clearing_calls_onchange_then_onclear() {
+    use dioxus::dioxus_core::{Event, Mutation, Mutations};
+    use dioxus_html::{PlatformEventData, SerializedHtmlEventConverter, SerializedMouseData};
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    thread_local! {
+        static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(call: String) {
+        CALLS.with_borrow_mut(|calls| calls.push(call));
+    }
+
+    fn app() -> Element {
+        rsx! {
+            Input {
+                default_value: "draft",
+                clearable: true,
+                onchange: |event: FormEvent| record(format!("change {:?}", event.value())),
+                onclear: |_| record("clear".to_string()),
+            }
+        }
+    }
+
+    dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+    let mut dom = VirtualDom::new(app);
+    let mut mutations = Mutations::default();
+    dom.rebuild(&mut mutations);
+    let clear = mutations
+        .edits
+        .iter()
+        .find_map(|edit| match edit {
+            Mutation::NewEventListener { name, id } if name == "click" => Some(*id),
+            _ => None,
+        })
+        .unwrap();
+
+    let data = PlatformEventData::new(Box::new(SerializedMouseData::default()));
+    let event = Event::new(Rc::new(data) as Rc<dyn Any>, true);
+    dom.runtime().handle_event("click", event, clear);
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+
+    CALLS.with_borrow(|calls| assert_eq!(calls, &[r#"change """#, "clear"]));
+    let html = dioxus::ssr::render(&dom);
+    assert!(html.contains(r#"<input value="""#), "{html}");
+}
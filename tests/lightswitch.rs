@@ -0,0 +1,43 @@
+#![cfg(feature = "lightswitch")]
+
+use dioxus_tw_components::prelude::*;
+
+#[test]
+fn cycle_goes_through_every_mode() {
+    let mut mode = ThemeMode::default();
+    assert_eq!(mode, ThemeMode::System);
+
+    let mut modes = Vec::new();
+    for _ in 0..3 {
+        mode = mode.cycle();
+        modes.push(mode);
+    }
+    assert_eq!(
+        modes,
+        [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System]
+    );
+}
+
+#[test]
+fn resolve_only_changes_system() {
+    assert_eq!(ThemeMode::System.resolve(true), ThemeMode::Dark);
+    assert_eq!(ThemeMode::System.resolve(false), ThemeMode::Light);
+    assert_eq!(ThemeMode::Light.resolve(true), ThemeMode::Light);
+    assert_eq!(ThemeMode::Dark.resolve(false), ThemeMode::Dark);
+}
+
+#[test]
+fn toggle_resolved_flips_the_displayed_theme() {
+    assert_eq!(ThemeMode::System.toggle_resolved(true), ThemeMode::Light);
+    assert_eq!(ThemeMode::System.toggle_resolved(false), ThemeMode::Dark);
+    assert_eq!(ThemeMode::Light.toggle_resolved(true), ThemeMode::Dark);
+    assert_eq!(ThemeMode::Dark.toggle_resolved(false), ThemeMode::Light);
+}
+
+#[test]
+fn modes_parse_back_from_their_name() {
+    for mode in [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System] {
+        assert_eq!(mode.as_str().parse(), Ok(mode));
+    }
+    assert_eq!("auto".parse::<ThemeMode>(), Err(()));
+}
@@ -0,0 +1,220 @@
+#![cfg(feature = "modelform")]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+
+#[derive(Form, Debug, Clone, PartialEq)]
+struct Profile {
+    #[field(label = "Username", validate = [Validator::required(), Validator::min_length(3)])]
+    username: String,
+    #[field(widget = "textarea", validate = [Validator::max_length(200)])]
+    bio: String,
+    #[field(widget = "select", options = ["fr", "en"])]
+    language: String,
+    newsletter: bool,
+    #[field(widget = "radio", options = ["s", "m", "l"])]
+    shirt_size: String,
+    #[field(widget = "slider", min = 0, max = 10)]
+    volume: i64,
+    age: Option<u8>,
+    #[field(widget = "email")]
+    email: String,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile {
+            username: "alice".to_string(),
+            bio: String::new(),
+            language: "en".to_string(),
+            newsletter: true,
+            shirt_size: "m".to_string(),
+            volume: 4,
+            age: None,
+            email: String::new(),
+        }
+    }
+}
+
+fn values(values: &[(&str, &str)]) -> FormValues {
+    values
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn derives_field_specs() {
+    let fields = Profile::fields();
+    let names: Vec<_> = fields.iter().map(|spec| spec.name).collect();
+
+    assert_eq!(
+        names,
+        [
+            "username",
+            "bio",
+            "language",
+            "newsletter",
+            "shirt_size",
+            "volume",
+            "age",
+            "email"
+        ]
+    );
+    assert_eq!(fields[0].field, ProfileField::Username);
+    assert_eq!(fields[0].validators.len(), 2);
+    assert_eq!(fields[1].widget, Widget::TextArea);
+    assert_eq!(fields[2].widget, Widget::Select(&["fr", "en"]));
+    assert_eq!(fields[3].widget, Widget::Checkbox);
+    assert_eq!(fields[4].label, "Shirt size");
+    assert_eq!(fields[4].widget, Widget::Radio(&["s", "m", "l"]));
+    assert_eq!(
+        fields[5].widget,
+        Widget::Slider {
+            min: 0,
+            max: 10,
+            step: 1
+        }
+    );
+    assert_eq!(fields[6].widget, Widget::Input("number"));
+    assert_eq!(fields[7].widget, Widget::Input("email"));
+    assert_eq!(ProfileField::ShirtSize.name(), "shirt_size");
+}
+
+#[test]
+fn round_trips_through_values() {
+    let profile = Profile {
+        age: Some(32),
+        ..Default::default()
+    };
+    let form_values = profile.to_values();
+
+    assert_eq!(form_values.get("newsletter"), Some("on"));
+    assert_eq!(form_values.get("volume"), Some("4"));
+    assert_eq!(form_values.get("age"), Some("32"));
+    assert_eq!(Profile::from_values(&form_values), Ok(profile));
+}
+
+#[test]
+fn reports_typed_conversion_errors() {
+    let errors = Profile::from_values(&values(&[("volume", "loud"), ("age", "-1")])).unwrap_err();
+    let fields: Vec<_> = errors.iter().map(|error| error.field).collect();
+
+    assert_eq!(fields, [ProfileField::Volume, ProfileField::Age]);
+    assert_eq!(errors[0].message, "Must be a number");
+}
+
+#[test]
+fn renders_every_widget() {
+    #[component]
+    fn App() -> Element {
+        let profile = use_signal(Profile::default);
+
+        rsx! {
+            ModelForm { value: profile,
+                button { r#type: "submit", "Save" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = dioxus::ssr::render(&dom);
+
+    assert!(html.contains(r#"class="form modelform""#), "{html}");
+    assert!(html.contains("Username"), "{html}");
+    assert!(html.contains(r#"name="username""#), "{html}");
+    assert!(html.contains(r#"value="alice""#), "{html}");
+    assert!(html.contains("<textarea"), "{html}");
+    assert!(html.contains("<select"), "{html}");
+    assert!(html.contains(r#"role="checkbox""#), "{html}");
+    assert!(html.contains(r#"data-checked="checked""#), "{html}");
+    assert!(html.contains(r#"type="radio""#), "{html}");
+    assert!(html.contains(r#"type="range""#), "{html}");
+    assert!(html.contains(r#"type="email""#), "{html}");
+    assert!(html.contains("Save"), "{html}");
+}
+
+#[test]
+fn option_paths_get_the_widget_of_their_type() {
+    #[derive(Form, Clone, PartialEq)]
+    struct Settings {
+        retries: std::option::Option<u32>,
+        enabled: core::option::Option<bool>,
+        name: std::string::String,
+    }
+
+    let fields = Settings::fields();
+    assert_eq!(fields[0].widget, Widget::Input("number"));
+    assert_eq!(fields[1].widget, Widget::Checkbox);
+    assert_eq!(fields[2].widget, Widget::Input("text"));
+}
+
+#[test]
+fn unknown_widgets_do_not_compile() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn labels_point_to_their_widgets() {
+    #[component]
+    fn App() -> Element {
+        let profile = use_signal(Profile::default);
+        rsx! {
+            ModelForm { value: profile }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = dioxus::ssr::render(&dom);
+
+    let label = html.find(r#"id="dx42-"#).expect(&html);
+    let id = &html[label + 4..];
+    let id = &id[..id.find("-username").expect(&html)];
+    assert!(html.contains(&format!(r#"for="{id}-username""#)), "{html}");
+    assert!(html.contains(&format!(r#"id="{id}-username""#)), "{html}");
+    assert!(html.contains(&format!(r#"id="{id}-newsletter""#)), "{html}");
+    // The options of a radio group have their own labels
+    assert!(
+        html.contains(&format!(r#"aria-labelledby="{id}-shirt_size-label""#)),
+        "{html}"
+    );
+    assert!(
+        !html.contains(&format!(r#"for="{id}-shirt_size""#)),
+        "{html}"
+    );
+}
+
+#[test]
+fn external_writes_reach_the_fields() {
+    thread_local! {
+        static PROFILE: std::cell::Cell<Option<Signal<Profile>>> = const { std::cell::Cell::new(None) };
+    }
+
+    #[component]
+    fn App() -> Element {
+        let profile = use_signal(Profile::default);
+        PROFILE.set(Some(profile));
+        rsx! {
+            ModelForm { value: profile }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut profile = PROFILE.get().unwrap();
+
+    dom.in_runtime(|| {
+        profile.write().username = "bob".to_string();
+    });
+    // Renders, then runs the effect and renders what it changed
+    for _ in 0..3 {
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    }
+    let html = dioxus::ssr::render(&dom);
+
+    assert!(html.contains(r#"value="bob""#), "{html}");
+    assert!(!html.contains(r#"value="alice""#), "{html}");
+}
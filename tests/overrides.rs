@@ -0,0 +1,52 @@
+//! The override resolution of the build script.
+
+#[path = "../build/overrides.rs"]
+mod overrides;
+
+use std::{ffi::OsStr, fs, path::PathBuf};
+
+use overrides::{overrides_dir, unused_overrides};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn unset_overrides_are_skipped() {
+    assert_eq!(overrides_dir(None), Ok(None));
+    assert_eq!(overrides_dir(Some(OsStr::new(""))), Ok(None));
+}
+
+#[test]
+fn overrides_must_be_an_absolute_directory() {
+    let dir = temp_dir("overrides-absolute");
+    assert_eq!(overrides_dir(Some(dir.as_os_str())), Ok(Some(dir.clone())));
+
+    // Reported instead of failing the build
+    let missing = dir.join("missing");
+    let reason = overrides_dir(Some(missing.as_os_str())).unwrap_err();
+    assert!(reason.contains("is not a directory"), "{reason}");
+
+    let reason = overrides_dir(Some(OsStr::new("style/overrides"))).unwrap_err();
+    assert!(reason.contains("relative = true"), "{reason}");
+}
+
+#[test]
+fn overrides_without_a_component_are_reported() {
+    let dir = temp_dir("overrides-unused");
+    for name in ["button.css", "missing.css", "notes.txt"] {
+        fs::write(dir.join(name), "").unwrap();
+    }
+    let sources = [
+        PathBuf::from("style/components/button.css"),
+        PathBuf::from("style/components/toast.css"),
+    ];
+
+    assert_eq!(
+        unused_overrides(&dir, &sources).unwrap(),
+        vec![dir.join("missing.css")]
+    );
+}
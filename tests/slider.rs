@@ -0,0 +1,106 @@
+#![cfg(feature = "slider")]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+use std::cell::Cell;
+
+thread_local! {
+    static RANGE: Cell<Option<Signal<(f64, f64)>>> = const { Cell::new(None) };
+}
+
+#[component]
+fn App() -> Element {
+    let range = use_signal(|| (2.5, 7.5));
+    RANGE.set(Some(range));
+
+    rsx! {
+        RangeSlider {
+            value: range,
+            min: 0.0,
+            max: 10.0,
+            step: 0.5,
+            marks: vec![SliderMark::from(0.0), SliderMark::new(5.0).label("Half"), SliderMark::from(10.0)],
+        }
+        RangeSlider { orientation: Orientation::Vertical, tooltip: false }
+    }
+}
+
+fn html(dom: &mut VirtualDom) -> String {
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    dioxus::ssr::render(dom)
+}
+
+#[test]
+fn renders_both_thumbs_and_marks() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = html(&mut dom);
+
+    assert_eq!(html.matches(r#"type="range""#).count(), 4, "{html}");
+    assert!(html.contains(r#"aria-label="Minimum""#), "{html}");
+    assert!(html.contains(r#"step="0.5" value="2.5""#), "{html}");
+    assert!(html.contains(r#"step="0.5" value="7.5""#), "{html}");
+    assert!(html.contains("--start: 25; --end: 75"), "{html}");
+    assert_eq!(html.matches("rangeslider-tooltip").count(), 2, "{html}");
+    assert!(
+        html.contains(r#"style="--position: 50" data-in-range=true"#),
+        "{html}"
+    );
+    assert!(html.contains(">Half<"), "{html}");
+    assert!(html.contains(r#"data-orientation="vertical""#), "{html}");
+    assert!(html.contains(r#"value="100""#), "{html}");
+}
+
+#[test]
+fn external_writes_rerender() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut range = RANGE.get().unwrap();
+
+    dom.in_runtime(|| range.set((5.0, 10.0)));
+    let html = html(&mut dom);
+
+    assert!(html.contains("--start: 50; --end: 100"), "{html}");
+    assert!(
+        html.contains(r#"style="--position: 0" data-in-range=false"#),
+        "{html}"
+    );
+}
+
+#[cfg(feature = "form")]
+#[test]
+fn form_reset_moves_the_thumbs() {
+    thread_local! {
+        static FORM: Cell<Option<FormState>> = const { Cell::new(None) };
+    }
+
+    #[component]
+    fn FormApp() -> Element {
+        let form = use_form();
+        FORM.set(Some(form));
+
+        rsx! {
+            Form { form,
+                FormField { name: "range",
+                    RangeSlider { default_value: (2.0, 8.0), max: 10.0 }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(FormApp);
+    dom.rebuild_in_place();
+    let mut form = FORM.get().unwrap();
+
+    dom.in_runtime(|| form.set_value("range", "4,6"));
+    let moved = html(&mut dom);
+    assert!(moved.contains("--start: 40; --end: 60"), "{moved}");
+    assert!(moved.contains(r#"step="1" value="4""#), "{moved}");
+
+    dom.in_runtime(|| form.reset());
+    let reset = html(&mut dom);
+    assert!(reset.contains("--start: 20; --end: 80"), "{reset}");
+    assert!(reset.contains(r#"step="1" value="2""#), "{reset}");
+    assert!(reset.contains(r#"step="1" value="8""#), "{reset}");
+    dom.in_runtime(|| assert_eq!(form.value("range").as_deref(), Some("2,8")));
+}
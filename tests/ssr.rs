@@ -0,0 +1,91 @@
+#![cfg(feature = "lightswitch")]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+
+fn render(hint: ThemeHint) -> String {
+    #[component]
+    fn App(hint: ThemeHint) -> Element {
+        rsx! {
+            ThemeProvider { hint,
+                ThemedRoot { id: "main", "content" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new_with_props(App, AppProps { hint });
+    dom.rebuild_in_place();
+    dioxus::ssr::render(&dom)
+}
+
+#[test]
+fn cookie_header_is_parsed() {
+    let hint =
+        ThemeHint::from_cookie_header("session=abc; theme_mode=dark; theme_prefers_dark=false");
+
+    assert_eq!(hint.mode, Some(ThemeMode::Dark));
+    assert_eq!(hint.system_prefers_dark, Some(false));
+    assert_eq!(ThemeHint::from_cookie_header("theme_mode=bogus").mode, None);
+}
+
+#[test]
+fn cookie_round_trips() {
+    for mode in [ThemeMode::Dark, ThemeMode::Light, ThemeMode::System] {
+        let cookie = mode.to_cookie();
+        let header = cookie.split(';').next().unwrap();
+
+        assert_eq!(ThemeHint::from_cookie_header(header).mode, Some(mode));
+    }
+}
+
+#[test]
+fn ssr_renders_dark_hint() {
+    let html = render(ThemeHint {
+        mode: Some(ThemeMode::Dark),
+        system_prefers_dark: None,
+    });
+
+    assert!(html.contains(r#"class="dark""#), "{html}");
+    assert!(html.contains(r#"data-theme="dark""#), "{html}");
+    assert!(html.contains(r#"data-theme-mode="dark""#), "{html}");
+}
+
+#[test]
+fn ssr_renders_light_hint() {
+    let html = render(ThemeHint {
+        mode: Some(ThemeMode::Light),
+        system_prefers_dark: Some(true),
+    });
+
+    assert!(!html.contains(r#"class="dark""#), "{html}");
+    assert!(html.contains(r#"data-theme="light""#), "{html}");
+}
+
+#[test]
+fn ssr_resolves_system_from_hint() {
+    let html = render(ThemeHint {
+        mode: Some(ThemeMode::System),
+        system_prefers_dark: Some(true),
+    });
+
+    assert!(html.contains(r#"class="dark""#), "{html}");
+    assert!(html.contains(r#"data-theme="dark""#), "{html}");
+    assert!(html.contains(r#"data-theme-mode="system""#), "{html}");
+}
+
+#[test]
+fn ssr_emits_pre_hydration_script() {
+    let html = render(ThemeHint::default());
+
+    assert!(html.contains("<script>"), "{html}");
+    assert!(html.contains("theme_mode="), "{html}");
+}
+
+#[test]
+fn pre_hydration_script_reads_the_theme_cookie() {
+    let html = render(ThemeHint::default());
+    let cookie = ThemeMode::Dark.to_cookie();
+    let (name, _) = cookie.split_once('=').unwrap();
+
+    assert!(html.contains(&format!("; ){name}=(")), "{html}");
+}
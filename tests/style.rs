@@ -0,0 +1,31 @@
+#![cfg(feature = "all-components")]
+
+//! Run with `DIOXUS_TW_COMPONENTS_LAYER` and `DIOXUS_TW_COMPONENTS_LAYER_ORDER` unset.
+
+const STYLE: &str = include_str!(concat!(env!("OUT_DIR"), "/dioxus-tw-components-style.css"));
+
+#[cfg(not(feature = "theme"))]
+const SNAPSHOT: &str = include_str!("snapshots/style.css");
+#[cfg(feature = "theme")]
+const SNAPSHOT: &str = include_str!("snapshots/style_theme.css");
+
+#[test]
+fn generated_style_matches_snapshot() {
+    assert_eq!(STYLE, SNAPSHOT);
+}
+
+#[test]
+fn generated_style_is_layered() {
+    let (_header, css) = STYLE.split_once('\n').unwrap();
+    // Every layer of Tailwind v4 is declared, even the ones without component CSS
+    assert!(
+        css.starts_with("@layer theme,base,components,utilities;@layer components{"),
+        "{css}"
+    );
+    assert!(!css.contains("/*"), "comments are stripped: {css}");
+}
+
+#[test]
+fn components_inherit_the_app_font() {
+    assert!(!STYLE.contains("--font-sans"), "{STYLE}");
+}
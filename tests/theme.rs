@@ -0,0 +1,127 @@
+#![cfg(feature = "theme")]
+
+use dioxus_tw_components::theme::*;
+
+#[test]
+fn theme_manager_css_matches_snapshot() {
+    let theme_manager = ThemeManager::default();
+
+    assert_eq!(
+        theme_manager.export_to_css(),
+        include_str!("snapshots/theme_manager.css")
+    );
+}
+
+#[test]
+fn theme_style_matches_snapshot() {
+    assert_eq!(
+        Theme::default().to_style(),
+        include_str!("snapshots/theme_style.txt")
+    );
+}
+
+#[test]
+fn theme_css_is_stable_across_builds() {
+    // Each hash map gets its own random seed, so maps built separately would iterate
+    // in different orders, unlike clones which keep the order of their source
+    let expected = Theme::default().export_to_css();
+
+    for _ in 0..10 {
+        assert_eq!(Theme::default().export_to_css(), expected);
+        assert_eq!(
+            ThemeManager::default().export_to_css(),
+            include_str!("snapshots/theme_manager.css")
+        );
+    }
+}
+
+#[test]
+fn custom_colors_keep_insertion_order() {
+    let mut theme = Theme::default();
+    let color = HslColor::try_new_from_hex("#123456").unwrap();
+
+    theme
+        .colors_mut()
+        .insert("zebra".to_string(), ColorChoice::Simple(color.clone()));
+    theme
+        .colors_mut()
+        .insert("alpha".to_string(), ColorChoice::Simple(color.clone()));
+    // Replacing an existing color must not move it
+    theme
+        .colors_mut()
+        .insert("primary".to_string(), ColorChoice::Simple(color));
+
+    let keys: Vec<&str> = theme.colors().keys().map(String::as_str).collect();
+    assert_eq!(
+        keys,
+        [
+            "background",
+            "primary",
+            "secondary",
+            "accent",
+            "muted",
+            "destructive",
+            "success",
+            "border",
+            "input",
+            "popover",
+            "zebra",
+            "alpha",
+        ]
+    );
+
+    let css = theme.export_to_css();
+    assert!(css.find("--zebra").unwrap() < css.find("--alpha").unwrap());
+}
+
+#[test]
+fn tokens_are_exported_as_variables() {
+    let css = Theme::default().export_to_css();
+
+    assert!(css.contains("  --font-sans: ui-sans-serif, system-ui, sans-serif;\n"));
+    assert!(css.contains("  --text-sm: 0.875rem;\n"));
+    assert!(css.contains("  --spacing-md: 1rem;\n"));
+    // The DEFAULT entry takes the bare prefix
+    assert!(css.contains("  --shadow: 0 1px 3px 0 rgb(0 0 0 / 0.1)"));
+    assert!(css.contains("  --radius-button: var(--radius);\n"));
+    assert!(css.contains("  --duration-fast: 150ms;\n"));
+    // Tokens follow the theme radius
+    assert!(css.find("  --radius:").unwrap() < css.find("  --font-sans:").unwrap());
+}
+
+#[test]
+fn token_scales_keep_their_order() {
+    let mut scale = TokenScale::new("spacing", &[("sm", "0.5rem"), ("lg", "1.5rem")]);
+    scale.set("sm", "0.75rem");
+    scale.set("xl", "2rem");
+
+    assert_eq!(
+        scale.export_to_css(),
+        "  --spacing-sm: 0.75rem;\n  --spacing-lg: 1.5rem;\n  --spacing-xl: 2rem;\n"
+    );
+    assert_eq!(
+        scale.to_style(),
+        " --spacing-sm: 0.75rem; --spacing-lg: 1.5rem; --spacing-xl: 2rem;"
+    );
+    assert_eq!(scale.get("xl"), Some("2rem"));
+    assert_eq!(scale.var_name("DEFAULT"), "spacing");
+}
+
+#[test]
+fn custom_tokens_reach_the_css() {
+    let mut theme = Theme::default();
+    theme
+        .tokens_mut()
+        .font_family
+        .set("sans", "Inter, sans-serif");
+    theme.tokens_mut().radius.set("button", "9999px");
+
+    let css = theme.export_to_css();
+    assert!(css.contains("  --font-sans: Inter, sans-serif;\n"), "{css}");
+    assert!(css.contains("  --radius-button: 9999px;\n"), "{css}");
+    assert!(
+        theme
+            .to_style()
+            .contains(" --font-sans: Inter, sans-serif;")
+    );
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "typedinput")]
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+use std::cell::Cell;
+
+#[derive(Clone, Copy)]
+struct Signals {
+    amount: Signal<Option<f64>>,
+    day: Signal<Option<NaiveDate>>,
+}
+
+thread_local! {
+    static SIGNALS: Cell<Option<Signals>> = const { Cell::new(None) };
+}
+
+fn date(text: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+}
+
+#[component]
+fn App() -> Element {
+    let amount = use_signal(|| Some(2.5));
+    let day = use_signal(|| Some(date("2024-03-01")));
+    SIGNALS.set(Some(Signals { amount, day }));
+
+    rsx! {
+        NumberInput::<f64> {
+            id: "amount",
+            value: amount,
+            min: 0.0,
+            max: 10.0,
+            step: 0.5,
+            locale: NumberLocale::FR,
+        }
+        NumberInput::<u8> { id: "count", steppers: false }
+        DateInput { value: day, min: date("2024-01-01") }
+        TimeInput { default_value: NaiveTime::from_hms_opt(9, 30, 15).unwrap() }
+        DateTimeInput { default_value: NaiveDateTime::parse_from_str("2024-03-01 09:30", "%Y-%m-%d %H:%M").unwrap() }
+    }
+}
+
+fn html(dom: &mut VirtualDom) -> String {
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    dioxus::ssr::render(dom)
+}
+
+#[test]
+fn locales_parse_separators() {
+    assert_eq!(NumberLocale::EN.parse::<f64>("1,234.5"), Some(1234.5));
+    assert_eq!(NumberLocale::FR.parse::<f64>("1 234,5"), Some(1234.5));
+    assert_eq!(
+        NumberLocale::FR.parse::<f64>("1\u{202f}234,5"),
+        Some(1234.5)
+    );
+    assert_eq!(NumberLocale::DE.parse::<f64>("1.234,5"), Some(1234.5));
+    assert_eq!(NumberLocale::EN.parse::<i32>("12a"), None);
+    assert_eq!(NumberLocale::DE.format(&2.5), "2,5");
+}
+
+#[test]
+fn float_steps_keep_the_step_precision() {
+    assert_eq!(0.2f64.step_up(0.1), 0.3);
+    assert_eq!(0.3f64.step_down(0.1), 0.2);
+    assert_eq!(1.15f64.step_up(0.1), 1.25);
+    assert_eq!(0.7f32.step_up(0.1), 0.8);
+    assert_eq!(2.5f64.step_up(1.0), 3.5);
+
+    let mut value = 0.0f64;
+    for _ in 0..3 {
+        value = value.step_up(0.1);
+    }
+    assert_eq!(value.to_string(), "0.3");
+}
+
+#[test]
+fn temporal_values_round_trip() {
+    let time = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+
+    assert_eq!(time.format_input(), "09:30");
+    assert_eq!(
+        NaiveTime::parse_input("09:30:15").unwrap().format_input(),
+        "09:30:15"
+    );
+    assert_eq!(NaiveDate::parse_input("2024-02-30"), None);
+    assert_eq!(
+        NaiveDateTime::parse_input("2024-03-01T09:30").unwrap(),
+        date("2024-03-01").and_time(time)
+    );
+}
+
+#[test]
+fn renders_typed_values() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = html(&mut dom);
+
+    assert!(html.contains(r#"value="2,5""#), "{html}");
+    assert!(html.contains(r#"aria-valuenow="2.5""#), "{html}");
+    assert!(html.contains(r#"aria-valuemax="10""#), "{html}");
+    assert!(html.contains(r#"inputmode="decimal""#), "{html}");
+    assert_eq!(html.matches("numberinput-stepper").count(), 2, "{html}");
+    assert!(html.contains(r#"type="date""#), "{html}");
+    assert!(html.contains(r#"min="2024-01-01""#), "{html}");
+    assert!(html.contains(r#"value="2024-03-01""#), "{html}");
+    assert!(html.contains(r#"value="09:30:15""#), "{html}");
+    assert!(html.contains(r#"type="datetime-local""#), "{html}");
+    assert!(html.contains(r#"value="2024-03-01T09:30""#), "{html}");
+    assert!(!html.contains("aria-invalid"), "{html}");
+}
+
+#[test]
+fn external_writes_rerender() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let mut signals = SIGNALS.get().unwrap();
+
+    dom.in_runtime(|| {
+        signals.amount.set(Some(10.0));
+        signals.day.set(None);
+    });
+    let html = html(&mut dom);
+
+    assert!(html.contains(r#"value="10""#), "{html}");
+    assert!(
+        html.contains(r#"aria-label="Increment" disabled=true"#),
+        "{html}"
+    );
+    assert!(!html.contains(r#"value="2024-03-01""#), "{html}");
+}
@@ -0,0 +1,9 @@
+use dioxus_tw_components::prelude::*;
+
+#[derive(Form, Clone, PartialEq)]
+struct Profile {
+    #[field(widget = "textbox")]
+    bio: String,
+}
+
+fn main() {}
@@ -0,0 +1,111 @@
+#![cfg(feature = "button")]
+
+use dioxus::prelude::*;
+use dioxus_tw_components::prelude::*;
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dioxus::ssr::render(&dom)
+}
+
+#[test]
+fn typed_props_render_data_attributes() {
+    let html = render(|| {
+        rsx! {
+            Button {
+                color: Color::Primary,
+                variant: ButtonVariant::Outline,
+                size: Size::Lg,
+                animation: Animation::Full,
+                "Save"
+            }
+        }
+    });
+
+    assert!(html.contains(r#"data-style="primary""#), "{html}");
+    assert!(html.contains(r#"data-variant="outline""#), "{html}");
+    assert!(html.contains(r#"data-size="lg""#), "{html}");
+    assert!(html.contains(r#"data-animation="full""#), "{html}");
+}
+
+#[test]
+fn missing_props_render_no_attribute() {
+    let html = render(|| rsx! { Button { "Save" } });
+
+    assert!(!html.contains("data-"), "{html}");
+}
+
+#[test]
+fn typed_props_replace_raw_attributes() {
+    let html = render(|| {
+        rsx! {
+            Button { "data-style": "secondary", color: Color::Destructive, "Delete" }
+        }
+    });
+
+    assert!(html.contains(r#"data-style="destructive""#), "{html}");
+    assert!(!html.contains("secondary"), "{html}");
+}
+
+#[test]
+fn raw_attributes_still_apply() {
+    let html = render(|| rsx! { Button { "data-style": "secondary", "Cancel" } });
+
+    assert!(html.contains(r#"data-style="secondary""#), "{html}");
+}
+
+#[cfg(feature = "pagination")]
+#[test]
+fn pagination_forwards_raw_attributes_to_its_buttons() {
+    let html = render(|| {
+        let page_number = use_signal(|| 1);
+        rsx! {
+            Pagination {
+                data_size: 30usize,
+                page_size: 10usize,
+                page_number,
+                "data-style": "secondary",
+                "data-variant": "ghost",
+                color: Color::Primary,
+            }
+        }
+    });
+
+    let buttons = html.matches("<button").count();
+    assert!(buttons > 2, "{html}");
+    assert_eq!(
+        html.matches(r#"data-variant="ghost""#).count(),
+        buttons + 1,
+        "{html}"
+    );
+    // The typed prop wins on the buttons, the container keeps the raw attribute
+    assert_eq!(
+        html.matches(r#"data-style="primary""#).count(),
+        buttons,
+        "{html}"
+    );
+    assert_eq!(
+        html.matches(r#"data-style="secondary""#).count(),
+        1,
+        "{html}"
+    );
+}
+
+#[test]
+fn user_classes_keep_the_component_class() {
+    let html = render(|| rsx! { Button { class: "select-none px-2 px-4", "Save" } });
+
+    assert!(
+        html.contains(r#"class="button select-none px-4""#),
+        "{html}"
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn display_classes_keep_the_table_class() {
+    let html = render(|| rsx! { Table { class: "block", TableBody {} } });
+
+    assert!(html.contains(r#"class="table block""#), "{html}");
+}